@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ColumnsProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Align child columns vertically.
     #[prop_or_default]
     pub vcentered: bool,
@@ -29,11 +34,11 @@ pub fn columns(props: &ColumnsProps) -> Html {
         props.multiline.then_some("is-multiline"),
         props.centered.then_some("is-centered"),
     );
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -45,6 +50,9 @@ pub struct ColumnProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A flexbox-based responsive column.
@@ -56,9 +64,9 @@ pub struct ColumnProps {
 /// add classes to this Component manually via the `classes` prop.
 #[component(Column)]
 pub fn column(props: &ColumnProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("column", props.classes.clone())}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
@@ -1,6 +1,9 @@
 use derive_more::Display;
 use std::borrow::Cow;
+use std::rc::Rc;
 use yew::html::IntoPropValue;
+use yew::prelude::*;
+use yew::virtual_dom::AttributeOrProperty;
 
 /// Common alignment classes.
 #[derive(Clone, Debug, Display, PartialEq, Eq)]
@@ -31,3 +34,384 @@ impl IntoPropValue<Cow<'static, str>> for Size {
         Cow::from(self.to_string())
     }
 }
+
+/// An extra attribute for an element, e.g. a test id for end-to-end selectors.
+///
+/// Converts from strings as a `data-testid`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestAttr {
+    pub key: AttrValue,
+    pub value: AttrValue,
+}
+
+impl TestAttr {
+    /// A `data-testid` attribute, as used by Testing Library and Playwright.
+    pub fn test_id(value: impl Into<AttrValue>) -> Self {
+        Self {
+            key: AttrValue::from("data-testid"),
+            value: value.into(),
+        }
+    }
+
+    /// A `data-cy` attribute, as used by Cypress.
+    pub fn data_cy(value: impl Into<AttrValue>) -> Self {
+        Self {
+            key: AttrValue::from("data-cy"),
+            value: value.into(),
+        }
+    }
+
+    /// A `data-*` attribute, e.g. `data("row", "3")` for `data-row="3"`.
+    pub fn data(name: &str, value: impl Into<AttrValue>) -> Self {
+        Self {
+            key: AttrValue::from(format!("data-{}", name)),
+            value: value.into(),
+        }
+    }
+
+    /// An `aria-*` attribute, e.g. `aria("describedby", "hint")` for `aria-describedby="hint"`.
+    pub fn aria(name: &str, value: impl Into<AttrValue>) -> Self {
+        Self {
+            key: AttrValue::from(format!("aria-{}", name)),
+            value: value.into(),
+        }
+    }
+}
+
+impl<T> From<T> for TestAttr
+where
+    T: Into<AttrValue>,
+{
+    fn from(value: T) -> Self {
+        Self::test_id(value)
+    }
+}
+
+/// Extra attributes which every component renders on its root element, e.g. test ids, `data-*` or `aria-*`
+/// attributes; they take precedence over the attributes the component sets itself.
+///
+/// The context providers (`TabsProvider`, `ModalControllerProvider`, `ThemeProvider` and `YbcIdProvider`) have no
+/// element of their own; when given attributes, they wrap their children in a `<div style="display: contents">`
+/// carrying them.
+///
+/// Build them from a single [`TestAttr`], a list of them or with the builder methods:
+///
+/// ```rust,ignore
+/// html! {
+///     <>
+///         <Button attrs={TestAttr::test_id("save")}>{"Save"}</Button>
+///         <Notification attrs={ExtraAttrs::new().data_cy("notice").aria("live", "polite")}>{"Saved."}</Notification>
+///     </>
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExtraAttrs(Vec<TestAttr>);
+
+impl ExtraAttrs {
+    /// No extra attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `attr`, replacing an earlier attribute with the same key.
+    pub fn with(mut self, attr: TestAttr) -> Self {
+        self.0.retain(|existing| existing.key != attr.key);
+        self.0.push(attr);
+        self
+    }
+
+    /// Add a `data-testid` attribute.
+    pub fn test_id(self, value: impl Into<AttrValue>) -> Self {
+        self.with(TestAttr::test_id(value))
+    }
+
+    /// Add a `data-cy` attribute.
+    pub fn data_cy(self, value: impl Into<AttrValue>) -> Self {
+        self.with(TestAttr::data_cy(value))
+    }
+
+    /// Add the `data-*` attribute `name`.
+    pub fn data(self, name: &str, value: impl Into<AttrValue>) -> Self {
+        self.with(TestAttr::data(name, value))
+    }
+
+    /// Add the `aria-*` attribute `name`.
+    pub fn aria(self, name: &str, value: impl Into<AttrValue>) -> Self {
+        self.with(TestAttr::aria(name, value))
+    }
+
+    /// Whether there are no extra attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The attributes in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &TestAttr> {
+        self.0.iter()
+    }
+
+    /// Set the attributes on `html`, the root of a component.
+    ///
+    /// A root which is not a single element, e.g. the content of a context provider, is wrapped in a
+    /// `<div style="display: contents">` carrying the attributes, so they are never dropped. Without attributes the
+    /// markup is returned unchanged.
+    pub(crate) fn apply(&self, html: Html) -> Html {
+        if self.is_empty() {
+            return html;
+        }
+        let mut tag = match html {
+            Html::VTag(tag) => tag,
+            html => match html! { <div style="display: contents">{html}</div> } {
+                Html::VTag(tag) => tag,
+                _ => unreachable!("`html!` renders a single element as a tag"),
+            },
+        };
+        let attributes = Rc::make_mut(&mut tag).attributes.get_mut_index_map();
+        // Literal attributes become `Static` entries of the map, which server-side rendering skips.
+        for value in attributes.values_mut() {
+            if let AttributeOrProperty::Static(literal) = value {
+                *value = AttributeOrProperty::Attribute(AttrValue::Static(literal));
+            }
+        }
+        for attr in &self.0 {
+            attributes.insert(attr.key.clone(), AttributeOrProperty::Attribute(attr.value.clone()));
+        }
+        Html::VTag(tag)
+    }
+}
+
+impl From<TestAttr> for ExtraAttrs {
+    fn from(attr: TestAttr) -> Self {
+        Self::new().with(attr)
+    }
+}
+
+impl From<Vec<TestAttr>> for ExtraAttrs {
+    fn from(attrs: Vec<TestAttr>) -> Self {
+        attrs.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+impl IntoPropValue<ExtraAttrs> for TestAttr {
+    fn into_prop_value(self) -> ExtraAttrs {
+        self.into()
+    }
+}
+
+impl IntoPropValue<ExtraAttrs> for Vec<TestAttr> {
+    fn into_prop_value(self) -> ExtraAttrs {
+        self.into()
+    }
+}
+
+/// Adds a `<style id={id}>` with `css` to the document head unless the document already has an element with that id.
+///
+/// Components keep their style sheets in the head this way, so that the page carries each sheet once however many
+/// instances it renders; struct components call this from `rendered` on the first render.
+pub(crate) fn inject_style(id: &str, css: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    if document.get_element_by_id(id).is_some() {
+        return;
+    }
+    let (Some(head), Ok(style)) = (document.head(), document.create_element("style")) else {
+        return;
+    };
+    style.set_id(id);
+    style.set_text_content(Some(css));
+    let _ = head.append_child(&style);
+}
+
+/// Injects a style sheet through [`inject_style`] once the component is mounted.
+#[hook]
+pub(crate) fn use_style(id: &'static str, css: &'static str) {
+    use_effect_with((), move |_| inject_style(id, css));
+}
+
+#[cfg(feature = "router")]
+pub(crate) use router::RouterAnchor;
+
+#[cfg(feature = "router")]
+mod router {
+    use serde::Serialize;
+    use yew::prelude::*;
+    use yew_router::Routable;
+    use yew_router::hooks::use_navigator;
+    use yew_router::navigator::NavigatorKind;
+    use yew_router::query::ToQuery;
+    use yew_router::utils::compose_path;
+
+    use super::ExtraAttrs;
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub(crate) struct RouterAnchorProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> {
+        pub to: R,
+        #[prop_or_default]
+        pub query: Option<Q>,
+        #[prop_or_default]
+        pub classes: Classes,
+        #[prop_or_default]
+        pub disabled: bool,
+        #[prop_or_default]
+        pub attrs: ExtraAttrs,
+        #[prop_or_default]
+        pub children: Children,
+    }
+
+    /// The anchor of yew-router's `Link`, rendered by ybc itself so that [`ExtraAttrs`] land on the `<a>`.
+    #[component(RouterAnchor)]
+    pub(crate) fn router_anchor<R, Q>(props: &RouterAnchorProps<R, Q>) -> Html
+    where
+        R: Routable + Clone + PartialEq + 'static,
+        Q: Clone + PartialEq + Serialize + 'static,
+    {
+        let navigator = use_navigator().expect("the router components must be used inside a `Router`");
+
+        let onclick = {
+            let navigator = navigator.clone();
+            let to = props.to.clone();
+            let query = props.query.clone();
+            Callback::from(move |event: MouseEvent| {
+                if event.meta_key() || event.ctrl_key() || event.shift_key() || event.alt_key() {
+                    return;
+                }
+                event.prevent_default();
+                match query.as_ref() {
+                    Some(query) => {
+                        let _ = navigator.push_with_query(&to, query);
+                    }
+                    None => navigator.push(&to),
+                }
+            })
+        };
+
+        let path = props.to.to_path();
+        let pathname = match navigator.basename() {
+            Some(base) if base.is_empty() && path.is_empty() => "/".to_string(),
+            Some(base) => format!("{}{}", base, path),
+            None => path,
+        };
+        let mut href = props
+            .query
+            .as_ref()
+            .and_then(|query| query.to_query().ok())
+            .and_then(|query| compose_path(&pathname, &query))
+            .unwrap_or(pathname);
+        if navigator.kind() == NavigatorKind::Hash {
+            href.insert(0, '#');
+        }
+
+        props.attrs.apply(html! {
+            <a class={props.classes.clone()} {href} {onclick} disabled={props.disabled}>
+                {props.children.clone()}
+            </a>
+        })
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use yew::prelude::*;
+
+    use crate::testing::{Queries, render_blocking};
+    use crate::{Button, Calendar, ExtraAttrs, File, TabItem, Tabs, TabsProvider, TestAttr, Toast, ToastProvider};
+
+    #[test]
+    fn attrs_land_on_the_root_element() {
+        let rendered = render_blocking(|| {
+            html! {
+                <Button attrs={ExtraAttrs::new().test_id("save").aria("describedby", "hint")}>{"Save"}</Button>
+            }
+        });
+        let button = rendered.get_by_test_id("save");
+        assert_eq!(button.tag(), "button");
+        assert_eq!(button.attr("aria-describedby").as_deref(), Some("hint"));
+    }
+
+    #[test]
+    fn providers_wrap_their_children_only_when_given_attrs() {
+        let tabs = |test_id: Option<&'static str>| {
+            move || {
+                let attrs = test_id.map(|test_id| ExtraAttrs::new().test_id(test_id)).unwrap_or_default();
+                html! {
+                    <TabsProvider {attrs}>
+                        <Tabs><TabItem index={0}>{"Profile"}</TabItem></Tabs>
+                    </TabsProvider>
+                }
+            }
+        };
+        let plain = render_blocking(tabs(None));
+        assert!(!plain.html().contains("display: contents"));
+
+        let wrapped = render_blocking(tabs(Some("settings")));
+        let wrapper = wrapped.get_by_test_id("settings");
+        assert_eq!(wrapper.tag(), "div");
+        assert_eq!(wrapper.attr("style").as_deref(), Some("display: contents"));
+        assert!(wrapper.query_by_role_and_name("tab", "Profile").is_some());
+    }
+
+    #[test]
+    fn toast_provider_puts_attrs_on_its_region() {
+        let rendered = render_blocking(|| html! { <ToastProvider attrs={TestAttr::test_id("toasts")} /> });
+        assert_eq!(rendered.get_by_test_id("toasts").role().as_deref(), Some("region"));
+        assert_eq!(
+            Toast::new("Saved.").attrs(TestAttr::test_id("saved")).attrs,
+            ExtraAttrs::new().test_id("saved")
+        );
+    }
+
+    #[test]
+    fn component_styles_are_left_to_the_document_head() {
+        let rendered = render_blocking(|| {
+            html! {
+                <ToastProvider>
+                    <Calendar id="start" on_date_changed={Callback::noop()} />
+                    <Calendar id="end" on_date_changed={Callback::noop()} />
+                </ToastProvider>
+            }
+        });
+        assert!(rendered.find_all(|element| element.tag() == "style").is_empty());
+    }
+
+    #[test]
+    fn calendars_render_any_test_attr_on_their_input() {
+        let input_attr = |test_attr: fn() -> TestAttr, key: &str| {
+            let rendered = render_blocking(move || {
+                html! { <Calendar id="due" on_date_changed={Callback::noop()} test_attr={test_attr()} attrs={TestAttr::data_cy("picker")} /> }
+            });
+            let root = &rendered.children()[0];
+            assert!(root.has_class("ybc-calendar"));
+            assert_eq!(root.attr("data-cy").as_deref(), Some("picker"));
+            let input = rendered.find_all(|element| element.tag() == "input").remove(0);
+            assert_eq!(input.attr("data-cy"), None);
+            input.attr(key)
+        };
+        assert_eq!(input_attr(|| TestAttr::test_id("due"), "data-testid").as_deref(), Some("due"));
+        assert_eq!(input_attr(|| TestAttr::data("field", "due"), "data-field").as_deref(), Some("due"));
+        assert_eq!(
+            input_attr(|| TestAttr::aria("describedby", "due-help"), "aria-describedby").as_deref(),
+            Some("due-help")
+        );
+    }
+
+    #[test]
+    fn file_renders_a_single_root_in_both_modes() {
+        let file = |drop_zone: bool| {
+            move || {
+                html! {
+                    <File name="cv" files={vec![]} update={Callback::noop()} {drop_zone} attrs={TestAttr::test_id("cv")} />
+                }
+            }
+        };
+        let plain = render_blocking(file(false));
+        assert_eq!(plain.children().len(), 1);
+        assert!(plain.get_by_test_id("cv").has_class("file"));
+
+        let drop_zone = render_blocking(file(true));
+        assert_eq!(drop_zone.children().len(), 1);
+        let root = drop_zone.get_by_test_id("cv");
+        assert!(root.has_class("ybc-file-field"));
+        assert_eq!(root.find_all(|element| element.has_class("ybc-file-drop-zone")).len(), 1);
+    }
+}
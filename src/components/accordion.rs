@@ -43,20 +43,17 @@
 //!   Ensure the import runs before the Yew bootstrap so `bulmaAccordion` is available globally (or adapt the setup to pass the module).
 //!
 //! - The important requirement: bulmaAccordion must be defined on window when setup_accordion is called in rendered().
+//!   Without it, and during server-side rendering (feature `ssr`), the accordions render as plain markup; the plugin is
+//!   attached after hydration.
 
 use std::rc::Rc;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 use web_sys::Element;
 use yew::events::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
-static ACCORDION_ITEM_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-
-fn next_accordion_item_id() -> String {
-    format!("accordion-item-{}", ACCORDION_ITEM_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
+use crate::{ExtraAttrs, use_ybc_id};
 
 #[component(AccordionItem)]
 pub fn accordion_item(props: &AccordionItemProps) -> Html {
@@ -96,8 +93,12 @@ pub fn accordion_item(props: &AccordionItemProps) -> Html {
         });
     }
 
-    let auto_id = use_state(|| Rc::<str>::from(next_accordion_item_id()));
-    let item_id = if props.id.is_empty() { (*auto_id).clone() } else { props.id.clone() };
+    let auto_id = use_ybc_id("accordion-item");
+    let item_id = if props.id.is_empty() {
+        Rc::from(auto_id.as_str())
+    } else {
+        props.id.clone()
+    };
     let header_id = AttrValue::from(format!("{}-header", item_id));
     let panel_id = AttrValue::from(format!("{}-panel", item_id));
     let accordion_classes = if is_open { "accordion is-active" } else { "accordion" };
@@ -105,7 +106,6 @@ pub fn accordion_item(props: &AccordionItemProps) -> Html {
     let on_click = {
         let set_local_open = set_local_open.clone();
         let on_toggle = props.on_toggle.clone();
-        let is_open = is_open;
         Callback::from(move |event: MouseEvent| {
             set_local_open.emit(!is_open);
             on_toggle.emit(event);
@@ -114,7 +114,6 @@ pub fn accordion_item(props: &AccordionItemProps) -> Html {
 
     let on_keydown = {
         let set_local_open = set_local_open.clone();
-        let is_open = is_open;
         Callback::from(move |event: KeyboardEvent| {
             let key = event.key();
             if key == "Enter" || key == " " {
@@ -124,7 +123,7 @@ pub fn accordion_item(props: &AccordionItemProps) -> Html {
         })
     };
 
-    html! {
+    props.attrs.apply(html! {
         <article class={classes!(accordion_classes)}>
             <div
                 id={header_id.clone()}
@@ -144,17 +143,23 @@ pub fn accordion_item(props: &AccordionItemProps) -> Html {
                 </div>
             </div>
         </article>
-    }
+    })
 }
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct AccordionsProps {
     pub children: ChildrenWithProps<AccordionItem>,
     pub id: Rc<str>,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 pub struct Accordions {
     props: AccordionsProps,
+    node_ref: NodeRef,
+    /// Whether the plugin was attached; never the case during server-side rendering.
+    attached: bool,
 }
 
 #[derive(Properties, Clone, PartialEq)]
@@ -180,6 +185,9 @@ pub struct AccordionItemProps {
     pub on_toggle: Callback<MouseEvent>,
     #[prop_or("".into())]
     pub id: Rc<str>,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 impl Component for Accordions {
@@ -187,7 +195,11 @@ impl Component for Accordions {
     type Properties = AccordionsProps;
 
     fn create(ctx: &Context<Self>) -> Self {
-        Self { props: ctx.props().clone() }
+        Self {
+            props: ctx.props().clone(),
+            node_ref: NodeRef::default(),
+            attached: false,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, _msg: Self::Message) -> bool {
@@ -196,30 +208,26 @@ impl Component for Accordions {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        html! {
-            <section id={ctx.props().id.to_string()} class="accordions">
+        ctx.props().attrs.apply(html! {
+            <section id={ctx.props().id.to_string()} class="accordions" ref={self.node_ref.clone()}>
                 {for ctx.props().children.iter().map(|child| {
                     html! {child.clone()}
                 })}
             </section>
-        }
+        })
     }
 
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        if first_render {
-            let window = web_sys::window().expect("no global `window` exists");
-            let document = window.document().expect("should have a document on window");
-
-            let element = document
-                .get_element_by_id(ctx.props().id.to_string().as_str())
-                .unwrap_or_else(|| panic!("should have #{} on the page", ctx.props().id));
-
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render && let Some(element) = self.node_ref.cast::<Element>() {
             setup_accordion(&element);
+            self.attached = true;
         }
     }
 
     fn destroy(&mut self, ctx: &Context<Self>) {
-        detach_accordion(&JsValue::from_str(&ctx.props().id));
+        if self.attached {
+            detach_accordion(&JsValue::from_str(&ctx.props().id));
+        }
     }
 }
 
@@ -227,6 +235,10 @@ impl Component for Accordions {
 let accordionInstances  = null;
 export function setup_accordion(element) {
     // console.log('Setting up accordion ID:' + element.id);
+    if (typeof bulmaAccordion === 'undefined') {
+      // Without the plugin the server-rendered markup stays as is.
+      return;
+    }
     if (accordionInstances === null) {
       accordionInstances = bulmaAccordion.attach('#' + element.id);
       return;
@@ -248,6 +260,9 @@ export function setup_accordion(element) {
 }
 
 export function detach_accordion(id) {
+    if (accordionInstances === null) {
+        return;
+    }
     for (let i = 0; i < accordionInstances.length; i++) {
         if (accordionInstances[i] && accordionInstances[i].element && accordionInstances[i].element.id === id) {
             // console.log('Detaching accordion #id='+id+'!');
@@ -1,318 +1,843 @@
-//! AutoComplete component: a Yew wrapper around the Bulma Tags Input plugin.
+//! AutoComplete component: a native Yew tags input with a filtered suggestion list.
 //!
-//! Required static assets
-//! - Add the Bulma TagsInput CSS into your HTML <head>:
-//!   <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/css/bulma-tagsinput.min.css" />
+//! Summary
+//! - Renders the selected values as Bulma tags followed by a text input, all inside a Bulma `input` box.
+//! - Suggestions are shown in a Bulma dropdown menu below the input.
+//! - No external JavaScript or CSS plugin is required.
 //!
-//! - Add the Bulma TagsInput JS so `BulmaTagsInput` is available on window. Place this before your wasm bootstrap script
-//!   (or ensure it loads before your Yew app mounts):
-//!   <script src="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/js/bulma-tagsinput.min.js"></script>
+//! Items
+//! - `AutoComplete` defaults to `String` items. Any type implementing [`AutoCompleteItem`] can be used instead; its
+//!   `label` is the text shown in tags and its `key` the identity used for duplicate detection.
+//! - `on_select` / `on_deselect` emit the typed item; `on_update` / `on_remove` emit its key.
 //!
-//! How to configure index.html
-//! - Minimal example (place CSS in <head>, script before the wasm init script):
-//!   ```html
-//!   <!doctype html>
-//!   <html>
-//!   <head>
-//!     <meta charset="utf-8" />
-//!     <meta name="viewport" content="width=device-width,initial-scale=1" />
-//!     <!-- Bulma TagsInput CSS -->
-//!     <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/css/bulma-tagsinput.min.css" />
-//!   </head>
-//!   <body>
-//!     <div id="root"></div>
+//! Suggestion sources
+//! - [`Suggest`]: when `suggest` is set, it is queried with the typed text and its future resolves to the
+//!   suggested items. Any `Fn(String) -> impl Future<Output = Vec<T>>` closure implements it, so a backend call or an
+//!   in-memory index can be plugged in directly. Responses to outdated queries are dropped.
+//! - Static list: when `items` is not empty, only those items can be selected. The list is filtered by the
+//!   label of each item against the typed text (honoring `case_sensitive`).
+//! - Remote: when `url_for_fetch` is set, the typed text is URL-encoded and appended to it, and the endpoint is
+//!   fetched with `auth_header` (if any) sent as the `Authorization` header. The response must be a JSON array of
+//!   strings, or of objects whose `data_item_value` / `data_item_text` fields hold the value and the label.
+//! - Free text: with none of the above, pressing Enter (or typing a comma) adds the typed text as a tag.
 //!
-//!     <!-- Bulma TagsInput JS: ensure this runs before your wasm bootstrap so `BulmaTagsInput` exists -->
-//!     <script src="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/js/bulma-tagsinput.min.js"></script>
-//!     <!-- Your wasm/bootstrap script that starts the Yew app -->
-//!     <script type="module">
-//!       import init from './pkg/your_crate.js';
-//!       init();
-//!     </script>
-//!   </body>
-//!   </html>
-//!   ```
+//! The remote and free text sources as well as `current_selector` produce strings, which are turned into items by
+//! [`AutoCompleteItem::from_text`]. Types which do not implement it can only be used with `suggest` and `items`.
 //!
-//! Notes and alternatives
-//! - If you use a bundler (webpack, vite, etc.) you can install bulma-tagsinput from npm and import it in your JS entry:
-//!   npm install @creativebulma/bulma-tagsinput
-//!   // in your entry file
-//!   import '@creativebulma/bulma-tagsinput/dist/css/bulma-tagsinput.min.css';
-//!   import '@creativebulma/bulma-tagsinput/dist/js/bulma-tagsinput.min.js';
-//!   Ensure the import runs before the Yew bootstrap so `BulmaTagsInput` is available globally (or adapt the setup to pass the module).
-//!
-//! - The important requirement: BulmaTagsInput must be defined on window when setup_static_autocomplete / setup_dynamic_autocomplete are called in rendered().
+//! Keyboard
+//! - ArrowDown / ArrowUp move the highlighted suggestion, Enter selects it, Escape closes the list.
+//! - Backspace in an empty input removes the last tag.
 //!
+//! Once `max_items` tags are selected, no more can be added until one is removed.
 
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
-use wasm_bindgen::JsCast;
-use wasm_bindgen::JsValue;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::prelude::wasm_bindgen;
-use web_sys::js_sys::{JSON, Reflect};
-use web_sys::{Element, js_sys};
+
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{self, Reflect};
+use web_sys::{HtmlInputElement, Request, RequestInit, Response};
+use yew::events::{FocusEvent, InputEvent, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
-pub struct AutoComplete {
-    id: Rc<str>,
+use crate::common::inject_style;
+use crate::id::next_component_id;
+use crate::{Delete, ExtraAttrs, Tag};
+
+const AUTOCOMPLETE_STYLE: &str = r#"
+.dropdown.ybc-autocomplete {
+    display: flex;
+    width: 100%;
+}
+
+.ybc-autocomplete .dropdown-trigger,
+.ybc-autocomplete .dropdown-menu {
+    width: 100%;
+}
+
+.ybc-autocomplete .dropdown-content {
+    max-height: 16rem;
+    overflow-y: auto;
+}
+
+.ybc-autocomplete-input.input {
+    flex-wrap: wrap;
+    gap: 0.25rem;
+    height: auto;
+    min-height: 2.5em;
+    cursor: text;
+}
+
+.ybc-autocomplete-input .tag {
+    margin: 0;
+}
+
+.ybc-autocomplete-field {
+    flex: 1 1 6em;
+    min-width: 6em;
+    border: 0;
+    outline: 0;
+    box-shadow: none;
+    background: transparent;
+    color: inherit;
+    font: inherit;
+    padding: 0;
+}
+"#;
+
+/// An item which can be selected in an [`AutoComplete`].
+pub trait AutoCompleteItem: Clone + PartialEq + 'static {
+    /// The text shown in the suggestion list and in the selected tag.
+    fn label(&self) -> String;
+
+    /// The identity used for duplicate detection and emitted through `on_update` / `on_remove`. Defaults to the label.
+    fn key(&self) -> String {
+        self.label()
+    }
+
+    /// Builds an item out of the text produced by the remote and free text sources and by `current_selector`.
+    /// Returns `None` by default, which leaves those sources without suggestions for this type.
+    fn from_text(_value: String) -> Option<Self> {
+        None
+    }
+}
+
+impl AutoCompleteItem for String {
+    fn label(&self) -> String {
+        self.clone()
+    }
+
+    fn from_text(value: String) -> Option<Self> {
+        Some(value)
+    }
+}
+
+/// The future returned by [`Suggest::suggest`].
+pub type SuggestFuture<T> = Pin<Box<dyn Future<Output = Vec<T>>>>;
+
+/// An asynchronous source of suggestions for an [`AutoComplete`].
+pub trait Suggest<T> {
+    /// Returns the items matching the typed `query`.
+    fn suggest(&self, query: &str) -> SuggestFuture<T>;
+}
+
+impl<T, F, Fut> Suggest<T> for F
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Vec<T>> + 'static,
+{
+    fn suggest(&self, query: &str) -> SuggestFuture<T> {
+        Box::pin(self(query.to_owned()))
+    }
+}
+
+/// A shared [`Suggest`] implementation, as accepted by [`AutoCompleteProps::suggest`].
+pub struct SuggestSource<T>(pub Rc<dyn Suggest<T>>);
+
+impl<T> SuggestSource<T> {
+    pub fn new(source: impl Suggest<T> + 'static) -> Self {
+        Self(Rc::new(source))
+    }
+}
+
+impl<T> Clone for SuggestSource<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> std::fmt::Debug for SuggestSource<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SuggestSource(..)")
+    }
+}
+
+impl<T> PartialEq for SuggestSource<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A value/label pair read from a remote JSON suggestion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The value emitted through `on_update` / `on_remove`.
+    pub value: String,
+    /// The label shown in the suggestion list and in the selected tag.
+    pub text: String,
+}
+
+impl Suggestion {
+    fn plain(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self { text: value.clone(), value }
+    }
 }
 
 #[derive(Clone, PartialEq, Properties)]
-pub struct AutoCompleteProps {
+pub struct AutoCompleteProps<T: AutoCompleteItem = String> {
+    /// The id of the text input. Generated automatically when empty.
     #[prop_or("".to_string().into())]
     pub id: Rc<str>,
+    /// The maximum number of tags which may be selected; `0` means no limit.
     #[prop_or(10)]
     pub max_items: u32,
+    /// The static list of selectable items.
+    #[prop_or_default]
+    pub items: Vec<T>,
+    /// Called with the key of every added tag.
     #[prop_or_default]
-    pub items: Vec<String>,
     pub on_update: Callback<String>,
+    /// Called with the key of every removed tag.
+    #[prop_or_default]
     pub on_remove: Callback<String>,
+    /// Called with every added item.
+    #[prop_or_default]
+    pub on_select: Callback<T>,
+    /// Called with every removed item.
+    #[prop_or_default]
+    pub on_deselect: Callback<T>,
+    /// The asynchronous suggestion source; takes precedence over `items` and `url_for_fetch`.
+    #[prop_or_default]
+    pub suggest: Option<SuggestSource<T>>,
+    /// The initially selected value, if any.
     #[prop_or("".to_string().into())]
     pub current_selector: Rc<str>,
+    /// The placeholder shown while no tag is selected.
     #[prop_or("Choose Tags".to_string().into())]
     pub placeholder: Rc<str>,
+    /// Extra classes for the `input` box wrapping the tags.
     #[prop_or(classes ! ("".to_string()))]
     pub classes: Classes,
+    /// Match suggestions and detect duplicates case-sensitively.
     #[prop_or(true)]
     pub case_sensitive: bool,
+    /// The field of remote JSON objects holding the suggestion label.
     #[prop_or("".to_string().into())]
     pub data_item_text: Rc<str>,
+    /// The field of remote JSON objects holding the suggestion value.
     #[prop_or("".to_string().into())]
     pub data_item_value: Rc<str>,
+    /// The url queried for remote suggestions; the typed text is appended to it.
     #[prop_or("".to_string().into())]
     pub url_for_fetch: Rc<str>,
+    /// The `Authorization` header value sent with remote queries.
     #[prop_or("".to_string().into())]
     pub auth_header: Rc<str>,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// An item together with its extracted key and label.
+#[derive(Clone, PartialEq)]
+struct Entry<T> {
+    item: T,
+    key: String,
+    label: String,
+}
+
+impl<T: AutoCompleteItem> Entry<T> {
+    fn new(item: T) -> Self {
+        Self {
+            key: item.key(),
+            label: item.label(),
+            item,
+        }
+    }
+}
+
+/// A tags input offering suggestions from an async source, a static list or a remote JSON endpoint.
+pub struct AutoComplete<T: AutoCompleteItem = String> {
+    id: Rc<str>,
+    input_ref: NodeRef,
+    selected: Vec<Entry<T>>,
+    query: String,
+    remote: Vec<Entry<T>>,
+    highlighted: Option<usize>,
+    open: bool,
+    fetch_seq: u32,
 }
 
-pub enum Msg {
-    Added(String),
-    Removed(String),
+pub enum Msg<T> {
+    Input(String),
+    KeyDown(KeyboardEvent),
+    Focus,
+    Blur,
+    Highlight(usize),
+    Select(usize),
+    Remove(usize),
+    Suggested(u32, Vec<T>),
+    Fetched(u32, Vec<Suggestion>),
 }
 
-impl Component for AutoComplete {
-    type Message = Msg;
-    type Properties = AutoCompleteProps;
+impl<T: AutoCompleteItem> Component for AutoComplete<T> {
+    type Message = Msg<T>;
+    type Properties = AutoCompleteProps<T>;
+
     fn create(ctx: &Context<Self>) -> Self {
-        Self { id: ctx.props().id.clone() }
+        let props = ctx.props();
+        let id = if props.id.is_empty() {
+            Rc::from(next_component_id(ctx, "autocomplete").as_str())
+        } else {
+            props.id.clone()
+        };
+        let selected = if props.current_selector.is_empty() {
+            Vec::new()
+        } else {
+            text_entry(Suggestion::plain(props.current_selector.as_ref())).into_iter().collect()
+        };
+        Self {
+            id,
+            input_ref: NodeRef::default(),
+            selected,
+            query: String::new(),
+            remote: Vec::new(),
+            highlighted: None,
+            open: false,
+            fetch_seq: 0,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            Msg::Added(tag) => {
-                ctx.props().on_update.emit(tag);
-                // gloo_console::log!("Added: {}", tag.as_str());
+            Msg::Input(query) => {
+                if is_free_text(ctx.props()) && query.ends_with(',') {
+                    self.query = query.trim_end_matches(',').to_string();
+                    self.add_free_text(ctx);
+                    return true;
+                }
+                self.query = query;
+                self.open = true;
+                self.highlighted = None;
+                self.request_remote(ctx);
+            }
+            Msg::KeyDown(event) => return self.on_key_down(ctx, event),
+            Msg::Focus => {
+                self.open = true;
+                if ctx.props().suggest.is_some() {
+                    self.request_remote(ctx);
+                }
+            }
+            Msg::Blur => {
+                self.open = false;
+                self.highlighted = None;
             }
-            Msg::Removed(tag) => {
-                ctx.props().on_remove.emit(tag);
-                // gloo_console::log!("Removed: {}", tag.as_str());
+            Msg::Highlight(index) => {
+                self.highlighted = Some(index);
             }
+            Msg::Select(index) => {
+                if let Some(entry) = self.suggestions(ctx.props()).into_iter().nth(index) {
+                    self.add(ctx, entry);
+                }
+            }
+            Msg::Remove(index) => {
+                if index < self.selected.len() {
+                    let removed = self.selected.remove(index);
+                    self.emit_removed(ctx, removed);
+                }
+            }
+            Msg::Suggested(seq, items) => return self.receive(seq, items.into_iter().map(Entry::new).collect()),
+            Msg::Fetched(seq, suggestions) => return self.receive(seq, suggestions.into_iter().filter_map(text_entry).collect()),
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.id != old_props.id && !props.id.is_empty() {
+            self.id = props.id.clone();
+        }
+        if props.current_selector != old_props.current_selector
+            && !props.current_selector.is_empty()
+            && !self.is_full(props)
+            && let Some(entry) = text_entry(Suggestion::plain(props.current_selector.as_ref()))
+            && !self.is_selected(props, &entry.key)
+        {
+            self.selected.push(entry);
         }
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let current_selector = ctx.props().current_selector.to_string();
-        let items = ctx
-            .props()
-            .items
+        let props = ctx.props();
+        let link = ctx.link();
+        let listbox_id = format!("{}-listbox", self.id);
+        let suggestions = self.suggestions(props);
+        let is_expanded = self.open && !suggestions.is_empty();
+        let active_descendant = self
+            .highlighted
+            .filter(|_| is_expanded)
+            .map(|index| format!("{}-option-{}", self.id, index));
+
+        let tags = self
+            .selected
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let onclick = link.callback(move |event: MouseEvent| {
+                    event.stop_propagation();
+                    Msg::Remove(index)
+                });
+                let aria_label = format!("remove {}", entry.label);
+                html! {
+                    <Tag>
+                        {entry.label.clone()}
+                        <Delete classes={classes!("is-small")} aria_label={aria_label} {onclick} />
+                    </Tag>
+                }
+            })
+            .collect::<Html>();
+
+        let options = suggestions
             .iter()
-            .map(|item| {
-                if item == current_selector.as_str() {
-                    html! {
-                         <option value={item.to_string()} selected=true>{item}</option>
-                    }
-                } else {
-                    html! {
-                         <option value={item.to_string()}>{item}</option>
-                    }
+            .enumerate()
+            .map(|(index, entry)| {
+                let is_highlighted = self.highlighted == Some(index);
+                let onmousedown = link.callback(move |event: MouseEvent| {
+                    // Keep the focus on the input so the list does not close before the click lands.
+                    event.prevent_default();
+                    Msg::Select(index)
+                });
+                let onmouseenter = link.callback(move |_: MouseEvent| Msg::Highlight(index));
+                html! {
+                    <a
+                        id={format!("{}-option-{}", self.id, index)}
+                        class={classes!("dropdown-item", is_highlighted.then_some("is-active"))}
+                        role="option"
+                        aria-selected={if is_highlighted { "true" } else { "false" }}
+                        {onmousedown}
+                        {onmouseenter}
+                    >
+                        {entry.label.clone()}
+                    </a>
                 }
             })
             .collect::<Html>();
-        if !ctx.props().items.is_empty() && ctx.props().data_item_text.is_empty() && ctx.props().data_item_value.is_empty() {
-            html! {
-                <div class={classes!(ctx.props().classes.clone(), "select")}>
-                    <select
-                        id={self.id.clone()} data-type="tags"
-                        data-placeholder={ctx.props().placeholder.clone()}
-                        value={current_selector.clone()}
-                        >
-                        {items}
-                    </select>
+
+        let focus_input = {
+            let input_ref = self.input_ref.clone();
+            Callback::from(move |_: MouseEvent| {
+                if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+            })
+        };
+        let oninput = link.callback(|event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            Msg::Input(input.value())
+        });
+        let onkeydown = link.callback(Msg::KeyDown);
+        let onfocus = link.callback(|_: FocusEvent| Msg::Focus);
+        let onblur = link.callback(|_: FocusEvent| Msg::Blur);
+        let placeholder = self.selected.is_empty().then(|| props.placeholder.to_string());
+
+        props.attrs.apply(html! {
+            <div class={classes!("dropdown", "ybc-autocomplete", is_expanded.then_some("is-active"))}>
+                <div class="dropdown-trigger">
+                    <div class={classes!("input", "ybc-autocomplete-input", props.classes.clone())} onclick={focus_input}>
+                        {tags}
+                        <input
+                            id={self.id.to_string()}
+                            ref={self.input_ref.clone()}
+                            class="ybc-autocomplete-field"
+                            type="text"
+                            autocomplete="off"
+                            role="combobox"
+                            aria-autocomplete="list"
+                            aria-expanded={if is_expanded { "true" } else { "false" }}
+                            aria-controls={listbox_id.clone()}
+                            aria-activedescendant={active_descendant}
+                            value={self.query.clone()}
+                            {placeholder}
+                            {oninput}
+                            {onkeydown}
+                            {onfocus}
+                            {onblur}
+                        />
+                    </div>
                 </div>
-            }
-        } else if !ctx.props().data_item_text.is_empty() && !ctx.props().data_item_value.is_empty() {
-            let has_value = !current_selector.is_empty();
-            let value = format!("{{\"{}\":\"{}\"}}", ctx.props().data_item_value, current_selector);
-            html! {
-                   <input type="text"
-                     class={classes!(ctx.props().classes.clone(), "input")}
-                             // data-type="name"
-                          data-item-text={ctx.props().data_item_text.clone()}
-                          data-item-value={ctx.props().data_item_value.clone()}
-                          id={self.id.to_string()} data-placeholder={ctx.props().placeholder.clone()}
-                          value={if has_value {value} else {"{}".to_string()}} />
-            }
+                <div class="dropdown-menu" id={listbox_id} role="listbox">
+                    <div class="dropdown-content">
+                        {options}
+                    </div>
+                </div>
+            </div>
+        })
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            inject_style("ybc-autocomplete-style", AUTOCOMPLETE_STYLE);
+        }
+    }
+}
+
+impl<T: AutoCompleteItem> AutoComplete<T> {
+    fn is_full(&self, props: &AutoCompleteProps<T>) -> bool {
+        props.max_items > 0 && self.selected.len() >= props.max_items as usize
+    }
+
+    fn is_selected(&self, props: &AutoCompleteProps<T>, key: &str) -> bool {
+        self.selected.iter().any(|entry| same_text(&entry.key, key, props.case_sensitive))
+    }
+
+    /// The suggestions currently offered, excluding the already selected ones.
+    fn suggestions(&self, props: &AutoCompleteProps<T>) -> Vec<Entry<T>> {
+        if self.is_full(props) {
+            return Vec::new();
+        }
+        let candidates = if props.suggest.is_some() {
+            self.remote.clone()
+        } else if !props.url_for_fetch.is_empty() {
+            if self.query.is_empty() { Vec::new() } else { self.remote.clone() }
         } else {
-            html! {
-                   <input type="text"
-                     class={classes!(ctx.props().classes.clone(), "input")}
-                          id={self.id.clone()} data-placeholder={ctx.props().placeholder.clone()} value={current_selector.clone()} />
-            }
+            props
+                .items
+                .iter()
+                .cloned()
+                .map(Entry::new)
+                .filter(|entry| contains_text(&entry.label, &self.query, props.case_sensitive))
+                .collect::<Vec<_>>()
+        };
+        candidates.into_iter().filter(|entry| !self.is_selected(props, &entry.key)).collect()
+    }
+
+    fn add(&mut self, ctx: &Context<Self>, entry: Entry<T>) {
+        let props = ctx.props();
+        if self.is_full(props) || self.is_selected(props, &entry.key) {
+            return;
         }
+        self.selected.push(entry.clone());
+        self.query.clear();
+        self.remote.clear();
+        self.highlighted = None;
+        props.on_update.emit(entry.key);
+        props.on_select.emit(entry.item);
     }
 
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        if first_render {
-            let _max_items = ctx.props().max_items;
-            let _case_sensitive = ctx.props().case_sensitive;
-            let _url_for_fetch = ctx.props().url_for_fetch.clone();
-            let _auth_header = ctx.props().auth_header.clone();
-            let window = web_sys::window().expect("no global `window` exists");
-            let document = window.document().expect("should have a document on window");
-
-            let element = document
-                .get_element_by_id(&self.id)
-                .unwrap_or_else(|| panic!("should have #{} on the page", self.id));
-
-            // Clone the link from the context
-            let link = ctx.link().clone();
-
-            // Move the cloned link into the closure
-            let callback = Closure::wrap(Box::new(move |tag: JsValue| {
-                let Some(raw) = tag.as_string() else {
-                    return;
-                };
-                let Ok(parsed) = JSON::parse(raw.as_str()) else {
-                    return;
-                };
-                let Ok(command) = parsed.dyn_into::<js_sys::Object>() else {
-                    return;
-                };
-                let Ok(op) = Reflect::get(&command, &JsValue::from_str("op")) else {
-                    return;
-                };
-                let Ok(value) = Reflect::get(&command, &JsValue::from_str("value")) else {
-                    return;
-                };
-                let Some(value) = value.as_string() else {
-                    return;
-                };
+    fn emit_removed(&self, ctx: &Context<Self>, entry: Entry<T>) {
+        ctx.props().on_remove.emit(entry.key);
+        ctx.props().on_deselect.emit(entry.item);
+    }
+
+    fn add_free_text(&mut self, ctx: &Context<Self>) {
+        let text = self.query.trim().to_string();
+        if let Some(entry) = text_entry(Suggestion::plain(text)).filter(|entry| !entry.key.is_empty()) {
+            self.add(ctx, entry);
+        }
+        self.query.clear();
+    }
 
-                if op.as_string().as_deref() == Some("add") {
-                    link.send_message(Msg::Added(value));
-                } else {
-                    link.send_message(Msg::Removed(value));
+    fn on_key_down(&mut self, ctx: &Context<Self>, event: KeyboardEvent) -> bool {
+        let props = ctx.props();
+        let count = self.suggestions(props).len();
+        match event.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                event.prevent_default();
+                self.open = true;
+                self.highlighted = Some(self.highlighted.map(|index| (index + 1) % count).unwrap_or(0));
+            }
+            "ArrowUp" if count > 0 => {
+                event.prevent_default();
+                self.open = true;
+                self.highlighted = Some(self.highlighted.map(|index| (index + count - 1) % count).unwrap_or(count - 1));
+            }
+            "Enter" => {
+                event.prevent_default();
+                if is_free_text(props) {
+                    self.add_free_text(ctx);
+                } else if let Some(entry) = self.highlighted.and_then(|index| self.suggestions(props).into_iter().nth(index)) {
+                    self.add(ctx, entry);
                 }
-            }) as Box<dyn FnMut(JsValue)>);
-            if _url_for_fetch.is_empty() {
-                setup_static_autocomplete(&element, callback.as_ref(), &JsValue::from(_max_items), &JsValue::from(_case_sensitive));
-            } else {
-                setup_dynamic_autocomplete(
-                    &element,
-                    callback.as_ref(),
-                    &JsValue::from(_max_items),
-                    &JsValue::from(_url_for_fetch.to_string()),
-                    &JsValue::from(_auth_header.to_string()),
-                    &JsValue::from(_case_sensitive),
-                    &JsValue::from(ctx.props().data_item_value.to_string()),
-                    &JsValue::from(ctx.props().current_selector.to_string()),
-                );
             }
-            callback.forget();
+            "Escape" => {
+                self.open = false;
+                self.highlighted = None;
+            }
+            "Backspace" if self.query.is_empty() => {
+                let Some(removed) = self.selected.pop() else {
+                    return false;
+                };
+                self.emit_removed(ctx, removed);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Offer the suggestions answering the query numbered `seq`, unless another query was sent since; returns
+    /// whether they were taken.
+    fn receive(&mut self, seq: u32, entries: Vec<Entry<T>>) -> bool {
+        if seq != self.fetch_seq {
+            return false;
         }
+        self.remote = entries;
+        self.highlighted = None;
+        true
     }
 
-    fn destroy(&mut self, _ctx: &Context<Self>) {
-        detach_autocomplete(&JsValue::from_str(self.id.as_ref()));
+    fn request_remote(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        if props.suggest.is_none() && props.url_for_fetch.is_empty() {
+            return;
+        }
+        self.fetch_seq = self.fetch_seq.wrapping_add(1);
+        let seq = self.fetch_seq;
+        if let Some(source) = props.suggest.as_ref() {
+            let future = source.0.suggest(&self.query);
+            ctx.link().send_future(async move { Msg::Suggested(seq, future.await) });
+            return;
+        }
+        if self.query.is_empty() {
+            self.remote.clear();
+            return;
+        }
+        let url = format!("{}{}", props.url_for_fetch, js_sys::encode_uri_component(&self.query));
+        let auth_header = props.auth_header.to_string();
+        let value_key = props.data_item_value.to_string();
+        let text_key = props.data_item_text.to_string();
+        ctx.link().send_future(async move {
+            let suggestions = fetch_suggestions(&url, &auth_header, &value_key, &text_key).await.unwrap_or_default();
+            Msg::Fetched(seq, suggestions)
+        });
     }
 }
 
-#[wasm_bindgen(inline_js = r#"
-let init = new Map();
-export function setup_dynamic_autocomplete(element, callback, max_tags, url_for_fetch, auth_header, case_sensitive, data_item_value, initial_value) {
-    // Attach Bulma autocomplete here
-    // console.log('Setting up dynamic autocomplete ID:' + element.id + ' fetch:' + url_for_fetch + ' auth:' + auth_header + ' case:' + case_sensitive + ' max:' + max_tags);
-     if (!init.has(element.id)) {
-		 // console.log('Setting up dynamic autocomplete ID:' + element.id);
-         let autocompleteInstance = BulmaTagsInput.attach( element, {
-            maxTags: max_tags,
-            caseSensitive: case_sensitive,
-            source: async function(value) {
-                // console.log('Fetching data for:'+value);
-				return await fetch(url_for_fetch + value)
-					.then(function(response) {
-					    if (response.status !== 200) {
-                            throw new Error('Failed to fetch data');
-                        }
-						return response.json();
-					});},
-         });
-           let autocomplete = autocompleteInstance[0];
-           // console.log('Attached autocomplete:'+element.id + ' ' + autocomplete);
-            autocomplete.on('after.add', function(tag) {
-                // console.log(tag);
-                callback('{"op":"add","value":"'+tag.item[data_item_value]+'"}');
-            });
-            autocomplete.on('after.remove', function(tag) {
-                // console.log(tag);
-                callback('{"op":"remove","value":"'+tag[data_item_value]+'"}');
-            });
-            if (initial_value.length > 0) {
-                autocomplete.add('{"'+data_item_value+'":"'+initial_value+'"}');
-            }
+fn is_free_text<T: AutoCompleteItem>(props: &AutoCompleteProps<T>) -> bool {
+    props.suggest.is_none() && props.items.is_empty() && props.url_for_fetch.is_empty()
+}
 
-          init.set(element.id, autocomplete);
-     }
+/// Builds an entry out of a string suggestion through [`AutoCompleteItem::from_text`]; a suggestion without a label
+/// of its own shows the label of the item.
+fn text_entry<T: AutoCompleteItem>(suggestion: Suggestion) -> Option<Entry<T>> {
+    let item = T::from_text(suggestion.value.clone())?;
+    if suggestion.text == suggestion.value {
+        return Some(Entry::new(item));
+    }
+    Some(Entry {
+        key: item.key(),
+        label: suggestion.text,
+        item,
+    })
 }
 
-export function setup_static_autocomplete(element, callback, max_tags, case_sensitive) {
-    // Attach Bulma autocomplete here
-    // console.log('Setting up static autocomplete ID:' + element.id + ' case:' + case_sensitive + ' max:' + max_tags);
-     if (!init.has(element.id)) {
-         let autocompleteInstance = BulmaTagsInput.attach( element, {
-            maxTags: max_tags,
-            caseSensitive: case_sensitive,
-         });
-           let autocomplete = autocompleteInstance[0];
-           // console.log('Attached autocomplete:'+element.id + ' ' + autocomplete);
-            autocomplete.on('after.add', function(tag) {
-                // console.log(tag);
-                if (tag.item && tag.item.value) {
-                    callback('{"op":"add","value":"'+tag.item.value+'"}');
-                } else if (tag.value) {
-                    callback('{"op":"add","value":"'+tag.value+'"}');
-                } else {
-                    callback('{"op":"add","value":"'+tag.item+'"}');
-                }
-            });
-            autocomplete.on('after.remove', function(tag) {
-                // console.log(tag);
-                if (tag.item && tag.item.value) {
-                    callback('{"op":"remove","value":"'+tag.item.value+'"}');
-                } else if (tag.value) {
-                    callback('{"op":"remove","value":"'+tag.value+'"}');
-                } else {
-                    callback('{"op":"remove","value":"'+tag+'"}');
-                }
-            });
+fn same_text(a: &str, b: &str, case_sensitive: bool) -> bool {
+    if case_sensitive { a == b } else { a.to_lowercase() == b.to_lowercase() }
+}
 
-          init.set(element.id, autocomplete);
+fn contains_text(haystack: &str, needle: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        haystack.contains(needle)
+    } else {
+        haystack.to_lowercase().contains(&needle.to_lowercase())
+    }
+}
 
-     }
+async fn fetch_suggestions(url: &str, auth_header: &str, value_key: &str, text_key: &str) -> Result<Vec<Suggestion>, JsValue> {
+    let init = RequestInit::new();
+    init.set_method("GET");
+    let request = Request::new_with_str_and_init(url, &init)?;
+    if !auth_header.is_empty() {
+        request.headers().set("Authorization", auth_header)?;
+    }
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no global `window` exists"))?;
+    let response: Response = JsFuture::from(window.fetch_with_request(&request)).await?.dyn_into()?;
+    if !response.ok() {
+        return Err(JsValue::from_str("Failed to fetch data"));
+    }
+    let json = JsFuture::from(response.json()?).await?;
+    Ok(parse_suggestions(&json, value_key, text_key))
 }
 
-export function detach_autocomplete(id) {
-   init.delete(id);
-   // console.log('Detached autocomplete:'+id);
+fn parse_suggestions(json: &JsValue, value_key: &str, text_key: &str) -> Vec<Suggestion> {
+    let Some(entries) = json.dyn_ref::<js_sys::Array>() else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            if let Some(value) = entry.as_string() {
+                return Some(Suggestion::plain(value));
+            }
+            let value = field_as_string(&entry, value_key)?;
+            let text = field_as_string(&entry, text_key).unwrap_or_else(|| value.clone());
+            Some(Suggestion { value, text })
+        })
+        .collect()
 }
 
-"#)]
-extern "C" {
-    fn setup_dynamic_autocomplete(
-        element: &Element, callback: &JsValue, max_tags: &JsValue, url_to_fetch: &JsValue, auth_header: &JsValue, case_sensitive: &JsValue,
-        data_item_value: &JsValue, initial_value: &JsValue,
-    );
-    fn setup_static_autocomplete(element: &Element, callback: &JsValue, max_tags: &JsValue, case_sensitive: &JsValue);
-    fn detach_autocomplete(id: &JsValue);
+fn field_as_string(entry: &JsValue, key: &str) -> Option<String> {
+    if key.is_empty() {
+        return None;
+    }
+    let field = Reflect::get(entry, &JsValue::from_str(key)).ok()?;
+    field.as_string().or_else(|| field.as_f64().map(|number| number.to_string()))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use yew::props;
+
+    use super::*;
+    use crate::testing::{Queries, Rendered, render_blocking};
+
+    /// A ticket whose key is its number, built from texts like `"#42"`.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Ticket(u32);
+
+    impl AutoCompleteItem for Ticket {
+        fn label(&self) -> String {
+            format!("Ticket {}", self.0)
+        }
+
+        fn key(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn from_text(value: String) -> Option<Self> {
+            value.strip_prefix('#')?.parse().ok().map(Ticket)
+        }
+    }
+
+    #[test]
+    fn text_entries_are_keyed_like_the_items() {
+        let suggestion = Suggestion {
+            value: "#42".into(),
+            text: "Crash on start".into(),
+        };
+        let entry = text_entry::<Ticket>(suggestion).unwrap();
+        assert_eq!(entry.item, Ticket(42));
+        assert_eq!(entry.key, Entry::new(Ticket(42)).key);
+        assert_eq!(entry.label, "Crash on start");
+        assert!(text_entry::<Ticket>(Suggestion::plain("42")).is_none());
+        assert_eq!(text_entry::<Ticket>(Suggestion::plain("#7")).unwrap().label, "Ticket 7");
+
+        let entry = text_entry::<String>(Suggestion::plain("rust")).unwrap();
+        assert_eq!((entry.key.as_str(), entry.label.as_str()), ("rust", "rust"));
+    }
+
+    fn languages() -> Vec<String> {
+        ["Rust", "Ruby", "Go", "rustls"].map(String::from).to_vec()
+    }
+
+    /// An open autocomplete with `query` typed and `selected` tags.
+    fn typed<T: AutoCompleteItem>(query: &str, selected: Vec<T>) -> AutoComplete<T> {
+        AutoComplete {
+            id: "languages".into(),
+            input_ref: NodeRef::default(),
+            selected: selected.into_iter().map(Entry::new).collect(),
+            query: query.into(),
+            remote: Vec::new(),
+            highlighted: None,
+            open: true,
+            fetch_seq: 0,
+        }
+    }
+
+    fn labels<T>(entries: Vec<Entry<T>>) -> Vec<String> {
+        entries.into_iter().map(|entry| entry.label).collect()
+    }
+
+    fn options(rendered: &Rendered) -> Vec<String> {
+        rendered.get_all_by_role("option").iter().map(Queries::text).collect()
+    }
+
+    fn tags(rendered: &Rendered) -> Vec<String> {
+        rendered.find_all(|element| element.has_class("tag")).iter().map(Queries::text).collect()
+    }
+
+    #[test]
+    fn static_items_are_filtered_by_the_typed_text() {
+        let props = props!(AutoCompleteProps<String> { items: languages() });
+        assert_eq!(labels(typed::<String>("", vec![]).suggestions(&props)), ["Rust", "Ruby", "Go", "rustls"]);
+        assert_eq!(labels(typed::<String>("Ru", vec![]).suggestions(&props)), ["Rust", "Ruby"]);
+        assert_eq!(labels(typed::<String>("ust", vec![]).suggestions(&props)), ["Rust", "rustls"]);
+        assert!(typed::<String>("Java", vec![]).suggestions(&props).is_empty());
+    }
+
+    #[test]
+    fn case_insensitive_matching_covers_filtering_and_duplicates() {
+        let sensitive = props!(AutoCompleteProps<String> { items: languages() });
+        let insensitive = props!(AutoCompleteProps<String> { items: languages(), case_sensitive: false });
+        assert_eq!(labels(typed::<String>("ru", vec![]).suggestions(&sensitive)), ["rustls"]);
+        assert_eq!(
+            labels(typed::<String>("ru", vec![]).suggestions(&insensitive)),
+            ["Rust", "Ruby", "rustls"]
+        );
+
+        let selected = typed("ru", vec!["RUST".to_string()]);
+        assert!(!selected.is_selected(&sensitive, "Rust"));
+        assert!(selected.is_selected(&insensitive, "Rust"));
+        assert_eq!(labels(selected.suggestions(&insensitive)), ["Ruby", "rustls"]);
+    }
+
+    #[test]
+    fn selected_items_are_not_offered_again() {
+        let props = props!(AutoCompleteProps<String> { items: languages() });
+        let autocomplete = typed("", vec!["Go".to_string(), "Rust".to_string()]);
+        assert!(autocomplete.is_selected(&props, "Go"));
+        assert_eq!(labels(autocomplete.suggestions(&props)), ["Ruby", "rustls"]);
+    }
+
+    #[test]
+    fn full_autocompletes_offer_nothing() {
+        let props = props!(AutoCompleteProps<String> { items: languages(), max_items: 2 });
+        let autocomplete = typed("", vec!["Go".to_string(), "Rust".to_string()]);
+        assert!(autocomplete.is_full(&props));
+        assert!(autocomplete.suggestions(&props).is_empty());
+
+        let unlimited = props!(AutoCompleteProps<String> { items: languages(), max_items: 0 });
+        assert!(!autocomplete.is_full(&unlimited));
+        assert_eq!(labels(autocomplete.suggestions(&unlimited)), ["Ruby", "rustls"]);
+    }
+
+    #[test]
+    fn only_the_latest_query_is_answered() {
+        let suggest = SuggestSource::new(|_: String| async { Vec::<Ticket>::new() });
+        let props = props!(AutoCompleteProps<Ticket> { suggest: Some(suggest) });
+        let mut autocomplete = typed::<Ticket>("", vec![Ticket(1)]);
+        autocomplete.fetch_seq = 2;
+        autocomplete.highlighted = Some(0);
+
+        assert!(!autocomplete.receive(1, vec![Entry::new(Ticket(7))]));
+        assert!(autocomplete.suggestions(&props).is_empty());
+        assert_eq!(autocomplete.highlighted, Some(0));
+
+        assert!(autocomplete.receive(2, vec![Entry::new(Ticket(1)), Entry::new(Ticket(2))]));
+        assert_eq!(labels(autocomplete.suggestions(&props)), ["Ticket 2"]);
+        assert_eq!(autocomplete.highlighted, None);
+    }
+
+    #[test]
+    fn renders_the_current_selector_as_a_tag_and_the_rest_as_options() {
+        let rendered = render_blocking(|| html! { <AutoComplete items={languages()} current_selector={Rc::from("Go")} /> });
+        assert_eq!(tags(&rendered), ["Go"]);
+        assert_eq!(options(&rendered), ["Rust", "Ruby", "rustls"]);
+        assert!(rendered.query_by_label("remove Go").is_some());
+        let input = rendered.get_by_role("combobox");
+        assert_eq!(input.attr("aria-expanded").as_deref(), Some("false"));
+        assert_eq!(input.attr("placeholder"), None);
+
+        let empty = render_blocking(|| html! { <AutoComplete items={languages()} /> });
+        assert!(tags(&empty).is_empty());
+        assert_eq!(empty.get_by_role("combobox").attr("placeholder").as_deref(), Some("Choose Tags"));
+    }
+
+    #[test]
+    fn renders_no_options_once_max_items_are_selected() {
+        let rendered = render_blocking(|| html! { <AutoComplete items={languages()} current_selector={Rc::from("Go")} max_items={1} /> });
+        assert_eq!(tags(&rendered), ["Go"]);
+        assert!(options(&rendered).is_empty());
+    }
+
+    #[test]
+    fn renders_typed_items_with_their_labels() {
+        let rendered = render_blocking(|| {
+            html! { <AutoComplete<Ticket> items={vec![Ticket(1), Ticket(2)]} current_selector={Rc::from("#2")} /> }
+        });
+        assert_eq!(tags(&rendered), ["Ticket 2"]);
+        assert_eq!(options(&rendered), ["Ticket 1"]);
+    }
 }
@@ -1,7 +1,7 @@
 use derive_more::Display;
 use yew::prelude::*;
 
-use crate::Alignment;
+use crate::{Alignment, ExtraAttrs};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct BreadcrumbProps {
@@ -10,6 +10,9 @@ pub struct BreadcrumbProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<BreadcrumbSize>,
@@ -33,13 +36,13 @@ pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
         props.alignment.as_ref().map(|alignment| alignment.to_string()),
         props.separator.as_ref().map(|separator| separator.to_string()),
     );
-    html! {
+    props.attrs.apply(html! {
         <nav {class} aria-label="breadcrumbs">
             <ul>
                 {props.children.clone()}
             </ul>
         </nav>
-    }
+    })
 }
 
 /// The 3 sizes available for a breadcrumb.
@@ -1,362 +1,1509 @@
 /*!
-Calendar component: a thin Yew wrapper around the bulma-calendar JS date/time picker.
+Calendar component: a native Yew date/time picker built from Bulma elements.
 
 Summary
-- Enhances a plain `<input>` with bulmaCalendar for date and time selection.
-- Emits changes through a Rust callback whenever the user selects, validates, or clears.
-- Requires bulmaCalendar JS and CSS to be loaded globally (available as `bulmaCalendar`).
+- Renders a read-only Bulma `input` showing the formatted value; clicking it (or pressing Enter/ArrowDown) opens a
+  month grid below it.
+- Supports the `date`, `time`, `datetime` and `range` picker types.
+- Emits the formatted string through `on_date_changed` and the typed [`CalendarValue`] through `on_change`.
+- Needs no external JavaScript or CSS beyond Bulma.
 
 Value format
-- The emitted string follows the configured `date_format` and `time_format` patterns understood by bulmaCalendar.
-- Clearing the picker emits an empty string.
+- The formatted string follows `date_format` (default `yyyy-MM-dd`) and `time_format` (default `HH:mm`).
+  A `datetime` value joins both with a space and a `range` value joins both dates with ` - `.
+- Supported pattern tokens: `yyyy`, `yy`, `MMMM` (month name), `MMM` (short month name), `MM`, `M`, `dd`, `d`,
+  `HH`, `H`, `mm`. Any other character is copied verbatim.
+- Clearing the picker emits an empty string and `None`.
 
 Programmatic control
-- To update the picker value from the outside, update the `date` prop.
-- To clear the picker from the outside, set `date` to a single space `" "`.
-
-Required static assets
-- CSS (add in `<head>`):
-  https://cdn.jsdelivr.net/npm/bulma-calendar@7.1.1/dist/css/bulma-calendar.min.css
-- JS (load before WASM bootstrap so `bulmaCalendar` exists):
-  https://cdn.jsdelivr.net/npm/bulma-calendar@7.1.1/dist/js/bulma-calendar.min.js
+- To update the picker value from the outside, update the `date` prop; it is parsed with the same patterns.
+- To clear the picker from the outside, set `date` to an empty string or a single space `" "`.
+
+Range mode
+- With `calendar_type="range"` the first click picks the start and the second click the end; while choosing the
+  end, the days up to the hovered (or keyboard-focused) day are previewed. Clicking a day before the start restarts
+  the selection there, so the end is never before the start.
+- `start`/`end` set the range from the outside and `on_range_changed` emits the typed [`CalendarRange`].
+- `range_presets` lists shortcuts such as [`RangePreset::last_days`] and [`RangePreset::this_month`] above the grid.
+
+Constraints and localization
+- `min_date`, `max_date`, `disabled_dates` and `is_date_disabled` make days unselectable.
+- `week_start` selects the first column of the grid.
+- `locale` is a BCP 47 language tag used for month and weekday names (through the browser's `Intl` support);
+  `locale_names` overrides the names explicitly.
+- `today` sets the date marked as today and used by the presets. Without it, the browser's date is read once mounted,
+  so server-side rendered and hydrated markup do not depend on either clock.
+
+Keyboard
+- In the grid, arrow keys move by day/week, PageUp/PageDown by month, Enter or Space selects and Escape closes.
 */
 
+use std::fmt::Write as _;
+use std::rc::Rc;
+
+use web_sys::HtmlElement;
+use yew::events::{Event, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+pub use crate::common::TestAttr;
+use crate::common::use_style;
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::JsValue;
 #[cfg(target_arch = "wasm32")]
-use wasm_bindgen::closure::Closure;
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::wasm_bindgen;
-#[cfg(target_arch = "wasm32")]
-use web_sys::Element;
+use web_sys::js_sys::{self, Object, Reflect};
 
-#[cfg(target_arch = "wasm32")]
-type CalendarClosure = Closure<dyn FnMut(JsValue)>;
-#[cfg(not(target_arch = "wasm32"))]
-type CalendarClosure = ();
+/// A calendar date; `month` and `day` are 1-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
 
-/// Optional test attribute rendered on the input.
-///
-/// Supported keys:
-/// - `data-testid`
-/// - `data-cy`
+impl CalendarDate {
+    /// Returns the date if it exists in the proleptic Gregorian calendar.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)).then_some(Self { year, month, day })
+    }
+
+    /// Today's date in the local time zone of the browser (UTC outside of the browser).
+    pub fn today() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let now = js_sys::Date::new_0();
+            Self {
+                year: now.get_full_year() as i32,
+                month: now.get_month() + 1,
+                day: now.get_date(),
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs() as i64)
+                .unwrap_or_default();
+            Self::from_days(secs.div_euclid(86_400))
+        }
+    }
+
+    /// The day of the week of this date.
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::from_index((self.to_days() + 4).rem_euclid(7) as usize)
+    }
+
+    /// Returns this date moved by `days` days.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Returns this date moved by `months` months, clamping the day to the length of the target month.
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// The first day of the month of this date.
+    pub fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// The last day of the month of this date.
+    pub fn last_of_month(&self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..*self
+        }
+    }
+
+    /// The number of days since 1970-01-01.
+    pub fn to_days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date `days` days after 1970-01-01.
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    fn iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A time of day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarTime {
+    pub hour: u32,
+    pub minute: u32,
+}
+
+/// The days of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    /// The position of this day in a Sunday-first week.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        [
+            Self::Sunday,
+            Self::Monday,
+            Self::Tuesday,
+            Self::Wednesday,
+            Self::Thursday,
+            Self::Friday,
+            Self::Saturday,
+        ][index % 7]
+    }
+}
+
+/// Month and weekday names used by a [`Calendar`].
 #[derive(Clone, Debug, PartialEq)]
-pub struct TestAttr {
-    pub key: AttrValue,
-    pub value: AttrValue,
+pub struct CalendarLocale {
+    /// The twelve month names, January first.
+    pub months: Vec<AttrValue>,
+    /// The seven short weekday names, Sunday first.
+    pub weekdays: Vec<AttrValue>,
 }
 
-impl TestAttr {
-    pub fn test_id(value: impl Into<AttrValue>) -> Self {
+impl CalendarLocale {
+    /// English month and weekday names.
+    pub fn english() -> Self {
+        let months = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let weekdays = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
         Self {
-            key: AttrValue::from("data-testid"),
-            value: value.into(),
+            months: months.into_iter().map(AttrValue::from).collect(),
+            weekdays: weekdays.into_iter().map(AttrValue::from).collect(),
         }
     }
 
-    pub fn data_cy(value: impl Into<AttrValue>) -> Self {
+    /// Names for the given BCP 47 language tag, as provided by the browser's `Intl` support.
+    ///
+    /// Falls back to [`CalendarLocale::english`] outside of the browser or for an empty tag.
+    pub fn for_language(tag: &str) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if !tag.is_empty() {
+                let name = |date: js_sys::Date, key: &str, style: &str| -> AttrValue {
+                    let options = Object::new();
+                    let _ = Reflect::set(&options, &JsValue::from_str(key), &JsValue::from_str(style));
+                    AttrValue::from(String::from(date.to_locale_date_string(tag, &options)))
+                };
+                // 2021-01-03 was a Sunday.
+                return Self {
+                    months: (0..12)
+                        .map(|month| name(js_sys::Date::new_with_year_month_day(2021, month, 1), "month", "long"))
+                        .collect(),
+                    weekdays: (0..7)
+                        .map(|day| name(js_sys::Date::new_with_year_month_day(2021, 0, 3 + day), "weekday", "short"))
+                        .collect(),
+                };
+            }
+        }
+        let _ = tag;
+        Self::english()
+    }
+
+    fn month(&self, month: u32) -> AttrValue {
+        self.months.get(month as usize - 1).cloned().unwrap_or_default()
+    }
+}
+
+/// A value selected in a [`Calendar`], together with its formatted representation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarValue {
+    /// The selected date, or the start of the selected range.
+    pub date: Option<CalendarDate>,
+    /// The end of the selected range in `range` mode.
+    pub end_date: Option<CalendarDate>,
+    /// The selected time in `time` and `datetime` modes.
+    pub time: Option<CalendarTime>,
+    /// The value formatted with `date_format` / `time_format`, as emitted by `on_date_changed`.
+    pub formatted: String,
+}
+
+/// An inclusive range of dates whose `end` is never before its `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CalendarRange {
+    start: CalendarDate,
+    end: CalendarDate,
+}
+
+impl CalendarRange {
+    /// Returns the range, or `None` if `end` is before `start`.
+    pub fn new(start: CalendarDate, end: CalendarDate) -> Option<Self> {
+        (end >= start).then_some(Self { start, end })
+    }
+
+    /// The first day of the range.
+    pub fn start(&self) -> CalendarDate {
+        self.start
+    }
+
+    /// The last day of the range.
+    pub fn end(&self) -> CalendarDate {
+        self.end
+    }
+
+    /// The number of days in the range, counting both ends.
+    pub fn days(&self) -> i64 {
+        self.end.to_days() - self.start.to_days() + 1
+    }
+
+    /// Whether `date` lies within the range.
+    pub fn contains(&self, date: CalendarDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Returns the range narrowed to `min..=max`, or `None` if nothing is left.
+    fn clamp(&self, min: Option<CalendarDate>, max: Option<CalendarDate>) -> Option<Self> {
+        let start = min.map_or(self.start, |min| self.start.max(min));
+        let end = max.map_or(self.end, |max| self.end.min(max));
+        Self::new(start, end)
+    }
+}
+
+/// A named shortcut shown next to the grid in `range` mode, resolved relative to today's date.
+///
+/// ```rust,ignore
+/// let presets = vec![
+///     RangePreset::last_days("Last 7 days", 7),
+///     RangePreset::this_month("This month"),
+///     RangePreset::new("Year to date", |today: CalendarDate| {
+///         CalendarRange::new(CalendarDate::new(today.year, 1, 1).unwrap(), today).unwrap()
+///     }),
+/// ];
+/// ```
+#[derive(Clone)]
+pub struct RangePreset {
+    /// The button label.
+    pub label: AttrValue,
+    resolve: Rc<dyn Fn(CalendarDate) -> CalendarRange>,
+}
+
+impl RangePreset {
+    /// A preset computing its range from today's date.
+    pub fn new(label: impl Into<AttrValue>, resolve: impl Fn(CalendarDate) -> CalendarRange + 'static) -> Self {
         Self {
-            key: AttrValue::from("data-cy"),
-            value: value.into(),
+            label: label.into(),
+            resolve: Rc::new(resolve),
         }
     }
+
+    /// A preset always selecting the same range.
+    pub fn fixed(label: impl Into<AttrValue>, range: CalendarRange) -> Self {
+        Self::new(label, move |_| range)
+    }
+
+    /// The last `days` days, ending today.
+    pub fn last_days(label: impl Into<AttrValue>, days: u32) -> Self {
+        Self::new(label, move |today: CalendarDate| CalendarRange {
+            start: today.add_days(1 - i64::from(days.max(1))),
+            end: today,
+        })
+    }
+
+    /// The current month, from its first to its last day.
+    pub fn this_month(label: impl Into<AttrValue>) -> Self {
+        Self::new(label, |today: CalendarDate| CalendarRange {
+            start: today.first_of_month(),
+            end: today.last_of_month(),
+        })
+    }
+
+    /// The previous month, from its first to its last day.
+    pub fn last_month(label: impl Into<AttrValue>) -> Self {
+        Self::new(label, |today: CalendarDate| {
+            let month = today.first_of_month().add_months(-1);
+            CalendarRange {
+                start: month,
+                end: month.last_of_month(),
+            }
+        })
+    }
+
+    /// The range this preset selects when today is `today`.
+    pub fn resolve(&self, today: CalendarDate) -> CalendarRange {
+        (self.resolve)(today)
+    }
 }
 
-impl<T> From<T> for TestAttr
-where
-    T: Into<AttrValue>,
-{
-    fn from(value: T) -> Self {
-        Self::test_id(value)
+impl std::fmt::Debug for RangePreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangePreset").field("label", &self.label).finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RangePreset {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.resolve, &other.resolve)
     }
 }
 
 /// Properties for [`Calendar`].
 #[derive(Clone, PartialEq, Properties)]
 pub struct CalendarProps {
-    /// Unique DOM id for the input (used to attach/detach the JS widget).
+    /// Unique DOM id for the input.
     pub id: String,
 
-    /// Date format understood by bulmaCalendar. Defaults to `yyyy-MM-dd` when empty.
+    /// Date pattern used to format and parse values. Defaults to `yyyy-MM-dd` when empty.
     #[prop_or_default]
     pub date_format: AttrValue,
 
-    /// Time format understood by bulmaCalendar. Defaults to `HH:mm` when empty.
+    /// Time pattern used to format and parse values. Defaults to `HH:mm` when empty.
     #[prop_or_default]
     pub time_format: AttrValue,
 
-    /// Optional initial/current value to seed or update the widget.
+    /// Optional initial/current value to seed or update the picker.
     #[prop_or_default]
     pub date: Option<String>,
 
     /// Callback invoked when the date/time changes; receives empty string on clear.
     pub on_date_changed: Callback<String>,
 
+    /// Callback invoked with the typed value when the date/time changes; receives `None` on clear.
+    #[prop_or_default]
+    pub on_change: Callback<Option<CalendarValue>>,
+
+    /// The start of the selected range in `range` mode; takes precedence over `date`.
+    #[prop_or_default]
+    pub start: Option<CalendarDate>,
+
+    /// The end of the selected range in `range` mode. Ignored when before `start`.
+    #[prop_or_default]
+    pub end: Option<CalendarDate>,
+
+    /// Callback invoked in `range` mode once both ends are chosen; receives `None` on clear.
+    #[prop_or_default]
+    pub on_range_changed: Callback<Option<CalendarRange>>,
+
+    /// Shortcuts listed next to the grid in `range` mode, e.g. [`RangePreset::last_days`].
+    #[prop_or_default]
+    pub range_presets: Vec<RangePreset>,
+
+    /// The date treated as today. When `None`, the browser's local date is read once mounted; until then no day is
+    /// marked as today and no presets are listed.
+    #[prop_or_default]
+    pub today: Option<CalendarDate>,
+
     /// Extra classes appended after Bulma `input`.
     #[prop_or_default]
     pub class: Vec<String>,
 
-    /// Optional test attribute on the input (`data-testid` or `data-cy`).
+    /// An extra attribute on the input, e.g. a `data-testid`, `data-*` or `aria-*` attribute.
     #[prop_or_default]
     pub test_attr: Option<TestAttr>,
 
-    /// Picker type (`date`, `time`, `datetime`).
+    /// Picker type (`date`, `time`, `datetime`, `range`).
     /// If empty, defaults to `datetime` when `time_format` is present, otherwise `date`.
     #[prop_or_default]
     pub calendar_type: AttrValue,
+
+    /// The earliest selectable date.
+    #[prop_or_default]
+    pub min_date: Option<CalendarDate>,
+
+    /// The latest selectable date.
+    #[prop_or_default]
+    pub max_date: Option<CalendarDate>,
+
+    /// Dates which cannot be selected.
+    #[prop_or_default]
+    pub disabled_dates: Vec<CalendarDate>,
+
+    /// Predicate returning `true` for dates which cannot be selected.
+    #[prop_or_default]
+    pub is_date_disabled: Option<Callback<CalendarDate, bool>>,
+
+    /// The first day of the week in the grid.
+    #[prop_or(Weekday::Sunday)]
+    pub week_start: Weekday,
+
+    /// BCP 47 language tag for month and weekday names. English when empty.
+    #[prop_or_default]
+    pub locale: AttrValue,
+
+    /// Explicit month and weekday names; takes precedence over `locale`.
+    #[prop_or_default]
+    pub locale_names: Option<CalendarLocale>,
+
+    /// Step of the minute selector.
+    #[prop_or(1)]
+    pub minute_step: u32,
+
+    /// The placeholder of the input.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Extra attributes, e.g. a test id, for the root element; `test_attr` stays on the input.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
-/// A date/time input enhanced by bulma-calendar.
-#[function_component(Calendar)]
-pub fn calendar(props: &CalendarProps) -> Html {
-    let input_ref = use_node_ref();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PickerType {
+    Date,
+    Time,
+    DateTime,
+    Range,
+}
 
-    let date_format_raw = props.date_format.trim().to_string();
-    assert!(
-        date_format_raw.is_empty() || date_format_raw == "yyyy-MM-dd",
-        "Calendar date_format must be exactly 'yyyy-MM-dd' (lowercase yyyy-MM-dd). Got '{}'",
-        props.date_format
-    );
+impl PickerType {
+    fn has_date(self) -> bool {
+        self != Self::Time
+    }
 
-    let date_format = if date_format_raw.is_empty() {
-        "yyyy-MM-dd".to_owned()
-    } else {
-        date_format_raw
-    };
+    fn has_time(self) -> bool {
+        matches!(self, Self::Time | Self::DateTime)
+    }
+}
 
-    let time_format_raw = props.time_format.trim().to_string();
-    let time_format = if time_format_raw.is_empty() {
-        "HH:mm".to_owned()
-    } else {
-        time_format_raw.clone()
-    };
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Selection {
+    date: Option<CalendarDate>,
+    end_date: Option<CalendarDate>,
+    time: Option<CalendarTime>,
+}
 
-    let calendar_type = {
-        let explicit = props.calendar_type.trim();
-        if explicit.is_empty() {
-            if props.time_format.trim().is_empty() {
-                "date".to_owned()
-            } else {
-                "datetime".to_owned()
-            }
-        } else {
-            explicit.to_owned()
-        }
-    };
+impl Selection {
+    fn is_empty(&self) -> bool {
+        self.date.is_none() && self.time.is_none()
+    }
+}
 
-    let initial_value = props.date.clone().unwrap_or_default();
-    let class = classes!("input", props.class.clone());
+const CALENDAR_STYLE: &str = r#"
+.dropdown.ybc-calendar {
+    display: flex;
+}
+
+.ybc-calendar .dropdown-trigger,
+.ybc-calendar .dropdown-trigger .input {
+    width: 100%;
+    cursor: pointer;
+}
+
+.ybc-calendar .dropdown-content {
+    padding: 0.75rem;
+    min-width: 18rem;
+}
+
+.ybc-calendar-nav {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    margin-bottom: 0.5rem;
+}
+
+.ybc-calendar-grid {
+    display: grid;
+    grid-template-columns: repeat(7, 1fr);
+    gap: 0.125rem;
+    text-align: center;
+}
+
+.ybc-calendar-grid [role="row"] {
+    display: contents;
+}
+
+.ybc-calendar-weekday {
+    font-size: 0.75rem;
+    font-weight: 600;
+    padding: 0.25rem 0;
+}
+
+.ybc-calendar-day.button {
+    width: 100%;
+    padding: 0;
+}
+
+.ybc-calendar-day.is-outside {
+    opacity: 0.45;
+}
+
+.ybc-calendar-day.is-today {
+    font-weight: 700;
+    text-decoration: underline;
+}
+
+.ybc-calendar-presets {
+    margin-bottom: 0.5rem;
+}
+
+.ybc-calendar-time {
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    gap: 0.5rem;
+    margin-top: 0.75rem;
+}
+
+.ybc-calendar-footer {
+    display: flex;
+    justify-content: space-between;
+    margin-top: 0.75rem;
+}
+"#;
 
-    let (data_testid, data_cy) = match props.test_attr.as_ref() {
-        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
-        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
-        _ => (None, None),
+/// A native date/time picker showing a Bulma-styled month grid.
+#[function_component(Calendar)]
+pub fn calendar(props: &CalendarProps) -> Html {
+    use_style("ybc-calendar-style", CALENDAR_STYLE);
+    let date_format = non_empty_or(&props.date_format, "yyyy-MM-dd");
+    let time_format = non_empty_or(&props.time_format, "HH:mm");
+    let picker_type = match props.calendar_type.trim() {
+        "time" => PickerType::Time,
+        "datetime" => PickerType::DateTime,
+        "range" => PickerType::Range,
+        "date" => PickerType::Date,
+        _ if props.time_format.trim().is_empty() => PickerType::Date,
+        _ => PickerType::DateTime,
+    };
+    let names = {
+        let locale = props.locale.clone();
+        let locale_names = props.locale_names.clone();
+        use_memo((locale, locale_names), |(locale, locale_names)| {
+            locale_names.clone().unwrap_or_else(|| CalendarLocale::for_language(locale))
+        })
     };
 
-    let input_type = if props.time_format.trim().is_empty() {
-        AttrValue::from("date")
-    } else {
-        AttrValue::from("datetime")
+    let selection = {
+        let date_format = date_format.clone();
+        let time_format = time_format.clone();
+        let names = names.clone();
+        let date = props.date.clone();
+        let range = (props.start, props.end);
+        use_state(move || {
+            range_selection(range, picker_type)
+                .unwrap_or_else(|| parse_selection(date.as_deref().unwrap_or_default(), picker_type, &date_format, &time_format, &names))
+        })
     };
+    let open = use_state(|| false);
+    let today = use_state(|| props.today);
+    let cursor = use_state(|| selection.date.or(props.today));
+    let hovered = use_state(|| None::<CalendarDate>);
+    let grid_ref = use_node_ref();
 
-    let callback_store = use_mut_ref(|| None::<CalendarClosure>);
-    let on_date_changed_ref = use_mut_ref(|| props.on_date_changed.clone());
-    *on_date_changed_ref.borrow_mut() = props.on_date_changed.clone();
+    {
+        let today = today.clone();
+        // Effects only run in the browser, so the clock never makes server and hydration renders differ.
+        use_effect_with(props.today, move |date| {
+            today.set(Some(date.unwrap_or_else(CalendarDate::today)));
+            || {}
+        });
+    }
+    let today = *today;
+    // The day the grid is centred on: the keyboard cursor, or today while nothing has been chosen.
+    let shown = (*cursor).or(today);
 
     {
-        let id = props.id.clone();
-        let input_ref = input_ref.clone();
-        let callback_store = callback_store.clone();
-        let on_date_changed_ref = on_date_changed_ref.clone();
+        let selection = selection.clone();
+        let cursor = cursor.clone();
         let date_format = date_format.clone();
         let time_format = time_format.clone();
-        let calendar_type = calendar_type.clone();
-        let initial_value = initial_value.clone();
-
-        use_effect_with(
-            (id.clone(), date_format.clone(), time_format.clone(), calendar_type.clone()),
-            move |(id, date_format, time_format, calendar_type)| {
-                #[cfg(target_arch = "wasm32")]
-                {
-                    if let Some(element) = input_ref.cast::<Element>() {
-                        let on_date_changed_ref = on_date_changed_ref.clone();
-                        let callback = Closure::wrap(Box::new(move |date: JsValue| {
-                            let s = date.as_string().unwrap_or_default();
-                            on_date_changed_ref.borrow().emit(s);
-                        }) as Box<dyn FnMut(JsValue)>);
-
-                        setup_date_picker(
-                            &element,
-                            callback.as_ref(),
-                            &JsValue::from(initial_value.clone()),
-                            &JsValue::from(date_format.clone()),
-                            &JsValue::from(time_format.clone()),
-                            &JsValue::from(calendar_type.clone()),
-                        );
-
-                        *callback_store.borrow_mut() = Some(callback);
-                    }
-
-                    let callback_store = callback_store.clone();
-                    let id_for_cleanup = id.clone();
-                    return move || {
-                        detach_date_picker(&JsValue::from(id_for_cleanup.as_str()));
-                        callback_store.borrow_mut().take();
-                    };
+        let names = names.clone();
+        use_effect_with(props.date.clone(), move |date| {
+            if let Some(date) = date {
+                let parsed = parse_selection(date, picker_type, &date_format, &time_format, &names);
+                if let Some(start) = parsed.date {
+                    cursor.set(Some(start));
                 }
-
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    let _ = (
-                        &input_ref,
-                        &callback_store,
-                        &on_date_changed_ref,
-                        &initial_value,
-                        id,
-                        date_format,
-                        time_format,
-                        calendar_type,
-                    );
-                    || {}
-                }
-            },
-        );
+                selection.set(parsed);
+            }
+            || {}
+        });
     }
 
     {
-        let id = props.id.clone();
-        let date = props.date.clone();
-        use_effect_with((id, date), move |(id, date)| {
-            #[cfg(target_arch = "wasm32")]
-            {
-                match date.as_deref() {
-                    Some(" ") | Some("") => {
-                        clear_date(&JsValue::from(id.as_str()));
-                    }
-                    Some(v) => {
-                        update_value(&JsValue::from(id.as_str()), &JsValue::from(v));
+        let selection = selection.clone();
+        let cursor = cursor.clone();
+        let has_date = props.date.is_some();
+        use_effect_with((props.start, props.end), move |range| {
+            match range_selection(*range, picker_type) {
+                Some(next) => {
+                    if let Some(start) = next.date {
+                        cursor.set(Some(start));
                     }
-                    None => {}
+                    selection.set(next);
                 }
+                None if picker_type == PickerType::Range && !has_date => selection.set(Selection::default()),
+                None => {}
             }
-            #[cfg(not(target_arch = "wasm32"))]
+            || {}
+        });
+    }
+
+    {
+        let grid_ref = grid_ref.clone();
+        use_effect_with((shown, *open), move |(cursor, open)| {
+            if *open
+                && let Some(cursor) = cursor
+                && let Some(grid) = grid_ref.cast::<HtmlElement>()
+                && let Ok(Some(day)) = grid.query_selector(&format!("[data-date='{}']", cursor.iso()))
+                && let Some(day) = wasm_bindgen::JsCast::dyn_ref::<HtmlElement>(&day)
             {
-                let _ = (id, date);
+                let _ = day.focus();
             }
-
             || {}
         });
     }
 
-    html! {
+    let is_disabled = {
+        let min_date = props.min_date;
+        let max_date = props.max_date;
+        let disabled_dates = props.disabled_dates.clone();
+        let predicate = props.is_date_disabled.clone();
+        move |date: CalendarDate| {
+            min_date.is_some_and(|min| date < min)
+                || max_date.is_some_and(|max| date > max)
+                || disabled_dates.contains(&date)
+                || predicate.as_ref().is_some_and(|predicate| predicate.emit(date))
+        }
+    };
+
+    let commit = {
+        let selection = selection.clone();
+        let on_date_changed = props.on_date_changed.clone();
+        let on_change = props.on_change.clone();
+        let on_range_changed = props.on_range_changed.clone();
+        let date_format = date_format.clone();
+        let time_format = time_format.clone();
+        let names = names.clone();
+        Callback::from(move |next: Selection| {
+            selection.set(next);
+            if picker_type == PickerType::Range {
+                let range = next.date.zip(next.end_date).and_then(|(start, end)| CalendarRange::new(start, end));
+                on_range_changed.emit(range);
+            }
+            if next.is_empty() {
+                on_date_changed.emit(String::new());
+                on_change.emit(None);
+                return;
+            }
+            let formatted = format_selection(&next, picker_type, &date_format, &time_format, &names);
+            on_date_changed.emit(formatted.clone());
+            on_change.emit(Some(CalendarValue {
+                date: next.date,
+                end_date: next.end_date,
+                time: next.time,
+                formatted,
+            }));
+        })
+    };
+
+    let select_day = {
+        let commit = commit.clone();
+        let selection = selection.clone();
+        let open = open.clone();
+        let cursor = cursor.clone();
+        let is_disabled = is_disabled.clone();
+        Callback::from(move |date: CalendarDate| {
+            if is_disabled(date) {
+                return;
+            }
+            cursor.set(Some(date));
+            let current = *selection;
+            match picker_type {
+                PickerType::Range => match (current.date, current.end_date) {
+                    (Some(start), None) if date >= start => {
+                        commit.emit(Selection {
+                            date: Some(start),
+                            end_date: Some(date),
+                            time: None,
+                        });
+                        open.set(false);
+                    }
+                    _ => selection.set(Selection {
+                        date: Some(date),
+                        end_date: None,
+                        time: None,
+                    }),
+                },
+                PickerType::DateTime => commit.emit(Selection {
+                    date: Some(date),
+                    end_date: None,
+                    time: Some(current.time.unwrap_or_default()),
+                }),
+                _ => {
+                    commit.emit(Selection {
+                        date: Some(date),
+                        end_date: None,
+                        time: None,
+                    });
+                    open.set(false);
+                }
+            }
+        })
+    };
+
+    let select_range = {
+        let commit = commit.clone();
+        let open = open.clone();
+        let cursor = cursor.clone();
+        let min_date = props.min_date;
+        let max_date = props.max_date;
+        Callback::from(move |range: CalendarRange| {
+            if let Some(range) = range.clamp(min_date, max_date) {
+                cursor.set(Some(range.start()));
+                commit.emit(Selection {
+                    date: Some(range.start()),
+                    end_date: Some(range.end()),
+                    time: None,
+                });
+                open.set(false);
+            }
+        })
+    };
+
+    let set_time = {
+        let commit = commit.clone();
+        let selection = selection.clone();
+        Callback::from(move |time: CalendarTime| {
+            let current = *selection;
+            let date = match picker_type {
+                PickerType::DateTime => Some(current.date.or(today).unwrap_or_else(CalendarDate::today)),
+                _ => None,
+            };
+            commit.emit(Selection {
+                date,
+                end_date: None,
+                time: Some(time),
+            });
+        })
+    };
+
+    let toggle = {
+        let open = open.clone();
+        let disabled = props.disabled;
+        Callback::from(move |_: MouseEvent| {
+            if !disabled {
+                open.set(!*open);
+            }
+        })
+    };
+
+    let on_input_keydown = {
+        let open = open.clone();
+        let disabled = props.disabled;
+        Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+            "Enter" | " " | "ArrowDown" if !disabled => {
+                event.prevent_default();
+                open.set(true);
+            }
+            "Escape" => open.set(false),
+            _ => {}
+        })
+    };
+
+    let on_grid_keydown = {
+        let cursor = cursor.clone();
+        let hovered = hovered.clone();
+        let open = open.clone();
+        let select_day = select_day.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let Some(current) = shown else {
+                return;
+            };
+            let next = match event.key().as_str() {
+                "ArrowLeft" => current.add_days(-1),
+                "ArrowRight" => current.add_days(1),
+                "ArrowUp" => current.add_days(-7),
+                "ArrowDown" => current.add_days(7),
+                "PageUp" => current.add_months(-1),
+                "PageDown" => current.add_months(1),
+                "Enter" | " " => {
+                    event.prevent_default();
+                    select_day.emit(current);
+                    return;
+                }
+                "Escape" => {
+                    event.prevent_default();
+                    open.set(false);
+                    return;
+                }
+                _ => return,
+            };
+            event.prevent_default();
+            cursor.set(Some(next));
+            hovered.set(Some(next));
+        })
+    };
+
+    let on_grid_leave = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(None))
+    };
+
+    let move_month = |months: i32| {
+        let cursor = cursor.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(shown) = shown {
+                cursor.set(Some(shown.add_months(months).first_of_month()));
+            }
+        })
+    };
+
+    let on_today = {
+        let select_day = select_day.clone();
+        let set_time = set_time.clone();
+        Callback::from(move |_: MouseEvent| {
+            if picker_type == PickerType::Time {
+                set_time.emit(CalendarTime::default());
+            } else {
+                select_day.emit(today.unwrap_or_else(CalendarDate::today));
+            }
+        })
+    };
+
+    let on_clear = {
+        let commit = commit.clone();
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| {
+            commit.emit(Selection::default());
+            open.set(false);
+        })
+    };
+
+    let on_close = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(false))
+    };
+
+    let formatted = if selection.is_empty() {
+        String::new()
+    } else {
+        format_selection(&selection, picker_type, &date_format, &time_format, &names)
+    };
+    let class = classes!("input", props.class.clone());
+    let popup_id = format!("{}-picker", props.id);
+    let placeholder = (!props.placeholder.is_empty()).then(|| props.placeholder.clone());
+
+    let day_grid = if let Some(shown) = shown.filter(|_| picker_type.has_date()) {
+        let month_start = shown.first_of_month();
+        let offset = (month_start.weekday().index() + 7 - props.week_start.index()) % 7;
+        let grid_start = month_start.add_days(-(offset as i64));
+        let weekdays = (0..7)
+            .map(|column| {
+                let name = names.weekdays.get((props.week_start.index() + column) % 7).cloned().unwrap_or_default();
+                html! { <div class="ybc-calendar-weekday" role="columnheader">{name}</div> }
+            })
+            .collect::<Html>();
+        let weeks = (0..6)
+            .map(|week| {
+                let days = (0..7)
+                    .map(|column| {
+                        let date = grid_start.add_days(week * 7 + column);
+                        let is_start = selection.date == Some(date);
+                        let is_end = selection.end_date == Some(date);
+                        let in_range = matches!((selection.date, selection.end_date), (Some(start), Some(end)) if date > start && date < end);
+                        let in_preview = matches!((selection.date, selection.end_date, *hovered), (Some(start), None, Some(hovered)) if date > start && date <= hovered);
+                        let is_selected = is_start || is_end;
+                        let class = classes!(
+                            "button",
+                            "is-small",
+                            "ybc-calendar-day",
+                            if is_selected {
+                                Some("is-link")
+                            } else if in_range || in_preview {
+                                Some("is-link is-light")
+                            } else {
+                                Some("is-white")
+                            },
+                            in_preview.then_some("is-preview"),
+                            (date.month != month_start.month).then_some("is-outside"),
+                            (Some(date) == today).then_some("is-today"),
+                        );
+                        let onclick = {
+                            let select_day = select_day.clone();
+                            Callback::from(move |_: MouseEvent| select_day.emit(date))
+                        };
+                        let onmouseenter = {
+                            let hovered = hovered.clone();
+                            Callback::from(move |_: MouseEvent| hovered.set(Some(date)))
+                        };
+                        html! {
+                            <div role="gridcell" aria-selected={if is_selected || in_range { "true" } else { "false" }}>
+                                <button
+                                    type="button"
+                                    {class}
+                                    data-date={date.iso()}
+                                    aria-label={date.iso()}
+                                    tabindex={if date == shown { "0" } else { "-1" }}
+                                    disabled={is_disabled(date)}
+                                    {onclick}
+                                    {onmouseenter}
+                                >
+                                    {date.day}
+                                </button>
+                            </div>
+                        }
+                    })
+                    .collect::<Html>();
+                html! { <div role="row">{days}</div> }
+            })
+            .collect::<Html>();
+        html! {
+            <>
+                <div class="ybc-calendar-nav">
+                    <button type="button" class="button is-small is-white" aria-label="previous month" onclick={move_month(-1)}>{"‹"}</button>
+                    <span class="has-text-weight-semibold" aria-live="polite">
+                        {format!("{} {}", names.month(month_start.month), month_start.year)}
+                    </span>
+                    <button type="button" class="button is-small is-white" aria-label="next month" onclick={move_month(1)}>{"›"}</button>
+                </div>
+                <div class="ybc-calendar-grid" role="grid" ref={grid_ref.clone()} onkeydown={on_grid_keydown} onmouseleave={on_grid_leave}>
+                    <div role="row">{weekdays}</div>
+                    {weeks}
+                </div>
+            </>
+        }
+    } else {
+        Html::default()
+    };
+
+    let presets = if picker_type == PickerType::Range
+        && !props.range_presets.is_empty()
+        && let Some(today) = today
+    {
+        let buttons = props
+            .range_presets
+            .iter()
+            .map(|preset| {
+                let range = preset.resolve(today);
+                let is_active = selection.date == Some(range.start()) && selection.end_date == Some(range.end());
+                let onclick = {
+                    let select_range = select_range.clone();
+                    Callback::from(move |_: MouseEvent| select_range.emit(range))
+                };
+                html! {
+                    <button
+                        type="button"
+                        class={classes!("button", "is-small", if is_active { "is-link" } else { "is-white" })}
+                        aria-pressed={if is_active { "true" } else { "false" }}
+                        disabled={range.clamp(props.min_date, props.max_date).is_none()}
+                        {onclick}
+                    >
+                        {preset.label.clone()}
+                    </button>
+                }
+            })
+            .collect::<Html>();
+        html! { <div class="ybc-calendar-presets buttons are-small" role="group" aria-label="presets">{buttons}</div> }
+    } else {
+        Html::default()
+    };
+
+    let time_picker = if picker_type.has_time() {
+        let time = selection.time.unwrap_or_default();
+        let step = props.minute_step.clamp(1, 60);
+        let on_hour = {
+            let set_time = set_time.clone();
+            Callback::from(move |event: Event| {
+                let hour = select_value(&event).parse().unwrap_or(time.hour);
+                set_time.emit(CalendarTime { hour, ..time });
+            })
+        };
+        let on_minute = {
+            let set_time = set_time.clone();
+            Callback::from(move |event: Event| {
+                let minute = select_value(&event).parse().unwrap_or(time.minute);
+                set_time.emit(CalendarTime { minute, ..time });
+            })
+        };
+        let hours = (0..24)
+            .map(|hour| html! { <option value={hour.to_string()} selected={hour == time.hour}>{format!("{:02}", hour)}</option> })
+            .collect::<Html>();
+        let minutes = (0..60)
+            .filter(|minute| minute % step == 0 || *minute == time.minute)
+            .map(|minute| html! { <option value={minute.to_string()} selected={minute == time.minute}>{format!("{:02}", minute)}</option> })
+            .collect::<Html>();
+        html! {
+            <div class="ybc-calendar-time">
+                <div class="select is-small">
+                    <select aria-label="hours" onchange={on_hour}>{hours}</select>
+                </div>
+                {":"}
+                <div class="select is-small">
+                    <select aria-label="minutes" onchange={on_minute}>{minutes}</select>
+                </div>
+            </div>
+        }
+    } else {
+        Html::default()
+    };
+
+    let overlay = if *open {
+        html! {
+            <div
+                onclick={on_close.clone()}
+                style="z-index:10;background-color:rgba(0,0,0,0);position:fixed;top:0;bottom:0;left:0;right:0;"
+            ></div>
+        }
+    } else {
+        Html::default()
+    };
+
+    let input = props.test_attr.clone().map(ExtraAttrs::from).unwrap_or_default().apply(html! {
         <input
             id={props.id.clone()}
-            class={class}
-            type={input_type}
-            value={initial_value}
-            ref={input_ref}
-            data-testid={data_testid}
-            data-cy={data_cy}
+            {class}
+            type="text"
+            readonly=true
+            value={formatted}
+            {placeholder}
+            disabled={props.disabled}
+            aria-haspopup="dialog"
+            aria-expanded={if *open { "true" } else { "false" }}
+            aria-controls={popup_id.clone()}
+            onclick={toggle}
+            onkeydown={on_input_keydown}
         />
-    }
+    });
+
+    props.attrs.apply(html! {
+        <div class={classes!("dropdown", "ybc-calendar", open.then_some("is-active"))}>
+            {overlay}
+            <div class="dropdown-trigger">
+                {input}
+            </div>
+            <div class="dropdown-menu" id={popup_id} role="dialog" aria-modal="false" aria-label="choose date">
+                <div class="dropdown-content">
+                    {presets}
+                    {day_grid}
+                    {time_picker}
+                    <div class="ybc-calendar-footer">
+                        <button type="button" class="button is-small is-text" onclick={on_today}>
+                            {if picker_type == PickerType::Time { "Midnight" } else { "Today" }}
+                        </button>
+                        <button type="button" class="button is-small is-text" onclick={on_clear}>{"Clear"}</button>
+                        <button type="button" class="button is-small is-link is-light" onclick={on_close}>{"Close"}</button>
+                    </div>
+                </div>
+            </div>
+        </div>
+    })
 }
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen(inline_js = r#"
-let init = new Map();
+fn range_selection((start, end): (Option<CalendarDate>, Option<CalendarDate>), picker_type: PickerType) -> Option<Selection> {
+    let start = start.filter(|_| picker_type == PickerType::Range)?;
+    Some(Selection {
+        date: Some(start),
+        end_date: end.filter(|end| *end >= start),
+        time: None,
+    })
+}
 
-export function setup_date_picker(element, callback, initial_date, date_format, time_format, picker_type) {
-    if (!element || !element.id) {
-        return;
-    }
+fn non_empty_or(value: &AttrValue, default: &str) -> String {
+    let value = value.trim();
+    if value.is_empty() { default.to_owned() } else { value.to_owned() }
+}
 
-    if (typeof bulmaCalendar === 'undefined' || typeof bulmaCalendar.attach !== 'function') {
-        console.warn('bulmaCalendar is not available on window. Calendar will remain a plain input.');
-        return;
+fn select_value(event: &Event) -> String {
+    event.target_unchecked_into::<web_sys::HtmlSelectElement>().value()
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
+}
 
-    if (!init.has(element.id)) {
-        const instances = bulmaCalendar.attach(element, {
-            type: picker_type || (String(time_format || '').trim() ? 'datetime' : 'date'),
-            color: 'info',
-            lang: 'en',
-            dateFormat: date_format,
-            timeFormat: time_format,
-            showTodayButton: false
-        });
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Year4,
+    Year2,
+    MonthName,
+    MonthShort,
+    Month2,
+    Month1,
+    Day2,
+    Day1,
+    Hour2,
+    Hour1,
+    Minute2,
+    Literal(char),
+}
 
-        if (!instances || !instances.length) {
-            return;
+fn tokenize(pattern: &str) -> Vec<Token> {
+    const TOKENS: [(&str, Token); 11] = [
+        ("yyyy", Token::Year4),
+        ("yy", Token::Year2),
+        ("MMMM", Token::MonthName),
+        ("MMM", Token::MonthShort),
+        ("MM", Token::Month2),
+        ("M", Token::Month1),
+        ("dd", Token::Day2),
+        ("d", Token::Day1),
+        ("HH", Token::Hour2),
+        ("H", Token::Hour1),
+        ("mm", Token::Minute2),
+    ];
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    'outer: while let Some(c) = rest.chars().next() {
+        for (text, token) in TOKENS {
+            if let Some(tail) = rest.strip_prefix(text) {
+                tokens.push(token);
+                rest = tail;
+                continue 'outer;
+            }
         }
+        tokens.push(Token::Literal(c));
+        rest = &rest[c.len_utf8()..];
+    }
+    tokens
+}
+
+fn format_date(date: CalendarDate, pattern: &str, names: &CalendarLocale) -> String {
+    let mut out = String::new();
+    for token in tokenize(pattern) {
+        let _ = match token {
+            Token::Year4 => write!(out, "{:04}", date.year),
+            Token::Year2 => write!(out, "{:02}", date.year.rem_euclid(100)),
+            Token::MonthName => write!(out, "{}", names.month(date.month)),
+            Token::MonthShort => write!(out, "{}", names.month(date.month).chars().take(3).collect::<String>()),
+            Token::Month2 => write!(out, "{:02}", date.month),
+            Token::Month1 => write!(out, "{}", date.month),
+            Token::Day2 => write!(out, "{:02}", date.day),
+            Token::Day1 => write!(out, "{}", date.day),
+            Token::Literal(c) => write!(out, "{}", c),
+            Token::Hour2 | Token::Hour1 | Token::Minute2 => Ok(()),
+        };
+    }
+    out
+}
 
-        const calendarInstance = instances[0];
-        init.set(element.id, calendarInstance);
+fn format_time(time: CalendarTime, pattern: &str) -> String {
+    let mut out = String::new();
+    for token in tokenize(pattern) {
+        let _ = match token {
+            Token::Hour2 => write!(out, "{:02}", time.hour),
+            Token::Hour1 => write!(out, "{}", time.hour),
+            Token::Minute2 => write!(out, "{:02}", time.minute),
+            Token::Literal(c) => write!(out, "{}", c),
+            _ => Ok(()),
+        };
+    }
+    out
+}
 
-        calendarInstance.on('select', function(datepicker) {
-            callback(datepicker.data.value());
-        });
+fn format_selection(selection: &Selection, picker_type: PickerType, date_format: &str, time_format: &str, names: &CalendarLocale) -> String {
+    let date = selection.date.map(|date| format_date(date, date_format, names)).unwrap_or_default();
+    let time = selection.time.map(|time| format_time(time, time_format)).unwrap_or_default();
+    match picker_type {
+        PickerType::Date => date,
+        PickerType::Time => time,
+        PickerType::DateTime => format!("{} {}", date, time).trim().to_owned(),
+        PickerType::Range => match selection.end_date {
+            Some(end) => format!("{} - {}", date, format_date(end, date_format, names)),
+            None => date,
+        },
+    }
+}
 
-        calendarInstance.on('clear', function(_datepicker) {
-            callback('');
-        });
+#[derive(Default)]
+struct Parsed {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+}
 
-        calendarInstance.on('validate', function(datepicker) {
-            callback(datepicker.data.value());
-            if (typeof calendarInstance.hide === 'function') {
-                calendarInstance.hide();
-            }
-        });
+/// Parses `input` against `pattern`, returning the parsed fields and the unparsed remainder.
+fn parse_fields<'a>(input: &'a str, pattern: &str, names: &CalendarLocale) -> Option<(Parsed, &'a str)> {
+    fn number(input: &str, max_digits: usize) -> Option<(u32, &str)> {
+        let digits = input.chars().take(max_digits).take_while(char::is_ascii_digit).count();
+        let value = input[..digits].parse().ok()?;
+        Some((value, &input[digits..]))
     }
 
-    if (init.has(element.id)) {
-        init.get(element.id).value(initial_date || '');
+    let mut parsed = Parsed::default();
+    let mut rest = input.trim_start();
+    for token in tokenize(pattern) {
+        match token {
+            Token::Year4 => {
+                let (value, tail) = number(rest, 4)?;
+                parsed.year = Some(value as i32);
+                rest = tail;
+            }
+            Token::Year2 => {
+                let (value, tail) = number(rest, 2)?;
+                parsed.year = Some(2000 + value as i32);
+                rest = tail;
+            }
+            Token::MonthName | Token::MonthShort => {
+                let word_len = rest
+                    .chars()
+                    .take_while(|c| c.is_alphabetic() || *c == '.')
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+                let word = rest[..word_len].trim_end_matches('.').to_lowercase();
+                let index = names
+                    .months
+                    .iter()
+                    .position(|month| !word.is_empty() && month.to_lowercase().starts_with(&word))?;
+                parsed.month = Some(index as u32 + 1);
+                rest = &rest[word_len..];
+            }
+            Token::Month2 | Token::Month1 => {
+                let (value, tail) = number(rest, 2)?;
+                parsed.month = Some(value);
+                rest = tail;
+            }
+            Token::Day2 | Token::Day1 => {
+                let (value, tail) = number(rest, 2)?;
+                parsed.day = Some(value);
+                rest = tail;
+            }
+            Token::Hour2 | Token::Hour1 => {
+                let (value, tail) = number(rest, 2)?;
+                parsed.hour = Some(value);
+                rest = tail;
+            }
+            Token::Minute2 => {
+                let (value, tail) = number(rest, 2)?;
+                parsed.minute = Some(value);
+                rest = tail;
+            }
+            Token::Literal(c) => {
+                rest = rest.strip_prefix(c)?;
+            }
+        }
     }
+    Some((parsed, rest))
+}
+
+fn parse_date<'a>(input: &'a str, pattern: &str, names: &CalendarLocale) -> Option<(CalendarDate, &'a str)> {
+    let (parsed, rest) = parse_fields(input, pattern, names).or_else(|| parse_fields(input, "yyyy-MM-dd", names))?;
+    let date = CalendarDate::new(parsed.year?, parsed.month?, parsed.day?)?;
+    Some((date, rest))
+}
+
+fn parse_time(input: &str, pattern: &str, names: &CalendarLocale) -> Option<CalendarTime> {
+    let (parsed, _) = parse_fields(input, pattern, names).or_else(|| parse_fields(input, "HH:mm", names))?;
+    let hour = parsed.hour.filter(|hour| *hour < 24)?;
+    let minute = parsed.minute.unwrap_or_default().min(59);
+    Some(CalendarTime { hour, minute })
 }
 
-export function detach_date_picker(id) {
-    if (init.has(id)) {
-        const instance = init.get(id);
-        if (instance && typeof instance.destroy === 'function') {
-            // bulma-calendar's destroy() does document.getElementById(id).remove().
-            // When a Yew component that hosts a Calendar unmounts, Yew tears down
-            // the DOM node before this cleanup effect runs, so getElementById
-            // returns null and .remove() throws an uncaught TypeError. The
-            // instance is being discarded anyway, so swallow a teardown failure
-            // rather than surface it (observed navigating away from a page with a
-            // date picker, e.g. the keys dashboard -> another route).
-            try {
-                instance.destroy();
-            } catch (_) {
-                /* element already removed by the framework on unmount */
+fn parse_selection(input: &str, picker_type: PickerType, date_format: &str, time_format: &str, names: &CalendarLocale) -> Selection {
+    let input = input.trim();
+    if input.is_empty() {
+        return Selection::default();
+    }
+    match picker_type {
+        PickerType::Date => Selection {
+            date: parse_date(input, date_format, names).map(|(date, _)| date),
+            ..Selection::default()
+        },
+        PickerType::Time => Selection {
+            time: parse_time(input, time_format, names),
+            ..Selection::default()
+        },
+        PickerType::DateTime => match parse_date(input, date_format, names) {
+            Some((date, rest)) => Selection {
+                date: Some(date),
+                end_date: None,
+                time: Some(parse_time(rest, time_format, names).unwrap_or_default()),
+            },
+            None => Selection::default(),
+        },
+        PickerType::Range => {
+            let Some((start, rest)) = parse_date(input, date_format, names) else {
+                return Selection::default();
+            };
+            let end = rest
+                .trim_start()
+                .strip_prefix('-')
+                .and_then(|rest| parse_date(rest, date_format, names))
+                .map(|(end, _)| end)
+                .filter(|end| *end >= start);
+            Selection {
+                date: Some(start),
+                end_date: end,
+                time: None,
             }
         }
-        init.delete(id);
     }
 }
 
-export function clear_date(id) {
-    if (init.has(id)) {
-        init.get(id).clear();
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
     }
-}
 
-export function update_value(id, value) {
-    if (init.has(id)) {
-        init.get(id).value(value || '');
+    fn range(start: CalendarDate, end: CalendarDate) -> CalendarRange {
+        CalendarRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn day_numbers_round_trip() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(date(1969, 12, 31).to_days(), -1);
+        assert_eq!(CalendarDate::from_days(0), date(1970, 1, 1));
+        assert_eq!(CalendarDate::from_days(11_016), date(2000, 2, 29));
+        for days in (-800_000..800_000).step_by(997) {
+            assert_eq!(CalendarDate::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn leap_years_and_month_ends() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2000, 2, 29).is_some());
+        assert!(CalendarDate::new(1900, 2, 29).is_none());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(2024, 4, 31).is_none());
+        assert!(CalendarDate::new(2024, 13, 1).is_none());
+        assert!(CalendarDate::new(2024, 1, 0).is_none());
+
+        assert_eq!(date(1900, 2, 28).add_days(1), date(1900, 3, 1));
+        assert_eq!(date(2000, 2, 28).add_days(1), date(2000, 2, 29));
+        assert_eq!(date(2024, 12, 31).add_days(1), date(2025, 1, 1));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31).add_months(-13), date(2023, 2, 28));
+        assert_eq!(date(2024, 2, 10).last_of_month(), date(2024, 2, 29));
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2024, 2, 29).weekday(), Weekday::Thursday);
+    }
+
+    #[test]
+    fn patterns_tokenize_longest_first() {
+        assert_eq!(
+            tokenize("d MMM yy"),
+            [Token::Day1, Token::Literal(' '), Token::MonthShort, Token::Literal(' '), Token::Year2]
+        );
+        assert_eq!(tokenize("MMMMM"), [Token::MonthName, Token::Month1]);
+        assert_eq!(tokenize("é"), [Token::Literal('é')]);
+    }
+
+    #[test]
+    fn dates_format_and_parse_back() {
+        let names = CalendarLocale::english();
+        let day = date(2024, 3, 5);
+        assert_eq!(format_date(day, "yyyy-MM-dd", &names), "2024-03-05");
+        assert_eq!(format_date(day, "d MMM yy", &names), "5 Mar 24");
+        assert_eq!(format_date(day, "MMMM d, yyyy", &names), "March 5, 2024");
+        for pattern in ["yyyy-MM-dd", "d MMM yy", "MMMM d, yyyy", "dd.MM.yyyy"] {
+            let formatted = format_date(day, pattern, &names);
+            assert_eq!(parse_date(&formatted, pattern, &names), Some((day, "")), "{}", pattern);
+        }
+        assert_eq!(parse_date("5 mar. 24", "d MMM yy", &names).map(|(date, _)| date), Some(day));
     }
-}
-"#)]
-#[allow(improper_ctypes, improper_ctypes_definitions)]
-extern "C" {
-    fn setup_date_picker(
-        element: &Element, callback: &JsValue, initial_date: &JsValue, date_format: &JsValue, time_format: &JsValue, picker_type: &JsValue,
-    );
 
-    fn detach_date_picker(id: &JsValue);
+    #[test]
+    fn bad_input_is_rejected() {
+        let names = CalendarLocale::english();
+        assert_eq!(parse_date("2023-02-29", "yyyy-MM-dd", &names), None);
+        assert_eq!(parse_date("2024-13-01", "yyyy-MM-dd", &names), None);
+        assert_eq!(parse_date("2024/03/05", "yyyy-MM-dd", &names), None);
+        assert_eq!(parse_date("abc", "yyyy-MM-dd", &names), None);
+        assert_eq!(parse_date("", "yyyy-MM-dd", &names), None);
+        assert_eq!(parse_date("5 Foo 24", "d MMM yy", &names), None);
+        assert!(parse_fields("12:3x", "HH:mm", &names).is_some_and(|(_, rest)| rest == "x"));
+        assert_eq!(parse_time("24:00", "HH:mm", &names), None);
+        // Falls back to the ISO pattern.
+        assert_eq!(
+            parse_date("2024-03-05", "dd.MM.yyyy", &names).map(|(date, _)| date),
+            Some(date(2024, 3, 5))
+        );
+        assert_eq!(
+            parse_selection("2024-03-05 - 2024-03-01", PickerType::Range, "yyyy-MM-dd", "HH:mm", &names).end_date,
+            None
+        );
+    }
+
+    #[test]
+    fn presets_resolve_relative_to_today() {
+        let today = date(2024, 3, 3);
+        assert_eq!(RangePreset::last_days("", 7).resolve(today), range(date(2024, 2, 26), today));
+        assert_eq!(RangePreset::last_days("", 0).resolve(today), range(today, today));
+        assert_eq!(
+            RangePreset::this_month("").resolve(date(2024, 2, 10)),
+            range(date(2024, 2, 1), date(2024, 2, 29))
+        );
+        assert_eq!(
+            RangePreset::last_month("").resolve(date(2024, 3, 31)),
+            range(date(2024, 2, 1), date(2024, 2, 29))
+        );
+        assert_eq!(
+            RangePreset::last_month("").resolve(date(2024, 1, 15)),
+            range(date(2023, 12, 1), date(2023, 12, 31))
+        );
+    }
 
-    fn clear_date(id: &JsValue);
+    #[test]
+    fn today_is_not_read_while_rendering() {
+        let unmarked = render_blocking(|| html! { <Calendar id="due" on_date_changed={Callback::noop()} /> });
+        assert!(unmarked.find_all(|element| element.has_class("is-today")).is_empty());
+        assert!(unmarked.query_by_role("grid").is_none());
 
-    fn update_value(id: &JsValue, value: &JsValue);
+        let marked = render_blocking(|| {
+            html! { <Calendar id="due" on_date_changed={Callback::noop()} today={date(2024, 2, 29)} /> }
+        });
+        let day = marked.get_by_role_and_name("button", "2024-02-29");
+        assert!(day.has_class("is-today"));
+        assert_eq!(day.attr("tabindex").as_deref(), Some("0"));
+    }
 }
@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct CardProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// An all-around flexible and composable component; this is the card container.
@@ -13,11 +18,11 @@ pub struct CardProps {
 /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
 #[component(Card)]
 pub fn card(props: &CardProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("card", props.classes.clone())}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -29,6 +34,9 @@ pub struct CardHeaderProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for card header content; rendered as a horizontal bar with a shadow.
@@ -36,11 +44,11 @@ pub struct CardHeaderProps {
 /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
 #[component(CardHeader)]
 pub fn card_header(props: &CardHeaderProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <header class={classes!("card-header", props.classes.clone())}>
             {props.children.clone()}
         </header>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -52,6 +60,9 @@ pub struct CardImageProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A fullwidth container for a responsive image.
@@ -59,11 +70,11 @@ pub struct CardImageProps {
 /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
 #[component(CardImage)]
 pub fn card_image(props: &CardImageProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("card-image", props.classes.clone())}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -75,6 +86,9 @@ pub struct CardContentProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for any other content as the body of the card.
@@ -82,11 +96,11 @@ pub struct CardContentProps {
 /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
 #[component(CardContent)]
 pub fn card_content(props: &CardContentProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("card-content", props.classes.clone())}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -98,6 +112,9 @@ pub struct CardFooterProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for card footer content; rendered as a horizontal list of controls.
@@ -105,9 +122,9 @@ pub struct CardFooterProps {
 /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
 #[component(CardFooter)]
 pub fn card_footer(props: &CardFooterProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <footer class={classes!("card-footer", props.classes.clone())}>
             {props.children.clone()}
         </footer>
-    }
+    })
 }
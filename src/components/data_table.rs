@@ -0,0 +1,817 @@
+/*!
+DataTable component: a sortable, selectable Bulma table driven by column definitions.
+
+Summary
+- Rows are rendered from `rows` through a list of [`ColumnDef`]s, each with a header, a cell renderer, an optional
+  sort key and an alignment.
+- Clicking the header of a sortable column cycles ascending → descending → unsorted and sets `aria-sort`.
+- `selection` adds a checkbox column for single or multi row selection; selected rows get Bulma's `is-selected`.
+- The table is wrapped in a Bulma `table-container`; `sticky_header` keeps the header visible while scrolling it.
+- `empty` replaces the body when there are no rows.
+- `loading` covers the table with a Bulma `is-loading` spinner; with `manual_sort` the rows are shown as given and
+  sort changes are only reported, which suits server-side sorting (see [`use_table_query`](crate::use_table_query)).
+
+Example
+```rust,ignore
+let columns = vec![
+    ColumnDef::new("name", "Name", |user: &User| html! { {user.name.clone()} }).sort_by_key(|user: &User| user.name.clone()),
+    ColumnDef::new("age", "Age", |user: &User| html! { {user.age} })
+        .sort_by_key(|user: &User| user.age)
+        .align(Alignment::Right),
+];
+html! {
+    <DataTable<User> {columns} rows={users} selection={TableSelection::Multi} row_key={Callback::from(|user: User| user.id.into())} />
+}
+```
+*/
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use web_sys::HtmlInputElement;
+use yew::events::MouseEvent;
+use yew::prelude::*;
+
+use crate::common::use_style;
+use crate::{Alignment, ExtraAttrs, Table};
+
+/// The direction of a sorted column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// The value of the `aria-sort` attribute for this direction.
+    pub fn aria_sort(&self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+        }
+    }
+}
+
+/// The column a [`DataTable`] is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SortState {
+    /// The `id` of the sorted [`ColumnDef`].
+    pub column: AttrValue,
+    pub direction: SortDirection,
+}
+
+impl SortState {
+    pub fn ascending(column: impl Into<AttrValue>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn descending(column: impl Into<AttrValue>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// The row selection mode of a [`DataTable`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableSelection {
+    /// No checkbox column.
+    #[default]
+    None,
+    /// At most one selected row.
+    Single,
+    /// Any number of selected rows, with a "select all" checkbox in the header.
+    Multi,
+}
+
+type RowCompare<Row> = Rc<dyn Fn(&Row, &Row) -> Ordering>;
+
+/// The definition of one [`DataTable`] column.
+pub struct ColumnDef<Row> {
+    /// A stable identifier, used by [`SortState`].
+    pub id: AttrValue,
+    /// The header text.
+    pub header: AttrValue,
+    /// The horizontal alignment of the header and the cells.
+    pub alignment: Option<Alignment>,
+    /// Extra classes applied to the header and the cells.
+    pub classes: Classes,
+    cell: Rc<dyn Fn(&Row) -> Html>,
+    compare: Option<RowCompare<Row>>,
+}
+
+impl<Row: 'static> ColumnDef<Row> {
+    /// A column rendering each row with `cell`.
+    pub fn new(id: impl Into<AttrValue>, header: impl Into<AttrValue>, cell: impl Fn(&Row) -> Html + 'static) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            alignment: None,
+            classes: Classes::new(),
+            cell: Rc::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Makes the column sortable by the key extracted with `key`.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&Row) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Makes the column sortable with the comparison function `compare`.
+    pub fn sort_by(mut self, compare: impl Fn(&Row, &Row) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Sets the horizontal alignment of the column.
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Adds classes to the header and the cells of the column.
+    pub fn classes(mut self, classes: impl Into<Classes>) -> Self {
+        self.classes.push(classes.into());
+        self
+    }
+}
+
+impl<Row> ColumnDef<Row> {
+    /// Whether the column has a sort key.
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Renders the cell of `row`.
+    pub fn render(&self, row: &Row) -> Html {
+        (self.cell)(row)
+    }
+
+    /// Compares two rows by the sort key of the column; `Equal` when the column is not sortable.
+    pub fn compare(&self, a: &Row, b: &Row) -> Ordering {
+        self.compare.as_ref().map_or(Ordering::Equal, |compare| compare(a, b))
+    }
+
+    fn cell_classes(&self) -> Classes {
+        let alignment = self.alignment.as_ref().map(|alignment| match alignment {
+            Alignment::Left => "has-text-left",
+            Alignment::Centered => "has-text-centered",
+            Alignment::Right => "has-text-right",
+        });
+        classes!(self.classes.clone(), alignment)
+    }
+}
+
+impl<Row> Clone for ColumnDef<Row> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            alignment: self.alignment.clone(),
+            classes: self.classes.clone(),
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<Row> std::fmt::Debug for ColumnDef<Row> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDef")
+            .field("id", &self.id)
+            .field("header", &self.header)
+            .field("alignment", &self.alignment)
+            .field("sortable", &self.is_sortable())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Row> PartialEq for ColumnDef<Row> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.alignment == other.alignment
+            && self.classes == other.classes
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && match (&self.compare, &other.compare) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct DataTableProps<Row: Clone + PartialEq + 'static> {
+    /// The column definitions, in display order.
+    pub columns: Vec<ColumnDef<Row>>,
+    /// The rows to display.
+    pub rows: Vec<Row>,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// Extracts a stable key from a row. Without it rows are identified by their index in `rows`.
+    #[prop_or_default]
+    pub row_key: Option<Callback<Row, AttrValue>>,
+
+    /// Controlled sort state; `Some(None)` means unsorted.
+    #[prop_or_default]
+    pub sort: Option<Option<SortState>>,
+    /// Controlled sort state setter.
+    #[prop_or_default]
+    pub set_sort: Option<Callback<Option<SortState>>>,
+    /// Initial sort state for uncontrolled mode.
+    #[prop_or_default]
+    pub default_sort: Option<SortState>,
+    /// Callback emitted when the sort state changes.
+    #[prop_or_default]
+    pub on_sort_change: Callback<Option<SortState>>,
+    /// Keep `rows` in the given order and only report sort changes, e.g. when the server sorts.
+    #[prop_or_default]
+    pub manual_sort: bool,
+
+    /// The row selection mode.
+    #[prop_or_default]
+    pub selection: TableSelection,
+    /// Controlled keys of the selected rows.
+    #[prop_or_default]
+    pub selected: Option<Vec<AttrValue>>,
+    /// Controlled selection setter.
+    #[prop_or_default]
+    pub set_selected: Option<Callback<Vec<AttrValue>>>,
+    /// Callback emitted with the selected rows when the selection changes.
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<Row>>,
+
+    /// Content shown in place of the rows when `rows` is empty.
+    #[prop_or_default]
+    pub empty: Option<Html>,
+    /// Keep the header visible while scrolling the table container.
+    #[prop_or_default]
+    pub sticky_header: bool,
+    /// The maximum height of the table container, e.g. `"24rem"`; useful with `sticky_header`.
+    #[prop_or_default]
+    pub max_height: AttrValue,
+    /// Optional label for assistive technologies.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// Cover the table with a loading overlay, e.g. while the next page is fetched.
+    #[prop_or_default]
+    pub loading: bool,
+
+    /// Add borders to all the cells.
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Add stripes to the table.
+    #[prop_or_default]
+    pub striped: bool,
+    /// Make the cells narrower.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Add a hover effect on each row.
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Make the table fullwidth.
+    #[prop_or_default]
+    pub fullwidth: bool,
+}
+
+const DATA_TABLE_STYLE: &str = r#"
+.ybc-data-table.has-sticky-header thead th {
+    position: sticky;
+    top: 0;
+    z-index: 2;
+    background-color: var(--bulma-scheme-main, #fff);
+}
+
+.ybc-data-table-sort {
+    display: inline-flex;
+    align-items: center;
+    gap: 0.25em;
+    padding: 0;
+    border: none;
+    background: none;
+    color: inherit;
+    font: inherit;
+    cursor: pointer;
+}
+
+.ybc-data-table-sort-indicator {
+    opacity: 0.4;
+}
+
+.ybc-data-table-sort[data-sorted] .ybc-data-table-sort-indicator {
+    opacity: 1;
+}
+
+.ybc-data-table {
+    position: relative;
+}
+
+.ybc-data-table-loading {
+    position: absolute;
+    inset: 0;
+    z-index: 3;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    background-color: rgba(255, 255, 255, 0.6);
+}
+
+.ybc-data-table .ybc-data-table-select {
+    width: 1%;
+}
+"#;
+
+/// The sort state after clicking the header of the column `id`, currently sorted in `direction`: ascending →
+/// descending → unsorted.
+fn next_sort(id: &AttrValue, direction: Option<SortDirection>) -> Option<SortState> {
+    match direction {
+        None => Some(SortState::ascending(id.clone())),
+        Some(SortDirection::Ascending) => Some(SortState::descending(id.clone())),
+        Some(SortDirection::Descending) => None,
+    }
+}
+
+/// A Bulma table rendering typed rows through [`ColumnDef`]s, with sorting and row selection.
+///
+/// [https://bulma.io/documentation/elements/table/](https://bulma.io/documentation/elements/table/)
+#[component(DataTable)]
+pub fn data_table<Row: Clone + PartialEq + 'static>(props: &DataTableProps<Row>) -> Html {
+    use_style("ybc-data-table-style", DATA_TABLE_STYLE);
+    let internal_sort = use_state(|| props.default_sort.clone());
+    let is_sort_controlled = props.sort.is_some() && props.set_sort.is_some();
+    let sort = props.sort.clone().unwrap_or_else(|| (*internal_sort).clone());
+
+    let set_local_sort = {
+        let internal_sort = internal_sort.clone();
+        let set_sort = props.set_sort.clone();
+        Callback::from(move |value: Option<SortState>| {
+            if is_sort_controlled {
+                if let Some(set_sort) = set_sort.as_ref() {
+                    set_sort.emit(value);
+                }
+            } else {
+                internal_sort.set(value);
+            }
+        })
+    };
+
+    {
+        let on_sort_change = props.on_sort_change.clone();
+        let prev_sort = use_mut_ref(|| sort.clone());
+        use_effect_with(sort.clone(), move |sort| {
+            let mut prev = prev_sort.borrow_mut();
+            if *prev != *sort {
+                on_sort_change.emit(sort.clone());
+                *prev = sort.clone();
+            }
+            || {}
+        });
+    }
+
+    let keys: Vec<AttrValue> = props
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| match props.row_key.as_ref() {
+            Some(row_key) => row_key.emit(row.clone()),
+            None => AttrValue::from(index.to_string()),
+        })
+        .collect();
+
+    let internal_selected = use_state(Vec::<AttrValue>::new);
+    let is_selection_controlled = props.selected.is_some() && props.set_selected.is_some();
+    let selected = props.selected.clone().unwrap_or_else(|| (*internal_selected).clone());
+
+    let set_local_selected = {
+        let internal_selected = internal_selected.clone();
+        let set_selected = props.set_selected.clone();
+        Callback::from(move |value: Vec<AttrValue>| {
+            if is_selection_controlled {
+                if let Some(set_selected) = set_selected.as_ref() {
+                    set_selected.emit(value);
+                }
+            } else {
+                internal_selected.set(value);
+            }
+        })
+    };
+
+    {
+        let on_selection_change = props.on_selection_change.clone();
+        let rows = props.rows.clone();
+        let keys = keys.clone();
+        let prev_selected = use_mut_ref(|| selected.clone());
+        use_effect_with(selected.clone(), move |selected| {
+            let mut prev = prev_selected.borrow_mut();
+            if *prev != *selected {
+                let rows = rows
+                    .iter()
+                    .zip(&keys)
+                    .filter(|(_, key)| selected.contains(key))
+                    .map(|(row, _)| row.clone())
+                    .collect();
+                on_selection_change.emit(rows);
+                *prev = selected.clone();
+            }
+            || {}
+        });
+    }
+
+    let mut order: Vec<usize> = (0..props.rows.len()).collect();
+    if !props.manual_sort
+        && let Some(sort) = sort.as_ref()
+        && let Some(column) = props.columns.iter().find(|column| column.id == sort.column && column.is_sortable())
+    {
+        order.sort_by(|a, b| {
+            let ordering = column.compare(&props.rows[*a], &props.rows[*b]);
+            match sort.direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    let has_selection = props.selection != TableSelection::None;
+    let all_selected = !keys.is_empty() && keys.iter().all(|key| selected.contains(key));
+    let some_selected = keys.iter().any(|key| selected.contains(key));
+
+    let select_all_ref = use_node_ref();
+    {
+        let select_all_ref = select_all_ref.clone();
+        let indeterminate = some_selected && !all_selected;
+        use_effect_with(indeterminate, move |indeterminate| {
+            if let Some(input) = select_all_ref.cast::<HtmlInputElement>() {
+                input.set_indeterminate(*indeterminate);
+            }
+            || {}
+        });
+    }
+
+    let header_cells = props
+        .columns
+        .iter()
+        .map(|column| {
+            let class = column.cell_classes();
+            if !column.is_sortable() {
+                return html! { <th {class} scope="col">{column.header.clone()}</th> };
+            }
+            let direction = sort.as_ref().filter(|sort| sort.column == column.id).map(|sort| sort.direction);
+            let onclick = {
+                let set_local_sort = set_local_sort.clone();
+                let id = column.id.clone();
+                Callback::from(move |_: MouseEvent| set_local_sort.emit(next_sort(&id, direction)))
+            };
+            let indicator = match direction {
+                Some(SortDirection::Ascending) => "▲",
+                Some(SortDirection::Descending) => "▼",
+                None => "↕",
+            };
+            html! {
+                <th {class} scope="col" aria-sort={direction.map_or("none", |direction| direction.aria_sort())}>
+                    <button type="button" class="ybc-data-table-sort" data-sorted={direction.map(|_| "true")} {onclick}>
+                        {column.header.clone()}
+                        <span class="ybc-data-table-sort-indicator" aria-hidden="true">{indicator}</span>
+                    </button>
+                </th>
+            }
+        })
+        .collect::<Html>();
+
+    let select_header = match props.selection {
+        TableSelection::None => Html::default(),
+        TableSelection::Single => html! { <th class="ybc-data-table-select" scope="col"><span class="is-sr-only">{"Select"}</span></th> },
+        TableSelection::Multi => {
+            let onclick = {
+                let set_local_selected = set_local_selected.clone();
+                let keys = keys.clone();
+                Callback::from(move |_: MouseEvent| set_local_selected.emit(if all_selected { Vec::new() } else { keys.clone() }))
+            };
+            html! {
+                <th class="ybc-data-table-select" scope="col">
+                    <input
+                        ref={select_all_ref.clone()}
+                        type="checkbox"
+                        checked={all_selected}
+                        disabled={keys.is_empty()}
+                        aria-label="select all rows"
+                        {onclick}
+                    />
+                </th>
+            }
+        }
+    };
+
+    let body = if props.rows.is_empty() {
+        let colspan = (props.columns.len() + usize::from(has_selection)).to_string();
+        let empty = props.empty.clone().unwrap_or_else(|| html! { {"No data"} });
+        html! {
+            <tr class="ybc-data-table-empty">
+                <td {colspan} class="has-text-centered has-text-grey">{empty}</td>
+            </tr>
+        }
+    } else {
+        order
+            .iter()
+            .map(|index| {
+                let row = &props.rows[*index];
+                let key = keys[*index].clone();
+                let is_selected = selected.contains(&key);
+                let select_cell = if has_selection {
+                    let onclick = {
+                        let set_local_selected = set_local_selected.clone();
+                        let selected = selected.clone();
+                        let key = key.clone();
+                        let selection = props.selection;
+                        Callback::from(move |_: MouseEvent| {
+                            let next = match (selection, is_selected) {
+                                (_, true) => selected.iter().filter(|other| **other != key).cloned().collect(),
+                                (TableSelection::Single, false) => vec![key.clone()],
+                                (_, false) => selected.iter().cloned().chain(Some(key.clone())).collect(),
+                            };
+                            set_local_selected.emit(next);
+                        })
+                    };
+                    html! {
+                        <td class="ybc-data-table-select">
+                            <input type="checkbox" checked={is_selected} aria-label="select row" {onclick} />
+                        </td>
+                    }
+                } else {
+                    Html::default()
+                };
+                let cells = props
+                    .columns
+                    .iter()
+                    .map(|column| html! { <td class={column.cell_classes()}>{column.render(row)}</td> })
+                    .collect::<Html>();
+                html! {
+                    <tr key={key.to_string()} class={classes!(is_selected.then_some("is-selected"))}>
+                        {select_cell}
+                        {cells}
+                    </tr>
+                }
+            })
+            .collect::<Html>()
+    };
+
+    let container_class = classes!("table-container", "ybc-data-table", props.sticky_header.then_some("has-sticky-header"));
+    let style = (!props.max_height.is_empty()).then(|| format!("max-height: {}; overflow-y: auto;", props.max_height));
+    let aria_label = (!props.aria_label.is_empty()).then(|| props.aria_label.clone());
+
+    props.attrs.apply(html! {
+        <div class={container_class} {style} aria-busy={props.loading.then_some("true")}>
+            if props.loading {
+                <div class="ybc-data-table-loading">
+                    <span class="button is-loading is-white is-large" aria-label="loading"></span>
+                </div>
+            }
+            <Table
+                classes={props.classes.clone()}
+                bordered={props.bordered}
+                striped={props.striped}
+                narrow={props.narrow}
+                hoverable={props.hoverable}
+                fullwidth={props.fullwidth}
+            >
+                if let Some(aria_label) = aria_label {
+                    <caption class="is-sr-only">{aria_label}</caption>
+                }
+                <thead>
+                    <tr>
+                        {select_header}
+                        {header_cells}
+                    </tr>
+                </thead>
+                <tbody>
+                    {body}
+                </tbody>
+            </Table>
+        </div>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, Rendered, render_blocking};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct User {
+        id: u32,
+        name: &'static str,
+        age: u32,
+    }
+
+    fn users() -> Vec<User> {
+        vec![
+            User {
+                id: 1,
+                name: "Grace",
+                age: 45,
+            },
+            User { id: 2, name: "Ada", age: 36 },
+            User {
+                id: 3,
+                name: "Linus",
+                age: 28,
+            },
+        ]
+    }
+
+    fn columns() -> Vec<ColumnDef<User>> {
+        vec![
+            ColumnDef::new("name", "Name", |user: &User| html! { <span class="user-name">{user.name}</span> }).sort_by_key(|user: &User| user.name),
+            ColumnDef::new("age", "Age", |user: &User| html! { {user.age} })
+                .sort_by_key(|user: &User| user.age)
+                .align(Alignment::Right),
+            ColumnDef::new("id", "Id", |user: &User| html! { {user.id} }),
+        ]
+    }
+
+    fn row_key() -> Option<Callback<User, AttrValue>> {
+        Some(Callback::from(|user: User| AttrValue::from(user.id.to_string())))
+    }
+
+    fn names(rendered: &Rendered) -> Vec<String> {
+        rendered
+            .find_all(|element| element.has_class("user-name"))
+            .iter()
+            .map(Queries::text)
+            .collect()
+    }
+
+    fn aria_sorts(rendered: &Rendered) -> Vec<Option<String>> {
+        rendered
+            .find_all(|element| element.tag() == "th")
+            .iter()
+            .map(|th| th.attr("aria-sort"))
+            .collect()
+    }
+
+    fn checkboxes(rendered: &Rendered, label: &str) -> Vec<bool> {
+        rendered
+            .get_all_by_label(label)
+            .iter()
+            .map(|input| input.attr("checked").is_some())
+            .collect()
+    }
+
+    #[test]
+    fn sorting_cycles_ascending_descending_unsorted() {
+        let id = AttrValue::from("name");
+        assert_eq!(next_sort(&id, None), Some(SortState::ascending("name")));
+        assert_eq!(next_sort(&id, Some(SortDirection::Ascending)), Some(SortState::descending("name")));
+        assert_eq!(next_sort(&id, Some(SortDirection::Descending)), None);
+    }
+
+    #[test]
+    fn sorted_columns_set_aria_sort_and_order_the_rows() {
+        let unsorted = render_blocking(|| html! { <DataTable<User> columns={columns()} rows={users()} /> });
+        assert_eq!(names(&unsorted), ["Grace", "Ada", "Linus"]);
+        assert_eq!(aria_sorts(&unsorted), [Some("none".into()), Some("none".into()), None]);
+
+        let ascending = render_blocking(|| {
+            html! { <DataTable<User> columns={columns()} rows={users()} default_sort={SortState::ascending("name")} /> }
+        });
+        assert_eq!(names(&ascending), ["Ada", "Grace", "Linus"]);
+        assert_eq!(aria_sorts(&ascending), [Some("ascending".into()), Some("none".into()), None]);
+
+        let descending = render_blocking(|| {
+            html! { <DataTable<User> columns={columns()} rows={users()} default_sort={SortState::descending("age")} /> }
+        });
+        assert_eq!(names(&descending), ["Grace", "Ada", "Linus"]);
+        assert_eq!(aria_sorts(&descending), [Some("none".into()), Some("descending".into()), None]);
+    }
+
+    #[test]
+    fn manual_sort_keeps_the_row_order() {
+        let rendered = render_blocking(|| {
+            html! {
+                <DataTable<User> columns={columns()} rows={users()} manual_sort=true
+                    sort={Some(Some(SortState::descending("name")))} set_sort={Callback::noop()} />
+            }
+        });
+        assert_eq!(names(&rendered), ["Grace", "Ada", "Linus"]);
+        assert_eq!(aria_sorts(&rendered)[0].as_deref(), Some("descending"));
+    }
+
+    #[test]
+    fn single_selection_has_no_select_all_checkbox() {
+        let rendered = render_blocking(|| {
+            html! {
+                <DataTable<User> columns={columns()} rows={users()} row_key={row_key()} selection={TableSelection::Single}
+                    selected={Some(vec![AttrValue::from("2")])} set_selected={Callback::noop()} />
+            }
+        });
+        assert!(rendered.query_by_label("select all rows").is_none());
+        assert_eq!(rendered.get_by_text("Select").attr("class").as_deref(), Some("is-sr-only"));
+        assert_eq!(checkboxes(&rendered, "select row"), [false, true, false]);
+        let selected: Vec<_> = rendered
+            .find_all(|element| element.has_class("is-selected"))
+            .iter()
+            .map(Queries::text)
+            .collect();
+        assert_eq!(selected, ["Ada362"]);
+    }
+
+    #[test]
+    fn multi_selection_has_a_select_all_checkbox() {
+        let table = |selected: Vec<&'static str>| {
+            move || {
+                let selected = selected.into_iter().map(AttrValue::from).collect::<Vec<_>>();
+                html! {
+                    <DataTable<User> columns={columns()} rows={users()} row_key={row_key()} selection={TableSelection::Multi}
+                        selected={Some(selected)} set_selected={Callback::noop()} />
+                }
+            }
+        };
+        let some = render_blocking(table(vec!["1", "3"]));
+        assert_eq!(checkboxes(&some, "select all rows"), [false]);
+        assert_eq!(checkboxes(&some, "select row"), [true, false, true]);
+
+        let all = render_blocking(table(vec!["1", "2", "3"]));
+        assert_eq!(checkboxes(&all, "select all rows"), [true]);
+        assert_eq!(all.find_all(|element| element.has_class("is-selected")).len(), 3);
+
+        let none = render_blocking(|| html! { <DataTable<User> columns={columns()} rows={users()} /> });
+        assert!(none.query_by_label("select row").is_none());
+    }
+
+    #[test]
+    fn empty_tables_show_the_empty_slot_across_all_columns() {
+        let rendered = render_blocking(|| {
+            html! {
+                <DataTable<User> columns={columns()} rows={vec![]} selection={TableSelection::Multi}
+                    empty={html! { <em>{"No users yet"}</em> }} />
+            }
+        });
+        let cell = rendered
+            .find_all(|element| element.has_class("ybc-data-table-empty"))
+            .remove(0)
+            .children()
+            .remove(0);
+        assert_eq!(cell.attr("colspan").as_deref(), Some("4"));
+        assert_eq!(cell.get_by_text("No users yet").tag(), "em");
+        assert!(rendered.get_by_label("select all rows").attr("disabled").is_some());
+
+        let default = render_blocking(|| html! { <DataTable<User> columns={columns()} rows={vec![]} /> });
+        assert_eq!(default.get_by_text("No data").attr("colspan").as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn loading_covers_the_table_with_an_overlay() {
+        let loading = render_blocking(|| html! { <DataTable<User> columns={columns()} rows={users()} loading=true /> });
+        let overlay = loading.find_all(|element| element.has_class("ybc-data-table-loading"));
+        assert_eq!(overlay.len(), 1);
+        assert!(overlay[0].get_by_label("loading").has_class("is-loading"));
+        let container = loading.find_all(|element| element.has_class("table-container")).remove(0);
+        assert_eq!(container.attr("aria-busy").as_deref(), Some("true"));
+
+        let idle = render_blocking(|| html! { <DataTable<User> columns={columns()} rows={users()} /> });
+        assert!(idle.query_by_label("loading").is_none());
+        assert_eq!(
+            idle.find_all(|element| element.has_class("table-container")).remove(0).attr("aria-busy"),
+            None
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::testing::{Queries, click, mount};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn clicking_a_header_cycles_its_sort() {
+        let columns = vec![ColumnDef::new("n", "Number", |n: &u32| html! { {n} }).sort_by_key(|n: &u32| *n)];
+        let mounted = mount(html! { <DataTable<u32> {columns} rows={vec![2, 3, 1]} /> }).await;
+        let header = || mounted.find_all(|element| element.tag() == "th").remove(0);
+        let cells = || {
+            mounted
+                .find_all(|element| element.tag() == "td")
+                .iter()
+                .map(Queries::text)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(header().attr("aria-sort").as_deref(), Some("none"));
+
+        for (aria_sort, order) in [("ascending", ["1", "2", "3"]), ("descending", ["3", "2", "1"]), ("none", ["2", "3", "1"])] {
+            click(&header().get_by_role("button")).await;
+            assert_eq!(header().attr("aria-sort").as_deref(), Some(aria_sort));
+            assert_eq!(cells(), order);
+        }
+    }
+}
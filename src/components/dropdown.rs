@@ -1,15 +1,8 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
-
 use yew::events::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
 use crate::elements::button::Button;
-
-static DROPDOWN_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-
-fn next_dropdown_id() -> String {
-    format!("dropdown-{}", DROPDOWN_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
+use crate::{ExtraAttrs, use_ybc_id};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct DropdownProps {
@@ -20,6 +13,9 @@ pub struct DropdownProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Make this dropdown triggerable based on hover.
     #[prop_or_default]
     pub hoverable: bool,
@@ -105,8 +101,8 @@ pub fn dropdown(props: &DropdownProps) -> Html {
         });
     }
 
-    let auto_id = use_state(|| AttrValue::from(next_dropdown_id()));
-    let root_id = props.id.clone().unwrap_or_else(|| (*auto_id).clone());
+    let auto_id = use_ybc_id("dropdown");
+    let root_id = props.id.clone().unwrap_or(auto_id);
     let menu_id = AttrValue::from(format!("{}-menu", root_id));
     let button_aria_label = (!props.button_aria_label.is_empty()).then_some(props.button_aria_label.clone());
     let menu_aria_label = (!props.menu_aria_label.is_empty()).then_some(props.menu_aria_label.clone());
@@ -114,7 +110,6 @@ pub fn dropdown(props: &DropdownProps) -> Html {
     let on_trigger_click = {
         let set_local_open = set_local_open.clone();
         let hoverable = props.hoverable;
-        let is_open = is_open;
         Callback::from(move |event: MouseEvent| {
             if hoverable {
                 return;
@@ -180,7 +175,7 @@ pub fn dropdown(props: &DropdownProps) -> Html {
         Html::default()
     };
 
-    html! {
+    props.attrs.apply(html! {
         <div id={root_id} {class} onkeydown={on_root_keydown}>
             {overlay}
             <div class="dropdown-trigger">
@@ -202,5 +197,5 @@ pub fn dropdown(props: &DropdownProps) -> Html {
                 </div>
             </div>
         </div>
-    }
+    })
 }
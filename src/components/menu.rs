@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct MenuProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A simple menu, for any type of vertical navigation.
@@ -13,11 +18,11 @@ pub struct MenuProps {
 /// [https://bulma.io/documentation/components/menu/](https://bulma.io/documentation/components/menu/)
 #[component(Menu)]
 pub fn menu(props: &MenuProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <aside class={classes!("menu", props.classes.clone())}>
             {props.children.clone()}
         </aside>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -30,6 +35,9 @@ pub struct MenuListProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for menu list `li` elements.
@@ -37,11 +45,11 @@ pub struct MenuListProps {
 /// [https://bulma.io/documentation/components/menu/](https://bulma.io/documentation/components/menu/)
 #[component(MenuList)]
 pub fn menu_list(props: &MenuListProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <ul class={classes!("menu-list", props.classes.clone())}>
             {props.children.clone()}
         </ul>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -51,6 +59,9 @@ pub fn menu_list(props: &MenuListProps) -> Html {
 pub struct MenuLabelProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The text of the label.
     #[prop_or_default]
     pub text: String,
@@ -61,9 +72,9 @@ pub struct MenuLabelProps {
 /// [https://bulma.io/documentation/components/menu/](https://bulma.io/documentation/components/menu/)
 #[component(MenuLabel)]
 pub fn menu_label(props: &MenuLabelProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <p class={classes!("menu-label", props.classes.clone())}>
             {props.text.clone()}
         </p>
-    }
+    })
 }
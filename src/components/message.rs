@@ -1,22 +1,139 @@
+use derive_more::Display;
 use yew::prelude::*;
 
+use crate::{Delete, ExtraAttrs, Size};
+
+/// Semantic colors for Bulma messages.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum MessageColor {
+    #[display("is-dark")]
+    Dark,
+    #[display("is-primary")]
+    Primary,
+    #[display("is-link")]
+    Link,
+    #[display("is-info")]
+    Info,
+    #[display("is-success")]
+    Success,
+    #[display("is-warning")]
+    Warning,
+    #[display("is-danger")]
+    Danger,
+}
+
+/// Shared with [`MessageHeader`] so it can render the delete button of a dismissible message.
+#[derive(Clone, PartialEq)]
+struct MessageContext {
+    dismiss: Option<Callback<MouseEvent>>,
+    delete_aria_label: AttrValue,
+}
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct MessageProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<MessageColor>,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Render a `delete` button in the [`MessageHeader`] which closes this component.
+    #[prop_or_default]
+    pub dismissible: bool,
+    /// Initial open state for uncontrolled mode.
+    #[prop_or(true)]
+    pub default_open: bool,
+    /// Controlled open state.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// Controlled open state setter.
+    #[prop_or_default]
+    pub set_open: Option<Callback<bool>>,
+    /// Called when the message opens.
+    #[prop_or_default]
+    pub on_open: Callback<()>,
+    /// Called when the message closes.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// The `aria-label` of the delete button.
+    #[prop_or_else(|| "close".into())]
+    pub delete_aria_label: AttrValue,
 }
 
 /// Colored message blocks, to emphasize part of your page.
 ///
 /// [https://bulma.io/documentation/components/message/](https://bulma.io/documentation/components/message/)
+///
+/// With `dismissible` the [`MessageHeader`] renders a delete button after its content. The message closes itself
+/// in uncontrolled mode; in controlled mode (`open` + `set_open`) the click is reported through `set_open(false)`.
 #[component(Message)]
 pub fn message(props: &MessageProps) -> Html {
-    html! {
-        <article class={classes!("message", props.classes.clone())}>
+    let internal_open = use_state(|| props.default_open);
+    let is_controlled = props.open.is_some() && props.set_open.is_some();
+    let is_open = props.open.unwrap_or(*internal_open);
+
+    let set_local_open = {
+        let internal_open = internal_open.clone();
+        let set_open = props.set_open.clone();
+        Callback::from(move |value: bool| {
+            if is_controlled {
+                if let Some(set_open) = set_open.as_ref() {
+                    set_open.emit(value);
+                }
+            } else {
+                internal_open.set(value);
+            }
+        })
+    };
+
+    {
+        let on_open = props.on_open.clone();
+        let on_close = props.on_close.clone();
+        let prev_open = use_mut_ref(move || is_open);
+        use_effect_with(is_open, move |is_open| {
+            let mut prev = prev_open.borrow_mut();
+            if *prev != *is_open {
+                if *is_open {
+                    on_open.emit(());
+                } else {
+                    on_close.emit(());
+                }
+                *prev = *is_open;
+            }
+            || {}
+        });
+    }
+
+    if !is_open {
+        return Html::default();
+    }
+
+    let class = classes!(
+        "message",
+        props.classes.clone(),
+        props.color.as_ref().map(ToString::to_string),
+        props.size.as_ref().map(ToString::to_string),
+    );
+    let context = MessageContext {
+        dismiss: props.dismissible.then(|| set_local_open.reform(|_: MouseEvent| false)),
+        delete_aria_label: props.delete_aria_label.clone(),
+    };
+    let article = props.attrs.apply(html! {
+        <article {class}>
             {props.children.clone()}
         </article>
+    });
+    html! {
+        <ContextProvider<MessageContext> {context}>
+            {article}
+        </ContextProvider<MessageContext>>
     }
 }
 
@@ -29,6 +146,9 @@ pub struct MessageHeaderProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// An optional message header that can hold a title and a delete element.
@@ -36,11 +156,20 @@ pub struct MessageHeaderProps {
 /// [https://bulma.io/documentation/components/message/](https://bulma.io/documentation/components/message/)
 #[component(MessageHeader)]
 pub fn message_header(props: &MessageHeaderProps) -> Html {
-    html! {
+    let context = use_context::<MessageContext>();
+    let delete = match context {
+        Some(MessageContext {
+            dismiss: Some(dismiss),
+            delete_aria_label,
+        }) => html! { <Delete onclick={dismiss} aria_label={delete_aria_label} /> },
+        _ => Html::default(),
+    };
+    props.attrs.apply(html! {
         <div class={classes!("message-header", props.classes.clone())}>
             {props.children.clone()}
+            {delete}
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -52,6 +181,9 @@ pub struct MessageBodyProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for the body of a message.
@@ -59,9 +191,69 @@ pub struct MessageBodyProps {
 /// [https://bulma.io/documentation/components/message/](https://bulma.io/documentation/components/message/)
 #[component(MessageBody)]
 pub fn message_body(props: &MessageBodyProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("message-body", props.classes.clone())}>
             {props.children.clone()}
         </div>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+
+    #[test]
+    fn the_header_of_a_dismissible_message_renders_the_delete_button() {
+        let rendered = render_blocking(|| {
+            html! {
+                <Message dismissible=true delete_aria_label="Hide">
+                    <MessageHeader><p>{"Heads up"}</p></MessageHeader>
+                    <MessageBody>{"Details"}</MessageBody>
+                </Message>
+            }
+        });
+        let header = rendered.find_all(|element| element.has_class("message-header")).remove(0);
+        let delete = header.get_by_role_and_name("button", "Hide");
+        assert!(delete.has_class("delete"));
+        assert_eq!(header.children().last(), Some(&delete));
+        let body = rendered.find_all(|element| element.has_class("message-body")).remove(0);
+        assert!(body.find_all(|element| element.tag() == "button").is_empty());
+    }
+
+    #[test]
+    fn headers_render_no_delete_button_unless_the_message_is_dismissible() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Message><MessageHeader>{"Inside"}</MessageHeader></Message>
+                    <MessageHeader>{"Outside"}</MessageHeader>
+                </>
+            }
+        });
+        assert_eq!(rendered.find_all(|element| element.has_class("message-header")).len(), 2);
+        assert!(rendered.find_all(|element| element.tag() == "button").is_empty());
+    }
+
+    #[test]
+    fn closed_messages_render_nothing() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Message open={false} set_open={Callback::noop()} dismissible=true>
+                        <MessageHeader>{"Controlled"}</MessageHeader>
+                    </Message>
+                    <Message default_open={false}><MessageBody>{"Uncontrolled"}</MessageBody></Message>
+                    <Message><MessageBody>{"Open"}</MessageBody></Message>
+                </>
+            }
+        });
+        let messages: Vec<_> = rendered
+            .find_all(|element| element.has_class("message"))
+            .iter()
+            .map(Queries::text)
+            .collect();
+        assert_eq!(messages, ["Open"]);
+        assert!(rendered.find_all(|element| element.has_class("message-header")).is_empty());
     }
 }
@@ -3,6 +3,7 @@ pub mod autocomplete;
 pub mod breadcrumb;
 pub mod calendar;
 pub mod card;
+pub mod data_table;
 pub mod dropdown;
 pub mod menu;
 pub mod message;
@@ -10,4 +11,7 @@ pub mod modal;
 pub mod navbar;
 pub mod pagination;
 pub mod panel;
+pub mod table_query;
 pub mod tabs;
+pub mod theme;
+pub mod toast;
@@ -1,8 +1,7 @@
 use std::collections::HashSet;
 use std::rc::Rc;
-use std::sync::atomic::{AtomicUsize, Ordering};
 
-use crate::{Button, Delete};
+use crate::{Button, Delete, ExtraAttrs, use_ybc_id};
 use wasm_bindgen::JsCast;
 use web_sys::{Event, HtmlDialogElement, HtmlElement, MouseEvent};
 use yew::prelude::*;
@@ -112,12 +111,6 @@ impl ModalController {
 /// Context type for the modal controller.
 pub type ModalControllerContext = ModalController;
 
-static MODAL_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-
-fn next_modal_id(prefix: &str) -> String {
-    format!("{}-{}", prefix, MODAL_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
-
 const DIALOG_STYLE: &str = r#"
 /* Avoid ghost overlays when state is closed. */
 dialog.modal:not([open]) {
@@ -240,6 +233,8 @@ struct DialogShellProps {
     id: String,
     #[prop_or_default]
     classes: Classes,
+    #[prop_or_default]
+    attrs: ExtraAttrs,
     is_active: bool,
     set_is_active: Callback<bool>,
     dialog_ref: NodeRef,
@@ -285,28 +280,31 @@ fn dialog_shell(props: &DialogShellProps) -> Html {
     let set_is_active_for_close = props.set_is_active.clone();
     let controller_for_close = controller.clone();
 
+    let dialog = props.attrs.apply(html! {
+        <dialog
+            id={props.id.clone()}
+            class={class}
+            ref={props.dialog_ref.clone()}
+            oncancel={Callback::from(move |ev: Event| {
+                ev.prevent_default();
+                if close_on_escape {
+                    on_escape.emit(());
+                }
+            })}
+            onclose={Callback::from(move |_ev: Event| {
+                set_is_active_for_close.emit(false);
+                if let Some(controller) = controller_for_close.as_ref() {
+                    controller.close(&id_for_close);
+                }
+            })}
+        >
+            { for props.children.iter() }
+        </dialog>
+    });
     html! {
         <>
             <style>{DIALOG_STYLE}</style>
-            <dialog
-                id={props.id.clone()}
-                class={class}
-                ref={props.dialog_ref.clone()}
-                oncancel={Callback::from(move |ev: Event| {
-                    ev.prevent_default();
-                    if close_on_escape {
-                        on_escape.emit(());
-                    }
-                })}
-                onclose={Callback::from(move |_ev: Event| {
-                    set_is_active_for_close.emit(false);
-                    if let Some(controller) = controller_for_close.as_ref() {
-                        controller.close(&id_for_close);
-                    }
-                })}
-            >
-                { for props.children.iter() }
-            </dialog>
+            {dialog}
         </>
     }
 }
@@ -328,6 +326,9 @@ pub struct ModalProps {
     /// Extra classes applied to the root `.modal`.
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Controlled open state.
     #[prop_or_default]
     pub open: Option<bool>,
@@ -365,8 +366,8 @@ pub fn modal(props: &ModalProps) -> Html {
 
     let controller = use_context::<ModalControllerContext>();
     let dialog_ref = use_node_ref();
-    let auto_id = use_state(|| next_modal_id("modal"));
-    let modal_id = props.id.clone().unwrap_or_else(|| (*auto_id).clone());
+    let auto_id = use_ybc_id("modal");
+    let modal_id = props.id.clone().unwrap_or_else(|| auto_id.to_string());
 
     let set_local_open = {
         let internal_open = internal_open.clone();
@@ -497,6 +498,7 @@ pub fn modal(props: &ModalProps) -> Html {
             <DialogShell
                 id={modal_id}
                 classes={props.classes.clone()}
+                attrs={props.attrs.clone()}
                 is_active={is_active}
                 set_is_active={set_local_open}
                 dialog_ref={dialog_ref}
@@ -542,6 +544,9 @@ pub struct ModalCardProps {
     /// Extra classes applied to the root `.modal`.
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Controlled open state.
     #[prop_or_default]
     pub open: Option<bool>,
@@ -575,8 +580,8 @@ pub fn modal_card(props: &ModalCardProps) -> Html {
 
     let controller = use_context::<ModalControllerContext>();
     let dialog_ref = use_node_ref();
-    let auto_id = use_state(|| AttrValue::from(next_modal_id("modal-card")));
-    let modal_id = props.id.clone().unwrap_or_else(|| (*auto_id).clone()).to_string();
+    let auto_id = use_ybc_id("modal-card");
+    let modal_id = props.id.clone().unwrap_or(auto_id).to_string();
 
     let set_local_open = {
         let internal_open = internal_open.clone();
@@ -706,6 +711,7 @@ pub fn modal_card(props: &ModalCardProps) -> Html {
             <DialogShell
                 id={modal_id}
                 classes={props.classes.clone()}
+                attrs={props.attrs.clone()}
                 is_active={is_active}
                 set_is_active={set_local_open}
                 dialog_ref={dialog_ref}
@@ -741,6 +747,9 @@ pub fn modal_card2(props: &ModalCardProps) -> Html {
 pub struct ModalControllerProviderProps {
     #[prop_or_default]
     pub children: Children,
+    /// Extra attributes, e.g. a test id, for a wrapper around the children; see [`ExtraAttrs`].
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// Provides [`ModalControllerContext`] to descendants.
@@ -749,9 +758,9 @@ pub fn ModalControllerProvider(props: &ModalControllerProviderProps) -> Html {
     let state = use_reducer(ModalControllerState::default);
     let controller = ModalController::new(state);
 
-    html! {
+    props.attrs.apply(html! {
         <ContextProvider<ModalControllerContext> context={controller}>
             { for props.children.iter() }
         </ContextProvider<ModalControllerContext>>
-    }
+    })
 }
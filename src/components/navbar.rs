@@ -1,22 +1,10 @@
 use derive_more::Display;
 use std::rc::Rc;
 use std::string::ToString;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use yew::events::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
-use crate::Button;
-
-static NAVBAR_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-static NAVBAR_DROPDOWN_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-
-fn next_navbar_id() -> String {
-    format!("navbar-menu-{}", NAVBAR_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
-
-fn next_navbar_dropdown_id() -> String {
-    format!("navbar-dropdown-{}", NAVBAR_DROPDOWN_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
+use crate::{Button, ExtraAttrs, use_ybc_id};
 
 #[derive(Clone, Eq, PartialEq)]
 pub struct NavBurgerCloserState {
@@ -36,6 +24,9 @@ pub struct NavbarProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Make the navbar fixed to the top or bottom of the UI.
     #[prop_or_default]
     pub fixed: Option<NavbarFixed>,
@@ -150,8 +141,8 @@ pub fn navbar(props: &NavbarProps) -> Html {
         });
     }
 
-    let auto_menu_id = use_state(|| AttrValue::from(next_navbar_id()));
-    let menu_id = props.menu_id.clone().unwrap_or_else(|| (*auto_menu_id).clone());
+    let auto_menu_id = use_ybc_id("navbar-menu");
+    let menu_id = props.menu_id.clone().unwrap_or(auto_menu_id);
     let aria_label = if props.aria_label.is_empty() {
         AttrValue::from("main navigation")
     } else {
@@ -160,7 +151,6 @@ pub fn navbar(props: &NavbarProps) -> Html {
 
     let toggle_menu_action = {
         let set_local_open = set_local_open.clone();
-        let is_menu_open = is_menu_open;
         Callback::from(move |_| set_local_open.emit(!is_menu_open))
     };
 
@@ -251,7 +241,7 @@ pub fn navbar(props: &NavbarProps) -> Html {
         </>
     };
 
-    if props.padded {
+    props.attrs.apply(if props.padded {
         html! {
             <nav {class} role="navigation" aria-label={aria_label}>
                 <div class="container">{contents}</div>
@@ -265,7 +255,7 @@ pub fn navbar(props: &NavbarProps) -> Html {
                 {props.children.clone()}
             </nav>
         }
-    }
+    })
 }
 
 /// The 2 possible fixed positions available for a navbar.
@@ -302,6 +292,9 @@ pub struct NavbarItemProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| NavbarItemTag::Div)]
     pub tag: NavbarItemTag,
@@ -342,7 +335,7 @@ pub fn navbar_item(props: &NavbarItemProps) -> Html {
         props.tab.then_some("is-tab"),
         props.active.then_some("is-active"),
     );
-    match props.tag {
+    props.attrs.apply(match props.tag {
         NavbarItemTag::A => {
             html! {
                 <a
@@ -362,7 +355,7 @@ pub fn navbar_item(props: &NavbarItemProps) -> Html {
                 </div>
             }
         }
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -372,6 +365,9 @@ pub fn navbar_item(props: &NavbarItemProps) -> Html {
 pub struct NavbarDividerProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// An element to display a horizontal rule in a navbar-dropdown.
@@ -379,7 +375,9 @@ pub struct NavbarDividerProps {
 /// [https://bulma.io/documentation/components/navbar/#dropdown-menu](https://bulma.io/documentation/components/navbar/#dropdown-menu)
 #[component(NavbarDivider)]
 pub fn navbar_divider(props: &NavbarDividerProps) -> Html {
-    html! { <hr class={classes!("navbar-divider", props.classes.clone())} /> }
+    props
+        .attrs
+        .apply(html! { <hr class={classes!("navbar-divider", props.classes.clone())} /> })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -392,6 +390,9 @@ pub struct NavbarDropdownProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The contents of the navbar-link used for triggering the dropdown menu.
     pub navlink: Html,
     /// Make this dropdown triggerable based on hover.
@@ -476,8 +477,8 @@ pub fn navbar_dropdown(props: &NavbarDropdownProps) -> Html {
         });
     }
 
-    let auto_id = use_state(|| AttrValue::from(next_navbar_dropdown_id()));
-    let root_id = props.id.clone().unwrap_or_else(|| (*auto_id).clone());
+    let auto_id = use_ybc_id("navbar-dropdown");
+    let root_id = props.id.clone().unwrap_or(auto_id);
     let menu_id = AttrValue::from(format!("{}-menu", root_id));
 
     // navbar-item classes
@@ -499,7 +500,6 @@ pub fn navbar_dropdown(props: &NavbarDropdownProps) -> Html {
     let on_trigger_click = {
         let set_local_open = set_local_open.clone();
         let hoverable = props.hoverable;
-        let is_menu_active = is_menu_active;
         Callback::from(move |event: MouseEvent| {
             if hoverable {
                 return;
@@ -538,7 +538,7 @@ pub fn navbar_dropdown(props: &NavbarDropdownProps) -> Html {
         Html::default()
     };
 
-    html! {
+    props.attrs.apply(html! {
         <div id={root_id} {class}>
             {overlay}
             <a
@@ -557,5 +557,5 @@ pub fn navbar_dropdown(props: &NavbarDropdownProps) -> Html {
                 {props.children.clone()}
             </div>
         </div>
-    }
+    })
 }
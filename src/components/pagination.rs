@@ -2,7 +2,7 @@ use derive_more::Display;
 use yew::events::MouseEvent;
 use yew::prelude::*;
 
-use crate::{Alignment, Size};
+use crate::{Alignment, ExtraAttrs, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct PaginationProps {
@@ -11,6 +11,9 @@ pub struct PaginationProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
@@ -21,15 +24,94 @@ pub struct PaginationProps {
     #[prop_or_default]
     pub rounded: bool,
 
-    /// The `pagination-previous` element to use.
+    /// The `pagination-previous` element to use in manual mode.
+    #[prop_or_default]
     pub previous: Html,
-    /// The `pagination-next` element to use.
+    /// The `pagination-next` element to use in manual mode.
+    #[prop_or_default]
     pub next: Html,
+
+    /// The total number of items; together with `page_size` this enables automatic mode.
+    #[prop_or_default]
+    pub total_items: Option<usize>,
+    /// The number of items per page in automatic mode.
+    #[prop_or(10)]
+    pub page_size: usize,
+    /// The total number of pages; enables automatic mode and takes precedence over `total_items`.
+    #[prop_or_default]
+    pub total_pages: Option<usize>,
+    /// The 1-based current page in automatic mode.
+    #[prop_or(1)]
+    pub current: usize,
+    /// Callback emitted with the 1-based page chosen in automatic mode.
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+    /// The number of pages shown on each side of the current page in automatic mode.
+    #[prop_or(1)]
+    pub siblings: usize,
+    /// The number of pages always shown at the start and at the end in automatic mode.
+    #[prop_or(1)]
+    pub boundaries: usize,
+    /// The text of the previous button in automatic mode.
+    #[prop_or_else(|| "Previous".into())]
+    pub previous_label: AttrValue,
+    /// The text of the next button in automatic mode.
+    #[prop_or_else(|| "Next".into())]
+    pub next_label: AttrValue,
+}
+
+/// An entry of the window of page links computed by [`pagination_window`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaginationPage {
+    /// A link to the 1-based page number.
+    Page(usize),
+    /// A gap of skipped pages.
+    Ellipsis,
+}
+
+/// Computes the page links shown for `current` of `total_pages` pages.
+///
+/// The first and last `boundaries` pages and `siblings` pages on each side of the current one are always shown.
+/// A gap of a single page is shown as that page instead of an ellipsis, so the number of entries stays constant
+/// while paging.
+pub fn pagination_window(current: usize, total_pages: usize, siblings: usize, boundaries: usize) -> Vec<PaginationPage> {
+    let count = total_pages as i64;
+    if count == 0 {
+        return Vec::new();
+    }
+    let page = (current as i64).clamp(1, count);
+    let siblings = siblings as i64;
+    let boundaries = boundaries as i64;
+
+    let end_start = (count - boundaries + 1).max(boundaries + 1);
+    let siblings_start = (page - siblings).min(count - boundaries - siblings * 2 - 1).max(boundaries + 2);
+    let siblings_end = (page + siblings).max(boundaries + siblings * 2 + 2).min(end_start - 2);
+
+    let mut items: Vec<PaginationPage> = (1..=boundaries.min(count)).map(|page| PaginationPage::Page(page as usize)).collect();
+    if siblings_start > boundaries + 2 {
+        items.push(PaginationPage::Ellipsis);
+    } else if boundaries + 1 < count - boundaries {
+        items.push(PaginationPage::Page((boundaries + 1) as usize));
+    }
+    items.extend((siblings_start..=siblings_end).map(|page| PaginationPage::Page(page as usize)));
+    if siblings_end < count - boundaries - 1 {
+        items.push(PaginationPage::Ellipsis);
+    } else if count - boundaries > boundaries {
+        items.push(PaginationPage::Page((count - boundaries) as usize));
+    }
+    items.extend((end_start..=count).map(|page| PaginationPage::Page(page as usize)));
+    items.dedup();
+    items
 }
 
 /// A responsive, usable, and flexible pagination component.
 ///
 /// [https://bulma.io/documentation/components/pagination/](https://bulma.io/documentation/components/pagination/)
+///
+/// In manual mode the caller provides `previous`, `next` and the list items as children. Setting `total_items`
+/// (with `page_size`) or `total_pages` switches to automatic mode: the component renders the previous/next
+/// buttons and a window of page links around `current` (see [`pagination_window`]) and reports clicks through
+/// `on_page_change`.
 #[component(Pagination)]
 pub fn pagination(props: &PaginationProps) -> Html {
     let class = classes!(
@@ -39,7 +121,55 @@ pub fn pagination(props: &PaginationProps) -> Html {
         props.alignment.as_ref().map(|alignment| alignment.to_string()),
         props.rounded.then_some("is-rounded"),
     );
-    html! {
+    let total_pages = props
+        .total_pages
+        .or_else(|| props.total_items.map(|total| total.div_ceil(props.page_size.max(1))));
+    if let Some(total_pages) = total_pages {
+        let total_pages = total_pages.max(1);
+        let current = props.current.clamp(1, total_pages);
+        let go_to = |page: usize| {
+            let on_page_change = props.on_page_change.clone();
+            Callback::from(move |_: MouseEvent| {
+                if page != current {
+                    on_page_change.emit(page);
+                }
+            })
+        };
+        let pages = pagination_window(current, total_pages, props.siblings, props.boundaries)
+            .into_iter()
+            .map(|entry| match entry {
+                PaginationPage::Page(page) if page == current => html! {
+                    <li>
+                        <button type="button" class="pagination-link is-current" aria-label={format!("Page {}", page)} aria-current="page">
+                            {page}
+                        </button>
+                    </li>
+                },
+                PaginationPage::Page(page) => html! {
+                    <li>
+                        <button type="button" class="pagination-link" aria-label={format!("Go to page {}", page)} onclick={go_to(page)}>
+                            {page}
+                        </button>
+                    </li>
+                },
+                PaginationPage::Ellipsis => html! { <li><PaginationEllipsis /></li> },
+            })
+            .collect::<Html>();
+        return props.attrs.apply(html! {
+            <nav {class} role="navigation" aria-label="pagination">
+                <button type="button" class="pagination-previous" disabled={current <= 1} onclick={go_to(current.saturating_sub(1).max(1))}>
+                    {props.previous_label.clone()}
+                </button>
+                <button type="button" class="pagination-next" disabled={current >= total_pages} onclick={go_to((current + 1).min(total_pages))}>
+                    {props.next_label.clone()}
+                </button>
+                <ul class="pagination-list">
+                    {pages}
+                </ul>
+            </nav>
+        });
+    }
+    props.attrs.apply(html! {
         <nav {class} role="navigation" aria-label="pagination">
             {props.previous.clone()}
             {props.next.clone()}
@@ -47,7 +177,7 @@ pub fn pagination(props: &PaginationProps) -> Html {
                 {props.children.clone()}
             </ul>
         </nav>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -66,6 +196,9 @@ pub struct PaginationItemProps {
     pub onclick: Callback<MouseEvent>,
     #[prop_or_default]
     pub current: bool,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A pagination element representing a link to a page number, the previous page or the next page.
@@ -77,11 +210,11 @@ pub fn pagination_item(props: &PaginationItemProps) -> Html {
         true => format!("{} is-current", props.item_type),
         false => props.item_type.to_string(),
     };
-    html! {
-        <a class={effective_class} aria-label={props.label.clone()} onclick={props.onclick.clone()}>
+    props.attrs.apply(html! {
+        <a class={effective_class} aria-label={props.label.clone()} aria-current={props.current.then_some("page")} onclick={props.onclick.clone()}>
             {props.children.clone()}
         </a>
-    }
+    })
 }
 
 /// A pagination item type.
@@ -107,6 +240,9 @@ pub struct PaginationEllipsisProps {
     /// Character which will be used as ellipsis (default: `"…"`)
     #[prop_or_else(|| "…".into())]
     pub character: String,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A horizontal ellipsis for pagination range separators.
@@ -114,7 +250,7 @@ pub struct PaginationEllipsisProps {
 /// [https://bulma.io/documentation/components/pagination/](https://bulma.io/documentation/components/pagination/)
 #[component(PaginationEllipsis)]
 pub fn pagination_ellipsis(props: &PaginationEllipsisProps) -> Html {
-    html! {<span class="pagination-ellipsis">{&props.character}</span>}
+    props.attrs.apply(html! {<span class="pagination-ellipsis">{&props.character}</span>})
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -125,17 +261,26 @@ mod router {
     use super::*;
     use serde::Serialize;
     use yew_router::Routable;
-    use yew_router::components::Link;
+    use yew_router::history::Location;
+    use yew_router::hooks::{use_location, use_navigator, use_route};
+
+    use crate::common::RouterAnchor;
 
     #[derive(Clone, Properties, PartialEq)]
-    pub struct RouterProps<R: Routable + Clone + PartialEq + 'static> {
+    pub struct RouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static = ()> {
         /// The Switched item representing the route.
         pub route: R,
+        /// The query string of the link.
+        #[prop_or_default]
+        pub query: Option<Q>,
         /// Html inside the component.
         #[prop_or_default]
         pub children: Children,
         /// The pagination item type for this component.
         pub item_type: PaginationItemType,
+        /// Extra attributes, e.g. a test id, for the root element.
+        #[prop_or_default]
+        pub attrs: ExtraAttrs,
     }
 
     /// A Yew Router anchor button for use in a `Pagination` component.
@@ -146,7 +291,7 @@ mod router {
 
     impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for PaginationItemRouter<R, Q> {
         type Message = ();
-        type Properties = RouterProps<R>;
+        type Properties = RouterProps<R, Q>;
 
         fn create(_ctx: &Context<Self>) -> Self {
             Self {
@@ -157,15 +302,372 @@ mod router {
 
         fn view(&self, ctx: &Context<Self>) -> Html {
             html! {
-                <Link<R, Q>
+                <RouterAnchor<R, Q>
                     to={ctx.props().route.clone()}
+                    query={ctx.props().query.clone()}
                     children={ctx.props().children.clone()}
                     classes={classes!(ctx.props().item_type.to_string())}
+                    attrs={ctx.props().attrs.clone()}
                 />
             }
         }
     }
+
+    //////////////////////////////////////////////////////////////////////////
+    //////////////////////////////////////////////////////////////////////////
+
+    /// The names of the query parameters holding the page and the page size.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct PaginationQueryParams {
+        pub page: AttrValue,
+        pub page_size: AttrValue,
+    }
+
+    impl Default for PaginationQueryParams {
+        fn default() -> Self {
+            Self {
+                page: AttrValue::from("page"),
+                page_size: AttrValue::from("page_size"),
+            }
+        }
+    }
+
+    /// The 1-based page and the page size read from the query string.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct PageQuery {
+        pub page: usize,
+        pub page_size: usize,
+    }
+
+    impl PageQuery {
+        /// This query with the page moved into `1..=total_pages`, e.g. for a bookmarked page past the end.
+        pub fn clamp_to(self, total_pages: usize) -> Self {
+            Self {
+                page: self.page.clamp(1, total_pages.max(1)),
+                ..self
+            }
+        }
+    }
+
+    fn query_pairs(location: &Location) -> Vec<(String, String)> {
+        location.query::<Vec<(String, String)>>().unwrap_or_default()
+    }
+
+    pub(super) fn read_page_query(pairs: &[(String, String)], params: &PaginationQueryParams, default_page_size: usize) -> PageQuery {
+        let number = |name: &str| {
+            pairs
+                .iter()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse::<usize>().ok())
+                .filter(|value| *value > 0)
+        };
+        PageQuery {
+            page: number(&params.page).unwrap_or(1),
+            page_size: number(&params.page_size).unwrap_or(default_page_size.max(1)),
+        }
+    }
+
+    /// Reads the page and page size from the query string of the current location.
+    ///
+    /// Missing, zero or invalid values fall back to page 1 and `default_page_size`. The page is not bounded by the
+    /// number of pages, which the hook does not know; use [`PageQuery::clamp_to`] once it is. The hook re-renders
+    /// on every navigation, including browser back/forward.
+    #[hook]
+    pub fn use_pagination_query(params: &PaginationQueryParams, default_page_size: usize) -> PageQuery {
+        let location = use_location();
+        let pairs = location.as_ref().map(query_pairs).unwrap_or_default();
+        read_page_query(&pairs, params, default_page_size)
+    }
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct PaginationRouterProps {
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// The alignment of this component.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+        /// Make the pagination elements rounded.
+        #[prop_or_default]
+        pub rounded: bool,
+
+        /// The total number of items.
+        #[prop_or_default]
+        pub total_items: Option<usize>,
+        /// The total number of pages; takes precedence over `total_items`.
+        #[prop_or_default]
+        pub total_pages: Option<usize>,
+        /// The page size used when the query string has none.
+        #[prop_or(10)]
+        pub default_page_size: usize,
+        /// The choices of a page size selector; no selector is rendered when empty.
+        #[prop_or_default]
+        pub page_sizes: Vec<usize>,
+        /// The names of the query parameters.
+        #[prop_or_default]
+        pub params: PaginationQueryParams,
+        /// Callback emitted when the page or page size in the query string changes, including back/forward navigation.
+        #[prop_or_default]
+        pub on_change: Callback<PageQuery>,
+
+        /// The number of pages shown on each side of the current page.
+        #[prop_or(1)]
+        pub siblings: usize,
+        /// The number of pages always shown at the start and at the end.
+        #[prop_or(1)]
+        pub boundaries: usize,
+        /// The text of the previous button.
+        #[prop_or_else(|| "Previous".into())]
+        pub previous_label: AttrValue,
+        /// The text of the next button.
+        #[prop_or_else(|| "Next".into())]
+        pub next_label: AttrValue,
+        /// The accessible label of the page size selector.
+        #[prop_or_else(|| "Items per page".into())]
+        pub page_size_label: AttrValue,
+        /// Extra attributes, e.g. a test id, for the pagination's `nav` element.
+        #[prop_or_default]
+        pub attrs: ExtraAttrs,
+    }
+
+    /// An automatic [`Pagination`] keeping the page (and page size) in the query string of the current route.
+    ///
+    /// Changing the page pushes a new history entry, so pages are bookmarkable and browser back/forward moves
+    /// between them. Other query parameters are preserved. A page past the end, e.g. from an old bookmark, shows the
+    /// last page. Read the values with [`use_pagination_query`].
+    #[component(PaginationRouter)]
+    pub fn pagination_router<R: Routable + 'static>(props: &PaginationRouterProps) -> Html {
+        let location = use_location();
+        let navigator = use_navigator();
+        let route = use_route::<R>();
+        let pairs = location.as_ref().map(query_pairs).unwrap_or_default();
+        let current = read_page_query(&pairs, &props.params, props.default_page_size);
+        let total_pages = props
+            .total_pages
+            .or_else(|| props.total_items.map(|total| total.div_ceil(current.page_size)));
+        let current = match total_pages {
+            Some(total_pages) => current.clamp_to(total_pages),
+            None => current,
+        };
+
+        {
+            let on_change = props.on_change.clone();
+            let prev_query = use_mut_ref(move || current);
+            use_effect_with(current, move |current| {
+                let mut prev = prev_query.borrow_mut();
+                if *prev != *current {
+                    on_change.emit(*current);
+                    *prev = *current;
+                }
+                || {}
+            });
+        }
+
+        let navigate = {
+            let params = props.params.clone();
+            let default_page_size = props.default_page_size;
+            Callback::from(move |next: PageQuery| {
+                let (Some(navigator), Some(route)) = (navigator.as_ref(), route.as_ref()) else {
+                    return;
+                };
+                let mut query: Vec<(String, String)> = pairs
+                    .iter()
+                    .filter(|(key, _)| *key != params.page.as_str() && *key != params.page_size.as_str())
+                    .cloned()
+                    .collect();
+                query.push((params.page.to_string(), next.page.to_string()));
+                if next.page_size != default_page_size {
+                    query.push((params.page_size.to_string(), next.page_size.to_string()));
+                }
+                let _ = navigator.push_with_query(route, &query);
+            })
+        };
+
+        let on_page_change = {
+            let navigate = navigate.clone();
+            Callback::from(move |page: usize| navigate.emit(PageQuery { page, ..current }))
+        };
+        let on_page_size_change = Callback::from(move |event: Event| {
+            let value = event.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            if let Ok(page_size) = value.parse() {
+                navigate.emit(PageQuery { page: 1, page_size });
+            }
+        });
+
+        let page_size_selector = if props.page_sizes.is_empty() {
+            Html::default()
+        } else {
+            let options = props
+                .page_sizes
+                .iter()
+                .map(|size| html! { <option value={size.to_string()} selected={*size == current.page_size}>{size}</option> })
+                .collect::<Html>();
+            html! {
+                <div class="select is-small">
+                    <select aria-label={props.page_size_label.clone()} onchange={on_page_size_change}>{options}</select>
+                </div>
+            }
+        };
+
+        html! {
+            <>
+                <Pagination
+                    classes={props.classes.clone()}
+                    size={props.size.clone()}
+                    alignment={props.alignment.clone()}
+                    rounded={props.rounded}
+                    total_items={props.total_items}
+                    total_pages={props.total_pages}
+                    page_size={current.page_size}
+                    current={current.page}
+                    {on_page_change}
+                    siblings={props.siblings}
+                    boundaries={props.boundaries}
+                    previous_label={props.previous_label.clone()}
+                    next_label={props.next_label.clone()}
+                    attrs={props.attrs.clone()}
+                />
+                {page_size_selector}
+            </>
+        }
+    }
 }
 
 #[cfg(feature = "router")]
-pub use router::PaginationItemRouter;
+pub use router::{PageQuery, PaginationItemRouter, PaginationQueryParams, PaginationRouter, PaginationRouterProps, use_pagination_query};
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use PaginationPage::{Ellipsis, Page};
+    #[cfg(feature = "router")]
+    use router::{PageQuery, PaginationQueryParams, read_page_query};
+
+    fn pages(current: usize, total_pages: usize, siblings: usize, boundaries: usize) -> String {
+        pagination_window(current, total_pages, siblings, boundaries)
+            .iter()
+            .map(|entry| match entry {
+                Page(page) => page.to_string(),
+                Ellipsis => "…".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn tiny_page_counts() {
+        assert_eq!(pages(1, 0, 1, 1), "");
+        assert_eq!(pages(1, 1, 1, 1), "1");
+        assert_eq!(pages(1, 2, 1, 1), "1 2");
+        assert_eq!(pages(0, 1, 0, 0), "1");
+    }
+
+    #[test]
+    fn window_follows_the_current_page() {
+        assert_eq!(pages(1, 10, 1, 1), "1 2 3 4 5 … 10");
+        assert_eq!(pages(5, 10, 1, 1), "1 … 4 5 6 … 10");
+        assert_eq!(pages(10, 10, 1, 1), "1 … 6 7 8 9 10");
+        assert_eq!(pages(5, 20, 2, 2), "1 2 3 4 5 6 7 8 … 19 20");
+        assert_eq!(pages(10, 20, 2, 2), "1 2 … 8 9 10 11 12 … 19 20");
+    }
+
+    #[test]
+    fn current_is_clamped() {
+        assert_eq!(pages(0, 10, 1, 1), pages(1, 10, 1, 1));
+        assert_eq!(pages(99, 10, 1, 1), pages(10, 10, 1, 1));
+    }
+
+    #[test]
+    fn oversized_siblings_and_boundaries_show_every_page() {
+        assert_eq!(pages(3, 5, 10, 1), "1 2 3 4 5");
+        assert_eq!(pages(3, 5, 1, 10), "1 2 3 4 5");
+        assert_eq!(pages(2, 3, 0, 5), "1 2 3");
+    }
+
+    #[test]
+    fn an_ellipsis_never_hides_a_single_page() {
+        assert_eq!(pages(4, 7, 1, 1), "1 2 3 4 5 6 7");
+        assert_eq!(pages(4, 9, 1, 1), "1 2 3 4 5 … 9");
+        assert_eq!(pages(6, 9, 1, 1), "1 … 5 6 7 8 9");
+    }
+
+    #[test]
+    fn every_window_is_consistent() {
+        for total_pages in 0..=25 {
+            for current in 0..=total_pages + 2 {
+                for siblings in 0..=4 {
+                    for boundaries in 0..=4 {
+                        let window = pagination_window(current, total_pages, siblings, boundaries);
+                        let context = format!("current {current} of {total_pages}, siblings {siblings}, boundaries {boundaries}: {window:?}");
+                        let page = current.clamp(1, total_pages.max(1));
+                        let shown: Vec<usize> = window
+                            .iter()
+                            .filter_map(|entry| match entry {
+                                Page(page) => Some(*page),
+                                Ellipsis => None,
+                            })
+                            .collect();
+                        assert!(shown.windows(2).all(|pair| pair[0] < pair[1]), "{context}");
+                        assert!(shown.iter().all(|shown| (1..=total_pages).contains(shown)), "{context}");
+                        let required = (1..=total_pages).filter(|candidate| {
+                            *candidate <= boundaries || *candidate > total_pages.saturating_sub(boundaries) || candidate.abs_diff(page) <= siblings
+                        });
+                        for candidate in required {
+                            assert!(shown.contains(&candidate), "page {candidate} missing, {context}");
+                        }
+                        // Pages next to each other in the list are adjacent, unless an ellipsis hiding at least two
+                        // pages sits between them.
+                        let mut previous = 0;
+                        let mut gap = false;
+                        for entry in window.iter().chain([Page(total_pages + 1)].iter()) {
+                            match entry {
+                                Ellipsis => {
+                                    assert!(!gap, "two ellipses in a row, {context}");
+                                    gap = true;
+                                }
+                                Page(next) => {
+                                    if gap {
+                                        assert!(*next - previous > 2, "an ellipsis hides fewer than two pages, {context}");
+                                    } else if total_pages > 0 {
+                                        assert_eq!(*next - previous, 1, "pages skipped without an ellipsis, {context}");
+                                    }
+                                    previous = *next;
+                                    gap = false;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "router")]
+    #[test]
+    fn page_queries_are_parsed_and_clamped() {
+        let params = PaginationQueryParams::default();
+        let read = |query: &[(&str, &str)]| {
+            let pairs: Vec<(String, String)> = query.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+            read_page_query(&pairs, &params, 20)
+        };
+        assert_eq!(read(&[]), PageQuery { page: 1, page_size: 20 });
+        assert_eq!(
+            read(&[("q", "rust"), ("page", "3"), ("page_size", "50")]),
+            PageQuery { page: 3, page_size: 50 }
+        );
+        assert_eq!(read(&[("page", "0"), ("page_size", "0")]), PageQuery { page: 1, page_size: 20 });
+        assert_eq!(read(&[("page", "-2"), ("page_size", "ten")]), PageQuery { page: 1, page_size: 20 });
+        assert_eq!(read(&[("page", "999")]).clamp_to(7), PageQuery { page: 7, page_size: 20 });
+        assert_eq!(read(&[("page", "999")]).clamp_to(0), PageQuery { page: 1, page_size: 20 });
+        assert_eq!(read(&[("page", "4")]).clamp_to(7).page, 4);
+
+        let custom = PaginationQueryParams {
+            page: "p".into(),
+            page_size: "n".into(),
+        };
+        let pairs = vec![("p".to_string(), "2".to_string()), ("page".to_string(), "5".to_string())];
+        assert_eq!(read_page_query(&pairs, &custom, 0), PageQuery { page: 2, page_size: 1 });
+    }
+}
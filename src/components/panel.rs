@@ -1,12 +1,17 @@
 use yew::events::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct PanelProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML content of this panel's heading; it is automatically wrapped in a `p.panel-heading`.
     #[prop_or_default]
     pub heading: Html,
@@ -22,12 +27,12 @@ pub struct PanelProps {
 pub fn panel(props: &PanelProps) -> Html {
     let class = classes!("panel", props.classes.clone());
     let aria_label = (!props.aria_label.is_empty()).then_some(props.aria_label.clone());
-    html! {
+    props.attrs.apply(html! {
         <nav {class} role="navigation" aria-label={aria_label}>
             <p class="panel-heading">{props.heading.clone()}</p>
             {props.children.clone()}
         </nav>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -40,6 +45,9 @@ pub struct PanelTabsProps {
     /// Optional accessible label for the tabs collection.
     #[prop_or_default]
     pub aria_label: AttrValue,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A container for the navigation tabs of a panel.
@@ -53,7 +61,9 @@ pub fn panel_tabs(props: &PanelTabsProps) -> Html {
     // role="tablist" requires role="tab" children and trips axe
     // `aria-required-children`; expose it as a labelled navigation region instead.
     // The accessible tablist widget lives in `tabs.rs` (`Tabs`/`TabItem`/`TabPanel`).
-    html! { <p class="panel-tabs" role="navigation" aria-label={aria_label}>{props.children.clone()}</p> }
+    props
+        .attrs
+        .apply(html! { <p class="panel-tabs" role="navigation" aria-label={aria_label}>{props.children.clone()}</p> })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -65,6 +75,9 @@ pub struct PanelBlockProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -117,7 +130,7 @@ pub fn panel_block(props: &PanelBlockProps) -> Html {
             }
         })
     };
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()}
             {class}
             role={role}
@@ -131,5 +144,5 @@ pub fn panel_block(props: &PanelBlockProps) -> Html {
         >
             {props.children.clone()}
         </@>
-    }
+    })
 }
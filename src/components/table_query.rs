@@ -0,0 +1,444 @@
+/*!
+Shared paging, sorting and filtering state for server-backed tables.
+
+Summary
+- [`TableQuery`] describes one page of a remote data set: the 1-based `page`, the `page_size`, the optional sort
+  column and the active filters.
+- [`use_table_query`] keeps a `TableQuery` in a reducer and emits every change through a callback, so a screen only
+  has to translate the query into a request. The returned [`TableQueryHandle`] is shared by the [`DataTable`], the
+  pagination and the filter inputs of the screen.
+- While a query is in flight the handle reports `loading`; [`TableQueryRequest::resolve`] stores the total number of
+  results of a query and ignores answers to outdated queries.
+- [`TableResultsCount`] renders the "Showing 1–25 of 1234" summary.
+
+Example
+```rust,ignore
+let users = use_state(Vec::<User>::new);
+let on_query = {
+    let users = users.clone();
+    Callback::from(move |request: TableQueryRequest| {
+        let users = users.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let (page, total) = fetch_users(&request.query).await;
+            users.set(page);
+            request.resolve(Some(total));
+        });
+    })
+};
+let table = use_table_query(TableQuery::new(25), on_query);
+html! {
+    <>
+        <Input name="q" value={table.filter("q").to_owned()} update={table.filter_setter("q")} />
+        <DataTable<User>
+            {columns}
+            rows={(*users).clone()}
+            manual_sort=true
+            sort={Some(table.query().sort.clone())}
+            set_sort={table.sort_setter()}
+            loading={table.loading()}
+        />
+        <TableResultsCount query={table.query().clone()} total_items={table.total_items()} />
+    </>
+}
+```
+
+[`DataTable`]: crate::DataTable
+*/
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{ExtraAttrs, SortState};
+
+/// The page, sort order and filters requested from a remote data set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TableQuery {
+    /// The 1-based page number.
+    pub page: usize,
+    /// The number of rows per page.
+    pub page_size: usize,
+    /// The sort column and direction, if any.
+    pub sort: Option<SortState>,
+    /// Filter values by filter name; empty values are never stored.
+    pub filters: BTreeMap<AttrValue, AttrValue>,
+}
+
+impl Default for TableQuery {
+    fn default() -> Self {
+        Self::new(25)
+    }
+}
+
+impl TableQuery {
+    /// The first page of `page_size` rows, unsorted and unfiltered.
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page: 1,
+            page_size: page_size.max(1),
+            sort: None,
+            filters: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the query sorted by `sort`.
+    pub fn with_sort(mut self, sort: SortState) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Returns the query with the filter `name` set to `value`.
+    pub fn with_filter(mut self, name: impl Into<AttrValue>, value: impl Into<AttrValue>) -> Self {
+        self.set_filter(name.into(), value.into());
+        self
+    }
+
+    /// The value of the filter `name`, or an empty string.
+    pub fn filter(&self, name: &str) -> &str {
+        self.filters.get(name).map(|value| value.as_str()).unwrap_or_default()
+    }
+
+    /// The index of the first row of the page, for `offset`/`limit` style APIs.
+    pub fn offset(&self) -> usize {
+        (self.page.max(1) - 1) * self.page_size
+    }
+
+    /// The number of pages needed for `total_items` rows; at least 1.
+    pub fn page_count(&self, total_items: usize) -> usize {
+        total_items.div_ceil(self.page_size.max(1)).max(1)
+    }
+
+    fn set_filter(&mut self, name: AttrValue, value: AttrValue) {
+        if value.trim().is_empty() {
+            self.filters.remove(&name);
+        } else {
+            self.filters.insert(name, value);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TableQueryAction {
+    SetPage(usize),
+    SetPageSize(usize),
+    SetSort(Option<SortState>),
+    SetFilter(AttrValue, AttrValue),
+    ClearFilters,
+    Resolve(TableQuery, Option<usize>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct TableQueryState {
+    query: TableQuery,
+    total_items: Option<usize>,
+    loading: bool,
+}
+
+impl Reducible for TableQueryState {
+    type Action = TableQueryAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut query = self.query.clone();
+        match action {
+            TableQueryAction::SetPage(page) => {
+                let last = self.total_items.map_or(usize::MAX, |total| query.page_count(total));
+                query.page = page.clamp(1, last);
+            }
+            TableQueryAction::SetPageSize(page_size) => {
+                query.page_size = page_size.max(1);
+                query.page = 1;
+            }
+            TableQueryAction::SetSort(sort) => {
+                query.sort = sort;
+                query.page = 1;
+            }
+            TableQueryAction::SetFilter(name, value) => {
+                query.set_filter(name, value);
+                query.page = 1;
+            }
+            TableQueryAction::ClearFilters => {
+                query.filters.clear();
+                query.page = 1;
+            }
+            TableQueryAction::Resolve(resolved, total_items) => {
+                if resolved != self.query {
+                    return self;
+                }
+                let last = total_items.map_or(query.page, |total| query.page_count(total));
+                if query.page > last {
+                    // The results shrank below the current page, so the last page is requested instead.
+                    query.page = last;
+                    return Rc::new(Self {
+                        query,
+                        total_items,
+                        loading: true,
+                    });
+                }
+                return Rc::new(Self {
+                    total_items,
+                    loading: false,
+                    ..(*self).clone()
+                });
+            }
+        }
+        if query == self.query {
+            return self;
+        }
+        Rc::new(Self {
+            query,
+            total_items: self.total_items,
+            loading: true,
+        })
+    }
+}
+
+/// The state returned by [`use_table_query`].
+#[derive(Clone, PartialEq)]
+pub struct TableQueryHandle {
+    state: UseReducerHandle<TableQueryState>,
+}
+
+impl std::fmt::Debug for TableQueryHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableQueryHandle")
+            .field("query", &self.state.query)
+            .field("total_items", &self.state.total_items)
+            .field("loading", &self.state.loading)
+            .finish()
+    }
+}
+
+impl TableQueryHandle {
+    /// The current query.
+    pub fn query(&self) -> &TableQuery {
+        &self.state.query
+    }
+
+    /// Whether the current query has not been resolved yet.
+    pub fn loading(&self) -> bool {
+        self.state.loading
+    }
+
+    /// The total number of results reported for the last resolved query.
+    pub fn total_items(&self) -> Option<usize> {
+        self.state.total_items
+    }
+
+    /// The number of pages of the last resolved query.
+    pub fn page_count(&self) -> Option<usize> {
+        self.state.total_items.map(|total| self.state.query.page_count(total))
+    }
+
+    /// The value of the filter `name`, or an empty string.
+    pub fn filter(&self, name: &str) -> &str {
+        self.state.query.filter(name)
+    }
+
+    /// Go to `page`, clamped to the known page count.
+    pub fn set_page(&self, page: usize) {
+        self.state.dispatch(TableQueryAction::SetPage(page));
+    }
+
+    /// Change the page size and return to the first page.
+    pub fn set_page_size(&self, page_size: usize) {
+        self.state.dispatch(TableQueryAction::SetPageSize(page_size));
+    }
+
+    /// Change the sort order and return to the first page.
+    pub fn set_sort(&self, sort: Option<SortState>) {
+        self.state.dispatch(TableQueryAction::SetSort(sort));
+    }
+
+    /// Set the filter `name` (an empty value removes it) and return to the first page.
+    pub fn set_filter(&self, name: impl Into<AttrValue>, value: impl Into<AttrValue>) {
+        self.state.dispatch(TableQueryAction::SetFilter(name.into(), value.into()));
+    }
+
+    /// Remove all filters and return to the first page.
+    pub fn clear_filters(&self) {
+        self.state.dispatch(TableQueryAction::ClearFilters);
+    }
+
+    /// Record the total number of results of `query` and stop loading.
+    ///
+    /// Answers to a query which is no longer current are ignored, so out-of-order responses cannot overwrite newer
+    /// results. Pass `None` as total when the request failed.
+    pub fn resolve(&self, query: &TableQuery, total_items: Option<usize>) {
+        self.state.dispatch(TableQueryAction::Resolve(query.clone(), total_items));
+    }
+
+    /// A callback for [`set_page`](Self::set_page), e.g. for a pagination `on_page_change`.
+    pub fn page_setter(&self) -> Callback<usize> {
+        let handle = self.clone();
+        Callback::from(move |page| handle.set_page(page))
+    }
+
+    /// A callback for [`set_page_size`](Self::set_page_size).
+    pub fn page_size_setter(&self) -> Callback<usize> {
+        let handle = self.clone();
+        Callback::from(move |page_size| handle.set_page_size(page_size))
+    }
+
+    /// A callback for [`set_sort`](Self::set_sort), e.g. for the `set_sort` prop of a `DataTable`.
+    pub fn sort_setter(&self) -> Callback<Option<SortState>> {
+        let handle = self.clone();
+        Callback::from(move |sort| handle.set_sort(sort))
+    }
+
+    /// A callback setting the filter `name`, e.g. for the `update` prop of an `Input`.
+    pub fn filter_setter(&self, name: impl Into<AttrValue>) -> Callback<String> {
+        let handle = self.clone();
+        let name = name.into();
+        Callback::from(move |value: String| handle.set_filter(name.clone(), value))
+    }
+}
+
+/// A query emitted by [`use_table_query`], to be answered with [`resolve`](Self::resolve) once its data has arrived.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableQueryRequest {
+    pub query: TableQuery,
+    handle: TableQueryHandle,
+}
+
+impl TableQueryRequest {
+    /// Record the total number of results and stop loading; see [`TableQueryHandle::resolve`].
+    pub fn resolve(&self, total_items: Option<usize>) {
+        self.handle.resolve(&self.query, total_items);
+    }
+}
+
+/// Keeps a [`TableQuery`] and emits it through `on_query` initially and on every change.
+///
+/// The handle starts out loading; resolve the emitted [`TableQueryRequest`] once the data of a query has arrived.
+/// When a resolved total leaves the current page past the end, the last page is queried instead. Queries are always
+/// emitted through the `on_query` of the latest render.
+#[hook]
+pub fn use_table_query(initial: TableQuery, on_query: Callback<TableQueryRequest>) -> TableQueryHandle {
+    let state = use_reducer(move || TableQueryState {
+        query: initial,
+        total_items: None,
+        loading: true,
+    });
+    let latest_on_query = use_mut_ref(|| on_query.clone());
+    *latest_on_query.borrow_mut() = on_query;
+
+    let handle = TableQueryHandle { state };
+    {
+        let handle = handle.clone();
+        use_effect_with(handle.query().clone(), move |query| {
+            let on_query = latest_on_query.borrow().clone();
+            on_query.emit(TableQueryRequest {
+                query: query.clone(),
+                handle,
+            });
+            || {}
+        });
+    }
+
+    handle
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct TableResultsCountProps {
+    /// The query of the displayed page.
+    pub query: TableQuery,
+    /// The total number of results, if known.
+    pub total_items: Option<usize>,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// Text shown when there are no results.
+    #[prop_or_else(|| "No results".into())]
+    pub empty_text: AttrValue,
+}
+
+/// A live "Showing 26–50 of 1234" summary of the rows on the current page.
+#[component(TableResultsCount)]
+pub fn table_results_count(props: &TableResultsCountProps) -> Html {
+    let class = classes!("ybc-table-results-count", "is-size-7", props.classes.clone());
+    let text = match props.total_items {
+        None => String::new(),
+        Some(0) => props.empty_text.to_string(),
+        Some(total) => {
+            let first = (props.query.offset() + 1).min(total);
+            let last = (props.query.offset() + props.query.page_size).min(total);
+            format!("Showing {}–{} of {}", first, last, total)
+        }
+    };
+    props.attrs.apply(html! {
+        <p {class} role="status" aria-live="polite">{text}</p>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn reduce(state: TableQueryState, actions: impl IntoIterator<Item = TableQueryAction>) -> TableQueryState {
+        let state = actions.into_iter().fold(Rc::new(state), |state, action| state.reduce(action));
+        (*state).clone()
+    }
+
+    fn resolved(page: usize, total_items: usize) -> TableQueryState {
+        let query = TableQuery { page, ..TableQuery::new(10) };
+        TableQueryState {
+            query: query.clone(),
+            total_items: Some(total_items),
+            loading: false,
+        }
+    }
+
+    #[test]
+    fn pages_are_clamped_to_the_known_count() {
+        assert_eq!(reduce(resolved(1, 95), [TableQueryAction::SetPage(50)]).query.page, 10);
+        assert_eq!(reduce(resolved(3, 95), [TableQueryAction::SetPage(0)]).query.page, 1);
+        let unknown = TableQueryState {
+            total_items: None,
+            ..resolved(1, 0)
+        };
+        assert_eq!(reduce(unknown, [TableQueryAction::SetPage(50)]).query.page, 50);
+    }
+
+    #[test]
+    fn changes_return_to_the_first_page_and_start_loading() {
+        let state = reduce(resolved(4, 95), [TableQueryAction::SetFilter("q".into(), "ada".into())]);
+        assert_eq!(state.query.page, 1);
+        assert_eq!(state.query.filter("q"), "ada");
+        assert!(state.loading);
+        assert!(!reduce(resolved(1, 95), [TableQueryAction::SetFilter("q".into(), " ".into())]).loading);
+    }
+
+    #[test]
+    fn outdated_answers_are_ignored() {
+        let state = reduce(resolved(1, 95), [TableQueryAction::SetPage(2)]);
+        let stale = TableQuery::new(10);
+        let state = reduce(state, [TableQueryAction::Resolve(stale, Some(5))]);
+        assert_eq!(state.total_items, Some(95));
+        assert!(state.loading);
+    }
+
+    #[test]
+    fn a_shrunk_total_moves_to_the_last_page() {
+        let state = reduce(resolved(1, 95), [TableQueryAction::SetPage(9)]);
+        let query = state.query.clone();
+        let state = reduce(state, [TableQueryAction::Resolve(query, Some(42))]);
+        assert_eq!(state.query.page, 5);
+        assert_eq!(state.total_items, Some(42));
+        assert!(state.loading);
+
+        let query = state.query.clone();
+        let state = reduce(state, [TableQueryAction::Resolve(query, Some(0))]);
+        assert_eq!(state.query.page, 1);
+        assert!(state.loading);
+        let query = state.query.clone();
+        let state = reduce(state, [TableQueryAction::Resolve(query, Some(0))]);
+        assert_eq!(state.query.page, 1);
+        assert!(!state.loading);
+    }
+}
@@ -1,15 +1,7 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
-
 use yew::events::{KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
-use crate::{Alignment, Size};
-
-static TABS_AUTO_ID: AtomicUsize = AtomicUsize::new(1);
-
-fn next_tabs_id() -> String {
-    format!("tabs-{}", TABS_AUTO_ID.fetch_add(1, Ordering::Relaxed))
-}
+use crate::{Alignment, ExtraAttrs, Size, use_ybc_id};
 
 #[derive(Clone, PartialEq)]
 struct TabsContext {
@@ -23,6 +15,9 @@ pub struct TabsProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The alignment of this component.
     #[prop_or_default]
     pub alignment: Option<Alignment>,
@@ -119,27 +114,24 @@ pub fn tabs(props: &TabsProps) -> Html {
         props.fullwidth.then_some("is-fullwidth"),
     );
 
-    let auto_id = use_state(|| AttrValue::from(next_tabs_id()));
-    let list_id = props.id.clone().unwrap_or_else(|| (*auto_id).clone());
+    let auto_id = use_ybc_id("tabs");
+    let list_id = props.id.clone().unwrap_or(auto_id);
     let aria_label = (!props.aria_label.is_empty()).then_some(props.aria_label.clone());
 
+    let tabs = props.attrs.apply(html! {
+        <div {class}>
+            <ul id={list_id} role="tablist" aria-label={aria_label}>
+                {props.children.clone()}
+            </ul>
+        </div>
+    });
     if has_parent {
-        html! {
-            <div {class}>
-                <ul id={list_id} role="tablist" aria-label={aria_label}>
-                    {props.children.clone()}
-                </ul>
-            </div>
-        }
+        tabs
     } else {
         let context = TabsContext { active, set_active };
         html! {
             <ContextProvider<TabsContext> {context}>
-                <div {class}>
-                    <ul id={list_id} role="tablist" aria-label={aria_label}>
-                        {props.children.clone()}
-                    </ul>
-                </div>
+                {tabs}
             </ContextProvider<TabsContext>>
         }
     }
@@ -161,6 +153,9 @@ pub struct TabsProviderProps {
     /// Callback emitted when the active tab index changes.
     #[prop_or_default]
     pub on_change: Callback<usize>,
+    /// Extra attributes, e.g. a test id, for a wrapper around the children; see [`ExtraAttrs`].
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A wrapper component providing state context for [`Tabs`], [`TabItem`] and [`TabPanel`].
@@ -200,11 +195,11 @@ pub fn tabs_provider(props: &TabsProviderProps) -> Html {
 
     let context = TabsContext { active, set_active };
 
-    html! {
+    props.attrs.apply(html! {
         <ContextProvider<TabsContext> {context}>
             {props.children.clone()}
         </ContextProvider<TabsContext>>
-    }
+    })
 }
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -213,6 +208,9 @@ pub struct TabItemProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Zero-based tab index used by the tab state manager.
     pub index: usize,
     /// Disable this tab.
@@ -236,9 +234,9 @@ pub struct TabItemProps {
 #[component(TabItem)]
 pub fn tab_item(props: &TabItemProps) -> Html {
     let Some(ctx) = use_context::<TabsContext>() else {
-        return html! {
+        return props.attrs.apply(html! {
             <li class={props.classes.clone()}>{props.children.clone()}</li>
-        };
+        });
     };
 
     let is_active = ctx.active == props.index;
@@ -276,7 +274,7 @@ pub fn tab_item(props: &TabItemProps) -> Html {
         })
     };
 
-    html! {
+    props.attrs.apply(html! {
         <li class={classes!(props.classes.clone(), is_active.then_some("is-active"))} role="presentation">
             <a
                 id={tab_id.to_string()}
@@ -292,7 +290,7 @@ pub fn tab_item(props: &TabItemProps) -> Html {
                 {props.children.clone()}
             </a>
         </li>
-    }
+    })
 }
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -301,6 +299,9 @@ pub struct TabPanelProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Zero-based tab index linked with `TabItem::index`.
     pub index: usize,
     /// Optional id for this panel.
@@ -316,7 +317,7 @@ pub struct TabPanelProps {
 pub fn tab_panel(props: &TabPanelProps) -> Html {
     let is_active = use_context::<TabsContext>().map(|ctx| ctx.active == props.index).unwrap_or(true);
 
-    html! {
+    props.attrs.apply(html! {
         <div
             class={props.classes.clone()}
             id={props.id.clone()}
@@ -327,5 +328,5 @@ pub fn tab_panel(props: &TabPanelProps) -> Html {
         >
             {props.children.clone()}
         </div>
-    }
+    })
 }
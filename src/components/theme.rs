@@ -0,0 +1,571 @@
+/*!
+Theming: Bulma v1 CSS variables written at runtime, and light/dark mode switching.
+
+Summary
+- Wrap the app in a [`ThemeProvider`] with a [`Theme`]. Every color, the radius and the font family set on the theme
+  are rendered as Bulma v1 CSS custom properties, e.g. `--bulma-primary-h`, `--bulma-radius` or
+  `--bulma-family-primary`, which override those of the Bulma stylesheet. Unset values keep Bulma's defaults.
+- [`ThemeColor`]s are given as HSL (`ThemeColor::hsl(171.0, 100.0, 41.0)`) or parsed from hex (`ThemeColor::hex("#00d1b2")`).
+  Bulma derives the light, dark and invert shades of a color from its hue and saturation.
+- The [`ThemeMode`] is `Light`, `Dark` or `System`. Light and dark set `data-theme` on the `<html>` element; `System`
+  removes it, so Bulma follows `prefers-color-scheme`.
+- Call [`use_theme`] in any descendant to get a [`ThemeController`], which reads and switches the mode and overrides
+  the theme at runtime. The chosen mode is saved to `localStorage` under the provider's `storage_key` and restored on
+  the next visit.
+
+Example
+```rust,ignore
+#[component(ThemeToggle)]
+fn theme_toggle() -> Html {
+    let theme = use_theme();
+    let onclick = Callback::from(move |_| theme.toggle());
+    html! { <Button {onclick}>{"Toggle dark mode"}</Button> }
+}
+
+let theme = Theme::new()
+    .primary(ThemeColor::hex("#8a4d76").unwrap())
+    .radius("0.25rem")
+    .family("Inter, sans-serif");
+
+html! {
+    <ThemeProvider {theme}>
+        <ThemeToggle />
+    </ThemeProvider>
+}
+```
+*/
+
+use std::fmt::Write as _;
+
+use derive_more::Display;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use yew::prelude::*;
+
+use crate::ExtraAttrs;
+
+/// The query matching a dark color scheme preference of the user's system.
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// A color given by hue (`0..360` degrees), saturation and lightness (`0..=100` percent).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThemeColor {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+}
+
+impl ThemeColor {
+    /// A color from hue, saturation and lightness.
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self { hue, saturation, lightness }
+    }
+
+    /// Parses a `#rgb` or `#rrggbb` hex color; the `#` is optional.
+    pub fn hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |range: std::ops::Range<usize>, repeat: bool| -> Option<f32> {
+            let value = u8::from_str_radix(&digits[range], 16).ok()?;
+            Some(if repeat { value * 17 } else { value } as f32 / 255.0)
+        };
+        let (red, green, blue) = match digits.len() {
+            3 => (channel(0..1, true)?, channel(1..2, true)?, channel(2..3, true)?),
+            6 => (channel(0..2, false)?, channel(2..4, false)?, channel(4..6, false)?),
+            _ => return None,
+        };
+
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let lightness = (max + min) / 2.0;
+        if max == min {
+            return Some(Self::hsl(0.0, 0.0, lightness * 100.0));
+        }
+        let delta = max - min;
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            60.0 * ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == green {
+            60.0 * ((blue - red) / delta + 2.0)
+        } else {
+            60.0 * ((red - green) / delta + 4.0)
+        };
+        Some(Self::hsl(hue, saturation * 100.0, lightness * 100.0))
+    }
+}
+
+/// Colors, radius and font family overriding those of the Bulma stylesheet; `None` keeps Bulma's default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Theme {
+    pub primary: Option<ThemeColor>,
+    pub link: Option<ThemeColor>,
+    pub info: Option<ThemeColor>,
+    pub success: Option<ThemeColor>,
+    pub warning: Option<ThemeColor>,
+    pub danger: Option<ThemeColor>,
+    /// The default border radius, e.g. `"0.25rem"`.
+    pub radius: Option<AttrValue>,
+    /// The primary font family, e.g. `"Inter, sans-serif"`.
+    pub family: Option<AttrValue>,
+    /// Further CSS custom properties, e.g. `("--bulma-scheme-h", "220deg")`.
+    pub variables: Vec<(AttrValue, AttrValue)>,
+}
+
+impl Theme {
+    /// A theme keeping all of Bulma's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn primary(mut self, color: ThemeColor) -> Self {
+        self.primary = Some(color);
+        self
+    }
+
+    pub fn link(mut self, color: ThemeColor) -> Self {
+        self.link = Some(color);
+        self
+    }
+
+    pub fn info(mut self, color: ThemeColor) -> Self {
+        self.info = Some(color);
+        self
+    }
+
+    pub fn success(mut self, color: ThemeColor) -> Self {
+        self.success = Some(color);
+        self
+    }
+
+    pub fn warning(mut self, color: ThemeColor) -> Self {
+        self.warning = Some(color);
+        self
+    }
+
+    pub fn danger(mut self, color: ThemeColor) -> Self {
+        self.danger = Some(color);
+        self
+    }
+
+    pub fn radius(mut self, radius: impl Into<AttrValue>) -> Self {
+        self.radius = Some(radius.into());
+        self
+    }
+
+    pub fn family(mut self, family: impl Into<AttrValue>) -> Self {
+        self.family = Some(family.into());
+        self
+    }
+
+    /// Set a further CSS custom property; `name` includes the leading `--`.
+    pub fn variable(mut self, name: impl Into<AttrValue>, value: impl Into<AttrValue>) -> Self {
+        self.variables.push((name.into(), value.into()));
+        self
+    }
+
+    /// The CSS custom properties of this theme as name and value pairs, in the order they are rendered.
+    pub fn css_variables(&self) -> Vec<(String, String)> {
+        let mut variables = Vec::new();
+        let colors = [
+            ("primary", self.primary),
+            ("link", self.link),
+            ("info", self.info),
+            ("success", self.success),
+            ("warning", self.warning),
+            ("danger", self.danger),
+        ];
+        for (name, color) in colors {
+            if let Some(color) = color {
+                variables.push((format!("--bulma-{}-h", name), format!("{}deg", round(color.hue))));
+                variables.push((format!("--bulma-{}-s", name), format!("{}%", round(color.saturation))));
+                variables.push((format!("--bulma-{}-l", name), format!("{}%", round(color.lightness))));
+            }
+        }
+        if let Some(radius) = &self.radius {
+            variables.push(("--bulma-radius".to_owned(), radius.to_string()));
+        }
+        if let Some(family) = &self.family {
+            variables.push(("--bulma-family-primary".to_owned(), family.to_string()));
+        }
+        variables.extend(self.variables.iter().map(|(name, value)| (name.to_string(), value.to_string())));
+        variables
+    }
+
+    /// The style sheet setting the variables of this theme on the root element in both modes.
+    fn style_sheet(&self) -> String {
+        let mut css = String::from(":root, [data-theme=light], [data-theme=dark], .theme-light, .theme-dark {\n");
+        for (name, value) in self.css_variables() {
+            let _ = writeln!(css, "    {}: {};", name, value);
+        }
+        css.push('}');
+        css
+    }
+}
+
+/// Round to two decimals, so that values print without float noise.
+fn round(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Whether the page is light, dark or follows the system preference.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq)]
+pub enum ThemeMode {
+    #[display("light")]
+    Light,
+    #[display("dark")]
+    Dark,
+    #[default]
+    #[display("system")]
+    System,
+}
+
+impl ThemeMode {
+    /// The mode saved under `name`, as written by its `Display` implementation.
+    fn parse(name: &str) -> Option<Self> {
+        [Self::Light, Self::Dark, Self::System].into_iter().find(|mode| mode.to_string() == name)
+    }
+}
+
+/// A controller for reading and switching the theme and mode from anywhere in the component tree.
+#[derive(Clone, PartialEq)]
+pub struct ThemeController {
+    mode: UseStateHandle<ThemeMode>,
+    system_dark: UseStateHandle<bool>,
+    theme: Theme,
+    theme_override: UseStateHandle<Option<Theme>>,
+    storage_key: AttrValue,
+}
+
+impl ThemeController {
+    /// The chosen mode.
+    pub fn mode(&self) -> ThemeMode {
+        *self.mode
+    }
+
+    /// Returns true if the page is dark, either chosen or, in `System` mode, preferred by the system.
+    pub fn is_dark(&self) -> bool {
+        match *self.mode {
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+            ThemeMode::System => *self.system_dark,
+        }
+    }
+
+    /// Switch to `mode` and save it to `localStorage`.
+    pub fn set_mode(&self, mode: ThemeMode) {
+        if !self.storage_key.is_empty()
+            && let Some(storage) = local_storage()
+        {
+            let _ = storage.set_item(&self.storage_key, &mode.to_string());
+        }
+        self.mode.set(mode);
+    }
+
+    /// Switch to `Dark` while the page is light and to `Light` while it is dark.
+    pub fn toggle(&self) {
+        self.set_mode(if self.is_dark() { ThemeMode::Light } else { ThemeMode::Dark });
+    }
+
+    /// The theme in effect: the one set through [`ThemeController::set_theme`], or the provider's `theme`.
+    pub fn theme(&self) -> &Theme {
+        self.theme_override.as_ref().unwrap_or(&self.theme)
+    }
+
+    /// Replace the provider's `theme` until [`ThemeController::reset_theme`] is called.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme_override.set(Some(theme));
+    }
+
+    /// Go back to the provider's `theme`.
+    pub fn reset_theme(&self) {
+        self.theme_override.set(None);
+    }
+}
+
+impl std::fmt::Debug for ThemeController {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThemeController")
+            .field("mode", &*self.mode)
+            .field("is_dark", &self.is_dark())
+            .field("theme", self.theme())
+            .finish()
+    }
+}
+
+/// Context type for the theme controller.
+pub type ThemeContext = ThemeController;
+
+/// Returns the [`ThemeController`] of the nearest [`ThemeProvider`].
+///
+/// # Panics
+/// Panics when called outside of a `ThemeProvider`.
+#[hook]
+pub fn use_theme() -> ThemeController {
+    use_context::<ThemeContext>().expect("use_theme must be called inside a ThemeProvider")
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct ThemeProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    /// The colors, radius and font family written as Bulma CSS variables.
+    #[prop_or_default]
+    pub theme: Theme,
+    /// The mode used until one is chosen, and while none is saved.
+    #[prop_or_default]
+    pub default_mode: ThemeMode,
+    /// The `localStorage` key the chosen mode is saved under; nothing is saved when empty.
+    #[prop_or_else(|| "ybc-theme-mode".into())]
+    pub storage_key: AttrValue,
+    /// Extra attributes, e.g. a test id, for a wrapper around the children; see [`ExtraAttrs`].
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// Provides [`ThemeContext`] to descendants, renders the theme's CSS variables and applies the mode to the `<html>`
+/// element.
+///
+/// The saved mode and the system preference are read once mounted, so server-side rendered markup does not depend on
+/// them.
+#[component(ThemeProvider)]
+pub fn theme_provider(props: &ThemeProviderProps) -> Html {
+    let mode = use_state_eq(|| props.default_mode);
+    let system_dark = use_state_eq(|| false);
+    let theme_override = use_state_eq(|| None::<Theme>);
+
+    {
+        let mode = mode.clone();
+        let system_dark = system_dark.clone();
+        use_effect_with(props.storage_key.clone(), move |storage_key| {
+            if !storage_key.is_empty()
+                && let Some(saved) = local_storage()
+                    .and_then(|storage| storage.get_item(storage_key).ok().flatten())
+                    .and_then(|saved| ThemeMode::parse(&saved))
+            {
+                mode.set(saved);
+            }
+
+            let query = web_sys::window().and_then(|window| window.match_media(DARK_SCHEME_QUERY).ok().flatten());
+            let listener = query.clone().map(|query| {
+                system_dark.set(query.matches());
+                let listener = {
+                    let query = query.clone();
+                    Closure::<dyn Fn()>::new(move || system_dark.set(query.matches()))
+                };
+                let _ = query.add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+                listener
+            });
+            move || {
+                if let (Some(query), Some(listener)) = (query, listener) {
+                    let _ = query.remove_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+                }
+            }
+        });
+    }
+
+    use_effect_with(*mode, |mode| {
+        let root = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element());
+        if let Some(root) = &root {
+            let _ = match mode {
+                ThemeMode::System => root.remove_attribute("data-theme"),
+                mode => root.set_attribute("data-theme", &mode.to_string()),
+            };
+        }
+        move || {
+            if let Some(root) = root {
+                let _ = root.remove_attribute("data-theme");
+            }
+        }
+    });
+
+    let controller = ThemeController {
+        mode,
+        system_dark,
+        theme: props.theme.clone(),
+        theme_override,
+        storage_key: props.storage_key.clone(),
+    };
+    let style = controller.theme().style_sheet();
+
+    props.attrs.apply(html! {
+        <ContextProvider<ThemeContext> context={controller}>
+            <style>{style}</style>
+            { for props.children.iter() }
+        </ContextProvider<ThemeContext>>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn rounded(color: ThemeColor) -> (f32, f32, f32) {
+        (round(color.hue), round(color.saturation), round(color.lightness))
+    }
+
+    #[test]
+    fn hex_colors_convert_to_hsl() {
+        assert_eq!(ThemeColor::hex("#00d1b2").map(rounded), Some((171.1, 100.0, 40.98)));
+        assert_eq!(ThemeColor::hex("00d1b2").map(rounded), Some((171.1, 100.0, 40.98)));
+        assert_eq!(ThemeColor::hex("#f00").map(rounded), Some((0.0, 100.0, 50.0)));
+        assert_eq!(ThemeColor::hex("#ff0000"), ThemeColor::hex("#F00"));
+        assert_eq!(ThemeColor::hex("#00f").map(rounded), Some((240.0, 100.0, 50.0)));
+        assert_eq!(ThemeColor::hex("#808080").map(rounded), Some((0.0, 0.0, 50.2)));
+    }
+
+    #[test]
+    fn malformed_hex_colors_are_rejected() {
+        for hex in ["", "#", "#ff", "#ffff", "#fffffff", "#gggggg", "#+f+f+f", "#-1-1-1", "#ffé", " #fff"] {
+            assert_eq!(ThemeColor::hex(hex), None, "{:?}", hex);
+        }
+    }
+
+    #[test]
+    fn css_variables_list_the_set_values_in_order() {
+        assert!(Theme::new().css_variables().is_empty());
+
+        let theme = Theme::new()
+            .danger(ThemeColor::hsl(348.0, 86.0, 61.0))
+            .primary(ThemeColor::hsl(171.1, 100.0, 40.98))
+            .radius("0.25rem")
+            .family("Inter, sans-serif")
+            .variable("--bulma-scheme-h", "220deg");
+        let variables = theme.css_variables();
+        let variables: Vec<_> = variables.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+        assert_eq!(
+            variables,
+            [
+                ("--bulma-primary-h", "171.1deg"),
+                ("--bulma-primary-s", "100%"),
+                ("--bulma-primary-l", "40.98%"),
+                ("--bulma-danger-h", "348deg"),
+                ("--bulma-danger-s", "86%"),
+                ("--bulma-danger-l", "61%"),
+                ("--bulma-radius", "0.25rem"),
+                ("--bulma-family-primary", "Inter, sans-serif"),
+                ("--bulma-scheme-h", "220deg"),
+            ]
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::testing::{Mounted, Queries, click, mount, settle};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Buttons choosing every mode and toggling it, and the chosen mode.
+    #[component]
+    fn ModeSwitch() -> Html {
+        let theme = use_theme();
+        let choose = |label: &'static str, mode: ThemeMode| {
+            let theme = theme.clone();
+            let onclick = Callback::from(move |_: MouseEvent| theme.set_mode(mode));
+            html! { <button type="button" {onclick}>{label}</button> }
+        };
+        let toggle = {
+            let theme = theme.clone();
+            Callback::from(move |_: MouseEvent| theme.toggle())
+        };
+        html! {
+            <>
+                {choose("Light", ThemeMode::Light)}
+                {choose("Dark", ThemeMode::Dark)}
+                {choose("System", ThemeMode::System)}
+                <button type="button" onclick={toggle}>{"Toggle"}</button>
+                <output>{theme.mode().to_string()}</output>
+            </>
+        }
+    }
+
+    fn storage() -> web_sys::Storage {
+        local_storage().expect("the browser should have a local storage")
+    }
+
+    fn data_theme() -> Option<String> {
+        web_sys::window()?.document()?.document_element()?.get_attribute("data-theme")
+    }
+
+    async fn mount_switch(storage_key: &'static str, default_mode: ThemeMode) -> Mounted {
+        let mounted = mount(html! { <ThemeProvider {storage_key} {default_mode}><ModeSwitch /></ThemeProvider> }).await;
+        // The restored mode is applied by an effect of the render the restoring effect causes.
+        settle().await;
+        mounted
+    }
+
+    fn mode(mounted: &Mounted) -> String {
+        mounted.find_all(|element| element.tag() == "output").remove(0).text()
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_mode_is_set_on_the_html_element_and_saved() {
+        let key = "ybc-test-theme-set";
+        storage().remove_item(key).unwrap();
+        let mounted = mount_switch(key, ThemeMode::Light).await;
+        assert_eq!(data_theme().as_deref(), Some("light"));
+
+        click(&mounted.get_by_role_and_name("button", "Dark")).await;
+        assert_eq!(data_theme().as_deref(), Some("dark"));
+        assert_eq!(storage().get_item(key).unwrap().as_deref(), Some("dark"));
+
+        click(&mounted.get_by_role_and_name("button", "System")).await;
+        assert_eq!(data_theme(), None);
+        assert_eq!(storage().get_item(key).unwrap().as_deref(), Some("system"));
+
+        drop(mounted);
+        settle().await;
+        assert_eq!(data_theme(), None);
+        storage().remove_item(key).unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_saved_mode_is_restored_over_the_default() {
+        let key = "ybc-test-theme-restore";
+        storage().set_item(key, "dark").unwrap();
+        let mounted = mount_switch(key, ThemeMode::Light).await;
+        assert_eq!(mode(&mounted), "dark");
+        assert_eq!(data_theme().as_deref(), Some("dark"));
+        drop(mounted);
+        settle().await;
+
+        storage().set_item(key, "system").unwrap();
+        let mounted = mount_switch(key, ThemeMode::Dark).await;
+        assert_eq!(mode(&mounted), "system");
+        assert_eq!(data_theme(), None);
+        drop(mounted);
+        storage().remove_item(key).unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn toggling_in_system_mode_switches_away_from_the_system_preference() {
+        let key = "ybc-test-theme-toggle";
+        storage().remove_item(key).unwrap();
+        let system_dark = web_sys::window()
+            .and_then(|window| window.match_media(DARK_SCHEME_QUERY).ok().flatten())
+            .is_some_and(|query| query.matches());
+        let mounted = mount_switch(key, ThemeMode::System).await;
+        assert_eq!(data_theme(), None);
+
+        click(&mounted.get_by_role_and_name("button", "Toggle")).await;
+        let toggled = if system_dark { "light" } else { "dark" };
+        assert_eq!(mode(&mounted), toggled);
+        assert_eq!(data_theme().as_deref(), Some(toggled));
+
+        click(&mounted.get_by_role_and_name("button", "Toggle")).await;
+        assert_eq!(mode(&mounted), if system_dark { "dark" } else { "light" });
+        drop(mounted);
+        storage().remove_item(key).unwrap();
+    }
+}
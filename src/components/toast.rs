@@ -0,0 +1,591 @@
+/*!
+Toast notifications: transient Bulma notifications triggered from anywhere in the component tree.
+
+Summary
+- Wrap the app in a [`ToastProvider`] and call [`use_toast`] in any descendant to get a [`ToastController`].
+- `controller.show(Toast::success("Saved"))` (or the `info`/`success`/`warning`/`danger` shortcuts) adds a toast
+  and returns its [`ToastId`], which can be passed to `dismiss`.
+- Toasts close themselves after their duration (the provider's `default_duration_ms` unless set per toast). The timer
+  pauses while the pointer is over the toast or it has keyboard focus.
+- Toasts stack in one of six [`ToastPosition`]s. At most `max_visible` toasts are shown; the rest wait in line.
+- The stack is an `aria-live="polite"` region; `Danger` toasts use `role="alert"` so they are announced immediately.
+
+Example
+```rust,ignore
+#[component(SaveButton)]
+fn save_button() -> Html {
+    let toast = use_toast();
+    let onclick = Callback::from(move |_| {
+        toast.show(Toast::success("Profile saved").title("Done"));
+    });
+    html! { <Button {onclick}>{"Save"}</Button> }
+}
+
+html! {
+    <ToastProvider position={ToastPosition::BottomRight} max_visible={3}>
+        <SaveButton />
+    </ToastProvider>
+}
+```
+*/
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use derive_more::Display;
+use gloo_timers::callback::Timeout;
+use web_sys::js_sys;
+use yew::events::{FocusEvent, MouseEvent};
+use yew::prelude::*;
+
+use crate::common::use_style;
+use crate::{Delete, ExtraAttrs};
+
+/// Identifies a toast shown through a [`ToastController`]; unique within its [`ToastProvider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// The color of a toast.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq)]
+pub enum ToastVariant {
+    #[default]
+    #[display("is-info")]
+    Info,
+    #[display("is-success")]
+    Success,
+    #[display("is-warning")]
+    Warning,
+    #[display("is-danger")]
+    Danger,
+    #[display("is-primary")]
+    Primary,
+    #[display("is-link")]
+    Link,
+    #[display("is-dark")]
+    Dark,
+}
+
+/// The corner or edge of the viewport where toasts stack.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq)]
+pub enum ToastPosition {
+    #[default]
+    #[display("is-top-right")]
+    TopRight,
+    #[display("is-top-left")]
+    TopLeft,
+    #[display("is-top-center")]
+    TopCenter,
+    #[display("is-bottom-right")]
+    BottomRight,
+    #[display("is-bottom-left")]
+    BottomLeft,
+    #[display("is-bottom-center")]
+    BottomCenter,
+}
+
+/// A toast to show through [`ToastController::show`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    /// The message text.
+    pub message: AttrValue,
+    /// An optional bold title above the message.
+    pub title: Option<AttrValue>,
+    /// The color of the toast.
+    pub variant: ToastVariant,
+    /// Time in milliseconds until the toast closes; `None` uses the provider's `default_duration_ms`.
+    pub duration_ms: Option<u32>,
+    /// Keep the toast open until it is dismissed.
+    pub sticky: bool,
+    /// Render a `Delete` button closing the toast.
+    pub dismissible: bool,
+    /// Extra attributes, e.g. a test id, for the toast's `notification` element.
+    pub attrs: ExtraAttrs,
+}
+
+impl Toast {
+    /// A dismissible `Info` toast with the default duration.
+    pub fn new(message: impl Into<AttrValue>) -> Self {
+        Self {
+            message: message.into(),
+            title: None,
+            variant: ToastVariant::Info,
+            duration_ms: None,
+            sticky: false,
+            dismissible: true,
+            attrs: ExtraAttrs::new(),
+        }
+    }
+
+    pub fn info(message: impl Into<AttrValue>) -> Self {
+        Self::new(message)
+    }
+
+    pub fn success(message: impl Into<AttrValue>) -> Self {
+        Self::new(message).variant(ToastVariant::Success)
+    }
+
+    pub fn warning(message: impl Into<AttrValue>) -> Self {
+        Self::new(message).variant(ToastVariant::Warning)
+    }
+
+    pub fn danger(message: impl Into<AttrValue>) -> Self {
+        Self::new(message).variant(ToastVariant::Danger)
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<AttrValue>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the color.
+    pub fn variant(mut self, variant: ToastVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the time in milliseconds until the toast closes.
+    pub fn duration_ms(mut self, duration_ms: u32) -> Self {
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+
+    /// Keeps the toast open until it is dismissed.
+    pub fn sticky(mut self) -> Self {
+        self.sticky = true;
+        self
+    }
+
+    /// Sets whether the toast has a close button.
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Sets extra attributes, e.g. a test id, for the toast.
+    pub fn attrs(mut self, attrs: impl Into<ExtraAttrs>) -> Self {
+        self.attrs = attrs.into();
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ToastAction {
+    Show(ToastId, Toast),
+    Dismiss(ToastId),
+    Clear,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ToastState {
+    toasts: Vec<(ToastId, Toast)>,
+}
+
+impl Reducible for ToastState {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+
+        match action {
+            ToastAction::Show(id, toast) => toasts.push((id, toast)),
+            ToastAction::Dismiss(id) => toasts.retain(|(other, _)| *other != id),
+            ToastAction::Clear => toasts.clear(),
+        }
+
+        Rc::new(Self { toasts })
+    }
+}
+
+impl ToastState {
+    /// The toasts on screen: the oldest `max_visible`, at least one; the others wait in line.
+    fn visible(&self, max_visible: usize) -> &[(ToastId, Toast)] {
+        &self.toasts[..self.toasts.len().min(max_visible.max(1))]
+    }
+}
+
+/// A controller for showing and dismissing toasts from anywhere in the component tree.
+#[derive(Clone, PartialEq)]
+pub struct ToastController {
+    state: UseReducerHandle<ToastState>,
+    next_id: Rc<RefCell<usize>>,
+}
+
+impl ToastController {
+    fn new(state: UseReducerHandle<ToastState>, next_id: Rc<RefCell<usize>>) -> Self {
+        Self { state, next_id }
+    }
+
+    /// Show a toast, returning its id.
+    pub fn show(&self, toast: Toast) -> ToastId {
+        let id = {
+            let mut next_id = self.next_id.borrow_mut();
+            *next_id += 1;
+            ToastId(*next_id)
+        };
+        self.state.dispatch(ToastAction::Show(id, toast));
+        id
+    }
+
+    /// Show an `Info` toast.
+    pub fn info(&self, message: impl Into<AttrValue>) -> ToastId {
+        self.show(Toast::info(message))
+    }
+
+    /// Show a `Success` toast.
+    pub fn success(&self, message: impl Into<AttrValue>) -> ToastId {
+        self.show(Toast::success(message))
+    }
+
+    /// Show a `Warning` toast.
+    pub fn warning(&self, message: impl Into<AttrValue>) -> ToastId {
+        self.show(Toast::warning(message))
+    }
+
+    /// Show a `Danger` toast.
+    pub fn danger(&self, message: impl Into<AttrValue>) -> ToastId {
+        self.show(Toast::danger(message))
+    }
+
+    /// Close a toast by id.
+    pub fn dismiss(&self, id: ToastId) {
+        self.state.dispatch(ToastAction::Dismiss(id));
+    }
+
+    /// Close all toasts, including the queued ones.
+    pub fn clear(&self) {
+        self.state.dispatch(ToastAction::Clear);
+    }
+
+    /// The number of open toasts, including the queued ones.
+    pub fn len(&self) -> usize {
+        self.state.toasts.len()
+    }
+
+    /// Returns true if no toast is open.
+    pub fn is_empty(&self) -> bool {
+        self.state.toasts.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ToastController {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastController").field("toasts", &self.state.toasts).finish()
+    }
+}
+
+/// Context type for the toast controller.
+pub type ToastContext = ToastController;
+
+/// Returns the [`ToastController`] of the nearest [`ToastProvider`].
+///
+/// # Panics
+/// Panics when called outside of a `ToastProvider`.
+#[hook]
+pub fn use_toast() -> ToastController {
+    use_context::<ToastContext>().expect("use_toast must be called inside a ToastProvider")
+}
+
+const TOAST_STYLE: &str = r#"
+.ybc-toasts {
+    position: fixed;
+    z-index: 1000;
+    display: flex;
+    flex-direction: column;
+    gap: 0.75rem;
+    width: min(24rem, calc(100vw - 2rem));
+    pointer-events: none;
+}
+
+.ybc-toasts.is-top-right { top: 1rem; right: 1rem; }
+.ybc-toasts.is-top-left { top: 1rem; left: 1rem; }
+.ybc-toasts.is-top-center { top: 1rem; left: 50%; transform: translateX(-50%); }
+.ybc-toasts.is-bottom-right { bottom: 1rem; right: 1rem; flex-direction: column-reverse; }
+.ybc-toasts.is-bottom-left { bottom: 1rem; left: 1rem; flex-direction: column-reverse; }
+.ybc-toasts.is-bottom-center { bottom: 1rem; left: 50%; transform: translateX(-50%); flex-direction: column-reverse; }
+
+.ybc-toasts .notification {
+    margin-bottom: 0;
+    pointer-events: auto;
+    box-shadow: 0 0.5em 1em -0.125em rgba(10, 10, 10, 0.1);
+}
+"#;
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct ToastProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Where the toasts stack.
+    #[prop_or_default]
+    pub position: ToastPosition,
+    /// The maximum number of toasts shown at once; further toasts wait until a slot frees up.
+    #[prop_or(5)]
+    pub max_visible: usize,
+    /// Time in milliseconds until a toast without its own duration closes.
+    #[prop_or(5000)]
+    pub default_duration_ms: u32,
+    /// The accessible label of the toast region.
+    #[prop_or_else(|| "Notifications".into())]
+    pub aria_label: AttrValue,
+    /// Extra attributes, e.g. a test id, for the toast region.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// Provides [`ToastContext`] to descendants and renders the toast stack.
+#[component]
+pub fn ToastProvider(props: &ToastProviderProps) -> Html {
+    use_style("ybc-toast-style", TOAST_STYLE);
+    let state = use_reducer(ToastState::default);
+    let next_id = use_mut_ref(|| 0);
+    let controller = ToastController::new(state.clone(), next_id);
+
+    let on_dismiss = {
+        let controller = controller.clone();
+        Callback::from(move |id: ToastId| controller.dismiss(id))
+    };
+    let toasts = state
+        .visible(props.max_visible)
+        .iter()
+        .map(|(id, toast)| {
+            let duration_ms = (!toast.sticky).then(|| toast.duration_ms.unwrap_or(props.default_duration_ms));
+            html! {
+                <ToastItem key={id.0} id={*id} toast={toast.clone()} {duration_ms} on_dismiss={on_dismiss.clone()} />
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <ContextProvider<ToastContext> context={controller}>
+            { for props.children.iter() }
+            {props.attrs.apply(html! {
+                <div
+                    class={classes!("ybc-toasts", props.position.to_string(), props.classes.clone())}
+                    role="region"
+                    aria-label={props.aria_label.clone()}
+                    aria-live="polite"
+                >
+                    {toasts}
+                </div>
+            })}
+        </ContextProvider<ToastContext>>
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct ToastItemProps {
+    id: ToastId,
+    toast: Toast,
+    duration_ms: Option<u32>,
+    on_dismiss: Callback<ToastId>,
+}
+
+#[component]
+fn ToastItem(props: &ToastItemProps) -> Html {
+    let hovered = use_state(|| false);
+    let focused = use_state(|| false);
+    let paused = *hovered || *focused;
+    let remaining = use_mut_ref(|| props.duration_ms.map(f64::from));
+
+    {
+        let id = props.id;
+        let on_dismiss = props.on_dismiss.clone();
+        let remaining = remaining.clone();
+        use_effect_with(paused, move |paused| {
+            let timer = match *remaining.borrow() {
+                Some(left) if !*paused => {
+                    let started = js_sys::Date::now();
+                    let timeout = Timeout::new(left.max(0.0) as u32, move || on_dismiss.emit(id));
+                    Some((started, timeout))
+                }
+                _ => None,
+            };
+            move || {
+                if let Some((started, timeout)) = timer {
+                    timeout.cancel();
+                    if let Some(left) = remaining.borrow_mut().as_mut() {
+                        *left -= js_sys::Date::now() - started;
+                    }
+                }
+            }
+        });
+    }
+
+    let on_close = {
+        let id = props.id;
+        let on_dismiss = props.on_dismiss.clone();
+        Callback::from(move |_: MouseEvent| on_dismiss.emit(id))
+    };
+    let onmouseenter = flag_setter::<MouseEvent>(&hovered, true);
+    let onmouseleave = flag_setter::<MouseEvent>(&hovered, false);
+    let onfocusin = flag_setter::<FocusEvent>(&focused, true);
+    let onfocusout = flag_setter::<FocusEvent>(&focused, false);
+    let (role, aria_live): (&str, Option<&str>) = match props.toast.variant {
+        ToastVariant::Danger => ("alert", Some("assertive")),
+        _ => ("status", None),
+    };
+
+    props.toast.attrs.apply(html! {
+        <div
+            class={classes!("notification", "ybc-toast", props.toast.variant.to_string())}
+            {role}
+            aria-live={aria_live}
+            aria-atomic="true"
+            {onmouseenter}
+            {onmouseleave}
+            {onfocusin}
+            {onfocusout}
+        >
+            if props.toast.dismissible {
+                <Delete onclick={on_close} aria_label="close" />
+            }
+            if let Some(title) = props.toast.title.clone() {
+                <p class="has-text-weight-semibold">{title}</p>
+            }
+            <p>{props.toast.message.clone()}</p>
+        </div>
+    })
+}
+
+fn flag_setter<E: 'static>(flag: &UseStateHandle<bool>, value: bool) -> Callback<E> {
+    let flag = flag.clone();
+    Callback::from(move |_: E| flag.set(value))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+
+    fn shown(messages: &[&'static str]) -> Rc<ToastState> {
+        messages
+            .iter()
+            .enumerate()
+            .fold(Rc::default(), |state: Rc<ToastState>, (index, message)| {
+                state.reduce(ToastAction::Show(ToastId(index + 1), Toast::new(*message)))
+            })
+    }
+
+    fn messages(toasts: &[(ToastId, Toast)]) -> Vec<&str> {
+        toasts.iter().map(|(_, toast)| toast.message.as_str()).collect()
+    }
+
+    #[test]
+    fn toasts_are_shown_dismissed_and_cleared() {
+        let state = shown(&["Saved", "Sent", "Failed"]);
+        assert_eq!(messages(&state.toasts), ["Saved", "Sent", "Failed"]);
+        let state = state.reduce(ToastAction::Dismiss(ToastId(2)));
+        assert_eq!(messages(&state.toasts), ["Saved", "Failed"]);
+        let state = state.reduce(ToastAction::Dismiss(ToastId(9)));
+        assert_eq!(messages(&state.toasts), ["Saved", "Failed"]);
+        assert!(state.reduce(ToastAction::Clear).toasts.is_empty());
+    }
+
+    #[test]
+    fn toasts_beyond_max_visible_wait_in_line() {
+        let state = shown(&["Saved", "Sent", "Failed"]);
+        assert_eq!(messages(state.visible(2)), ["Saved", "Sent"]);
+        assert_eq!(messages(state.visible(5)), ["Saved", "Sent", "Failed"]);
+        assert_eq!(messages(state.visible(0)), ["Saved"]);
+
+        let state = state.reduce(ToastAction::Dismiss(ToastId(1)));
+        assert_eq!(messages(state.visible(2)), ["Sent", "Failed"]);
+        assert!(ToastState::default().visible(2).is_empty());
+    }
+
+    /// Shows two toasts while rendering and prints their ids.
+    #[component]
+    fn ShowTwice() -> Html {
+        let toast = use_toast();
+        let ids = use_mut_ref(|| (toast.info("One"), toast.info("Two")));
+        html! { <p>{format!("{:?}", *ids.borrow())}</p> }
+    }
+
+    #[test]
+    fn ids_are_counted_per_provider() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <ToastProvider><ShowTwice /></ToastProvider>
+                    <ToastProvider><ShowTwice /></ToastProvider>
+                </>
+            }
+        });
+        let ids: Vec<_> = rendered.find_all(|element| element.tag() == "p").iter().map(Queries::text).collect();
+        assert_eq!(ids, ["(ToastId(1), ToastId(2))", "(ToastId(1), ToastId(2))"]);
+    }
+
+    #[test]
+    fn the_stack_is_a_polite_live_region() {
+        let rendered = render_blocking(|| html! { <ToastProvider position={ToastPosition::TopLeft} aria_label="Alerts" /> });
+        let region = rendered.get_by_role_and_name("region", "Alerts");
+        assert_eq!(region.attr("aria-live").as_deref(), Some("polite"));
+        assert!(region.has_class("ybc-toasts") && region.has_class("is-top-left"));
+    }
+
+    #[test]
+    fn danger_toasts_are_announced_as_alerts() {
+        let item =
+            |toast: fn() -> Toast| move || html! { <ToastItem id={ToastId(1)} toast={toast()} duration_ms={None} on_dismiss={Callback::noop()} /> };
+
+        let danger = render_blocking(item(|| Toast::danger("Upload failed").title("Error")));
+        let alert = danger.get_by_role("alert");
+        assert_eq!(alert.attr("aria-live").as_deref(), Some("assertive"));
+        assert!(alert.has_class("is-danger"));
+        assert!(alert.query_by_text("Error").is_some() && alert.query_by_text("Upload failed").is_some());
+        assert!(alert.query_by_role_and_name("button", "close").is_some());
+
+        let info = render_blocking(item(|| Toast::success("Saved").dismissible(false)));
+        let status = info.get_by_role("status");
+        assert_eq!(status.attr("aria-live"), None);
+        assert!(status.query_by_role_and_name("button", "close").is_none());
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::testing::{Mounted, Queries, click, mount};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Shows a sticky toast numbered by the click count on every click.
+    #[component]
+    fn Notify() -> Html {
+        let toast = use_toast();
+        let count = use_mut_ref(|| 0);
+        let onclick = Callback::from(move |_: MouseEvent| {
+            *count.borrow_mut() += 1;
+            toast.show(Toast::info(format!("Toast {}", count.borrow())).sticky());
+        });
+        html! { <button type="button" {onclick}>{"Notify"}</button> }
+    }
+
+    fn shown(mounted: &Mounted) -> Vec<String> {
+        let region = mounted.get_by_role("region");
+        region.get_all_by_role("status").iter().map(|toast| toast.text()).collect()
+    }
+
+    #[wasm_bindgen_test]
+    async fn queued_toasts_move_up_when_one_is_dismissed() {
+        let mounted = mount(html! { <ToastProvider max_visible={2}><Notify /></ToastProvider> }).await;
+        for _ in 0..3 {
+            click(&mounted.get_by_role_and_name("button", "Notify")).await;
+        }
+        assert_eq!(shown(&mounted), ["Toast 1", "Toast 2"]);
+
+        click(
+            &mounted
+                .get_all_by_role("button")
+                .into_iter()
+                .find(|button| button.attr("aria-label").as_deref() == Some("close"))
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(shown(&mounted), ["Toast 2", "Toast 3"]);
+    }
+}
@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct BlockProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// Bulma’s most basic spacer block
@@ -14,9 +19,9 @@ pub struct BlockProps {
 #[component(Block)]
 pub fn block(props: &BlockProps) -> Html {
     let class = classes!("block", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct BoxProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A white box to contain other elements.
@@ -13,9 +18,9 @@ pub struct BoxProps {
 /// [https://bulma.io/documentation/elements/box/](https://bulma.io/documentation/elements/box/)
 #[component(Box)]
 pub fn r#box(props: &BoxProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <div class={classes!("box", props.classes.clone())}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
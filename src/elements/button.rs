@@ -2,12 +2,17 @@ use derive_more::Display;
 use yew::events::{Event, KeyboardEvent, MouseEvent};
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ButtonsProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The size for all buttons within this group.
     #[prop_or_default]
     pub size: Option<ButtonGroupSize>,
@@ -19,11 +24,11 @@ pub struct ButtonsProps {
 #[component(Buttons)]
 pub fn buttons(props: &ButtonsProps) -> Html {
     let class = classes!("buttons", props.classes.clone(), props.size.as_ref().map(ToString::to_string));
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
 
 /// The 3 sizes available for a button group.
@@ -121,6 +126,9 @@ pub struct ButtonProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The click handler to use for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -210,7 +218,7 @@ pub fn button(props: &ButtonProps) -> Html {
         props.fullwidth.then_some("is-fullwidth")
     );
     let _id = props.id.clone();
-    html! {
+    props.attrs.apply(html! {
         <button
             id={_id}
             {class}
@@ -229,7 +237,7 @@ pub fn button(props: &ButtonProps) -> Html {
         >
             {props.children.clone()}
         </button>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -241,6 +249,9 @@ pub struct IconButtonProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The click handler to use for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -296,6 +307,7 @@ pub fn icon_button(props: &IconButtonProps) -> Html {
     html! {
         <Button
             classes={props.classes.clone()}
+            attrs={props.attrs.clone()}
             onclick={props.onclick.clone()}
             loading={props.loading}
             r#static={props.r#static}
@@ -340,7 +352,8 @@ mod router {
     use super::*;
     use serde::Serialize;
     use yew_router::Routable;
-    use yew_router::components::Link;
+
+    use crate::common::RouterAnchor;
 
     #[derive(Clone, Properties, PartialEq)]
     pub struct ButtonRouterProps<R: Routable + Clone + PartialEq + 'static> {
@@ -376,6 +389,9 @@ mod router {
         /// Make this button full width.
         #[prop_or_default]
         pub fullwidth: bool,
+        /// Extra attributes, e.g. a test id, for the root element.
+        #[prop_or_default]
+        pub attrs: ExtraAttrs,
     }
 
     /// A Yew Router button element with Bulma styling.
@@ -407,10 +423,11 @@ mod router {
                 ctx.props().fullwidth.then_some("is-fullwidth"),
             );
             html! {
-                <Link<R, Q>
+                <RouterAnchor<R, Q>
                     to={ctx.props().route.clone()}
                     disabled={ctx.props().disabled}
                     {classes}
+                    attrs={ctx.props().attrs.clone()}
                     children={ctx.props().children.clone()}
                 />
             }
@@ -446,10 +463,11 @@ mod router {
                 ctx.props().fullwidth.then_some("is-fullwidth"),
             );
             html! {
-                <Link<R, Q>
+                <RouterAnchor<R, Q>
                     to={ctx.props().route.clone()}
                     disabled={ctx.props().disabled}
                     {classes}
+                    attrs={ctx.props().attrs.clone()}
                     children={ctx.props().children.clone()}
                 />
             }
@@ -469,6 +487,9 @@ pub struct ButtonAnchorProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The `href` attribute value to use for this component.
     #[prop_or_default]
     pub href: String,
@@ -523,7 +544,7 @@ pub fn button_anchor(props: &ButtonAnchorProps) -> Html {
         props.rounded.then_some("is-rounded"),
         props.fullwidth.then_some("is-fullwidth")
     );
-    html! {
+    props.attrs.apply(html! {
         <a
             {class}
             onclick={props.onclick.clone()}
@@ -534,7 +555,7 @@ pub fn button_anchor(props: &ButtonAnchorProps) -> Html {
         >
             {props.children.clone()}
         </a>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -544,6 +565,12 @@ pub fn button_anchor(props: &ButtonAnchorProps) -> Html {
 pub struct ButtonInputSubmitProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The text of the button; browsers show a localized "Submit" when unset.
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
     /// The submit handler to use for this component.
     #[prop_or_default]
     pub onsubmit: Callback<SubmitEvent>,
@@ -569,9 +596,9 @@ pub fn button_input_submit(props: &ButtonInputSubmitProps) -> Html {
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static"),
     );
-    html! {
-        <input type="submit" {class} onsubmit={props.onsubmit.clone()} disabled={props.disabled} />
-    }
+    props.attrs.apply(html! {
+        <input type="submit" {class} value={props.value.clone()} onsubmit={props.onsubmit.clone()} disabled={props.disabled} />
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -581,6 +608,9 @@ pub fn button_input_submit(props: &ButtonInputSubmitProps) -> Html {
 pub struct ButtonInputResetProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The reset handler to use for this component.
     #[prop_or_default]
     pub onreset: Callback<Event>,
@@ -606,7 +636,7 @@ pub fn button_input_reset(props: &ButtonInputResetProps) -> Html {
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static"),
     );
-    html! {
+    props.attrs.apply(html! {
         <input type="reset" {class} onreset={props.onreset.clone()} disabled={props.disabled} />
-    }
+    })
 }
@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ContentProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -16,9 +21,9 @@ pub struct ContentProps {
 /// [https://bulma.io/documentation/elements/content/](https://bulma.io/documentation/elements/content/)
 #[component(Content)]
 pub fn content(props: &ContentProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} class={classes!("content", props.classes.clone())}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct DeleteProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "button".into())]
     pub tag: String,
@@ -24,9 +29,9 @@ pub struct DeleteProps {
 pub fn delete(props: &DeleteProps) -> Html {
     let class = classes!("delete", props.classes.clone());
     let aria_label = (!props.aria_label.is_empty()).then_some(props.aria_label.clone());
-    html! {
-        <@{props.tag.clone()} {class} aria-label={aria_label} onclick={props.onclick.clone()}>
+    props.attrs.apply(html! {
+        <@{props.tag.clone()} {class} type={(props.tag == "button").then_some("button")} aria-label={aria_label} onclick={props.onclick.clone()}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
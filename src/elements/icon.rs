@@ -1,7 +1,7 @@
 use yew::events::MouseEvent;
 use yew::prelude::*;
 
-use crate::{Alignment, Size};
+use crate::{Alignment, ExtraAttrs, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct IconProps {
@@ -9,6 +9,9 @@ pub struct IconProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The click handler to use for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -31,11 +34,11 @@ pub fn icon(props: &IconProps) -> Html {
         props.size.as_ref().map(|size| size.to_string()),
         props.alignment.as_ref().map(|alignment| alignment.to_string()),
     );
-    html! {
+    props.attrs.apply(html! {
         <span {class} onclick={props.onclick.clone()}>
             {props.children.clone()}
         </span>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -50,6 +53,9 @@ pub struct FaIconProps {
     /// Optional extra classes for the wrapper `.icon`.
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The click handler to use for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -84,6 +90,7 @@ pub fn fa_icon(props: &FaIconProps) -> Html {
     html! {
         <Icon
             classes={props.classes.clone()}
+            attrs={props.attrs.clone()}
             onclick={props.onclick.clone()}
             size={props.size.clone()}
             alignment={props.alignment.clone()}
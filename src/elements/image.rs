@@ -1,12 +1,17 @@
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ImageProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<ImageSize>,
@@ -18,11 +23,11 @@ pub struct ImageProps {
 #[component(Image)]
 pub fn image(props: &ImageProps) -> Html {
     let class = classes!("image", props.classes.clone(), props.size.as_ref().map(|size| size.to_string()));
-    html! {
+    props.attrs.apply(html! {
         <figure {class}>
             {props.children.clone()}
         </figure>
-    }
+    })
 }
 
 /// Available placeholder sizes for figures.
@@ -1,22 +1,175 @@
+use derive_more::Display;
 use yew::prelude::*;
 
+use crate::{Delete, ExtraAttrs};
+
+/// Semantic colors for Bulma notifications.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum NotificationColor {
+    #[display("is-white")]
+    White,
+    #[display("is-light")]
+    Light,
+    #[display("is-dark")]
+    Dark,
+    #[display("is-black")]
+    Black,
+    #[display("is-primary")]
+    Primary,
+    #[display("is-link")]
+    Link,
+    #[display("is-info")]
+    Info,
+    #[display("is-success")]
+    Success,
+    #[display("is-warning")]
+    Warning,
+    #[display("is-danger")]
+    Danger,
+}
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct NotificationProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<NotificationColor>,
+    /// Use the light version of the color.
+    #[prop_or_default]
+    pub light: bool,
+    /// Render a `delete` button which closes this component.
+    #[prop_or_default]
+    pub dismissible: bool,
+    /// Initial open state for uncontrolled mode.
+    #[prop_or(true)]
+    pub default_open: bool,
+    /// Controlled open state.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// Controlled open state setter.
+    #[prop_or_default]
+    pub set_open: Option<Callback<bool>>,
+    /// Called when the notification opens.
+    #[prop_or_default]
+    pub on_open: Callback<()>,
+    /// Called when the notification closes.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// The `aria-label` of the delete button.
+    #[prop_or_else(|| "close".into())]
+    pub delete_aria_label: AttrValue,
 }
 
 /// Bold notification blocks, to alert your users of something.
 ///
 /// [https://bulma.io/documentation/elements/notification/](https://bulma.io/documentation/elements/notification/)
+///
+/// With `dismissible` the notification renders a delete button. It closes itself in uncontrolled mode; in
+/// controlled mode (`open` + `set_open`) the click is reported through `set_open(false)`.
 #[component(Notification)]
 pub fn notification(props: &NotificationProps) -> Html {
-    let class = classes!("notification", props.classes.clone());
-    html! {
+    let internal_open = use_state(|| props.default_open);
+    let is_controlled = props.open.is_some() && props.set_open.is_some();
+    let is_open = props.open.unwrap_or(*internal_open);
+
+    let set_local_open = {
+        let internal_open = internal_open.clone();
+        let set_open = props.set_open.clone();
+        Callback::from(move |value: bool| {
+            if is_controlled {
+                if let Some(set_open) = set_open.as_ref() {
+                    set_open.emit(value);
+                }
+            } else {
+                internal_open.set(value);
+            }
+        })
+    };
+
+    {
+        let on_open = props.on_open.clone();
+        let on_close = props.on_close.clone();
+        let prev_open = use_mut_ref(move || is_open);
+        use_effect_with(is_open, move |is_open| {
+            let mut prev = prev_open.borrow_mut();
+            if *prev != *is_open {
+                if *is_open {
+                    on_open.emit(());
+                } else {
+                    on_close.emit(());
+                }
+                *prev = *is_open;
+            }
+            || {}
+        });
+    }
+
+    if !is_open {
+        return Html::default();
+    }
+
+    let class = classes!(
+        "notification",
+        props.classes.clone(),
+        props.color.as_ref().map(ToString::to_string),
+        props.light.then_some("is-light"),
+    );
+    let on_delete = set_local_open.reform(|_: MouseEvent| false);
+    props.attrs.apply(html! {
         <div {class}>
+            if props.dismissible {
+                <Delete onclick={on_delete} aria_label={props.delete_aria_label.clone()} />
+            }
             {props.children.clone()}
         </div>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+
+    #[test]
+    fn dismissible_notifications_render_a_delete_button() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Notification dismissible=true delete_aria_label="Hide" classes="first">{"Saved"}</Notification>
+                    <Notification classes="second">{"Sent"}</Notification>
+                </>
+            }
+        });
+        let first = rendered.find_all(|element| element.has_class("first")).remove(0);
+        let delete = first.get_by_role_and_name("button", "Hide");
+        assert!(delete.has_class("delete"));
+        assert_eq!(first.children()[0], delete);
+        let second = rendered.find_all(|element| element.has_class("second")).remove(0);
+        assert!(second.find_all(|element| element.tag() == "button").is_empty());
+    }
+
+    #[test]
+    fn closed_notifications_render_nothing() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Notification open={false} set_open={Callback::noop()} dismissible=true>{"Controlled"}</Notification>
+                    <Notification default_open={false}>{"Uncontrolled"}</Notification>
+                    <Notification open={true}>{"Open"}</Notification>
+                </>
+            }
+        });
+        let notifications: Vec<_> = rendered
+            .find_all(|element| element.has_class("notification"))
+            .iter()
+            .map(Queries::text)
+            .collect();
+        assert_eq!(notifications, ["Open"]);
     }
 }
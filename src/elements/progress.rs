@@ -1,9 +1,14 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ProgressProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The maximum amount of progress; the 100% value.
     #[prop_or_else(|| 1.0)]
     pub max: f32,
@@ -21,7 +26,7 @@ pub fn progress(props: &ProgressProps) -> Html {
     let max = props.max.to_string();
     let value = props.value.to_string();
     let value_txt = format!("{}%", value);
-    if props.value == -1.0 {
+    props.attrs.apply(if props.value == -1.0 {
         html! {
             <progress {class} {max}/>
         }
@@ -31,5 +36,5 @@ pub fn progress(props: &ProgressProps) -> Html {
                 {value_txt}
             </progress>
         }
-    }
+    })
 }
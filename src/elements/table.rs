@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TableProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Add borders to all the cells.
     #[prop_or_default]
     pub bordered: bool,
@@ -40,7 +45,7 @@ pub fn table(props: &TableProps) -> Html {
         props.hoverable.then_some("is-hoverable"),
         props.fullwidth.then_some("is-fullwidth"),
     );
-    if props.scrollable {
+    props.attrs.apply(if props.scrollable {
         html! {
             <div class="table-container">
                 <table {class}>
@@ -54,5 +59,5 @@ pub fn table(props: &TableProps) -> Html {
                 {props.children.clone()}
             </table>
         }
-    }
+    })
 }
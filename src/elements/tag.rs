@@ -1,6 +1,6 @@
 use yew::prelude::*;
 
-use crate::Size;
+use crate::{ExtraAttrs, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TagProps {
@@ -8,6 +8,9 @@ pub struct TagProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "span".into())]
     pub tag: String,
@@ -37,11 +40,11 @@ pub fn tag(props: &TagProps) -> Html {
         props.delete.then_some("is-delete"),
         props.size.as_ref().map(|size| size.to_string()),
     );
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class} onclick={props.onclick.clone()}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -53,6 +56,9 @@ pub struct TagsProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Attach two tags together; this requires that this component wraps two `Tag` components.
     #[prop_or_default]
     pub has_addons: bool,
@@ -64,9 +70,9 @@ pub struct TagsProps {
 #[component(Tags)]
 pub fn tags(props: &TagsProps) -> Html {
     let class = classes!("tags", props.classes.clone(), props.has_addons.then_some("has-addons"));
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             {props.children.clone()}
         </div>
-    }
+    })
 }
@@ -1,12 +1,17 @@
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TitleProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "h3".into())]
     pub tag: String,
@@ -29,11 +34,11 @@ pub fn title(props: &TitleProps) -> Html {
         props.size.as_ref().map(|size| size.to_string()),
         props.is_spaced.then_some("is-spaced"),
     );
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -45,6 +50,9 @@ pub struct SubtitleProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "h3".into())]
     pub tag: String,
@@ -59,11 +67,11 @@ pub struct SubtitleProps {
 #[component(Subtitle)]
 pub fn subtitle(props: &SubtitleProps) -> Html {
     let class = classes!("subtitle", props.classes.clone(), props.size.as_ref().map(|size| size.to_string()));
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 /// The six sizes available for titles & subtitles.
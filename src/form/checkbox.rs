@@ -1,5 +1,7 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct CheckboxProps {
     /// The `name` attribute for this form element.
@@ -12,6 +14,9 @@ pub struct CheckboxProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
@@ -28,7 +33,7 @@ pub struct CheckboxProps {
 pub fn checkbox(props: &CheckboxProps) -> Html {
     let class = classes!("checkbox", props.classes.clone());
     let checked = props.checked;
-    html! {
+    props.attrs.apply(html! {
         <label {class} disabled={props.disabled}>
             <input
                 type="checkbox"
@@ -39,5 +44,5 @@ pub fn checkbox(props: &CheckboxProps) -> Html {
                 />
             {props.children.clone()}
         </label>
-    }
+    })
 }
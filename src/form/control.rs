@@ -1,17 +1,25 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ControlProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
     /// A modifier to have the controlled element fill up the remaining space.
     #[prop_or_default]
     pub expanded: bool,
+    /// Show a loading spinner in the control, e.g. while its value is validated.
+    #[prop_or_default]
+    pub loading: bool,
 }
 
 /// A container with which you can wrap the form controls.
@@ -19,10 +27,15 @@ pub struct ControlProps {
 /// [https://bulma.io/documentation/form/general/](https://bulma.io/documentation/form/general/)
 #[component(Control)]
 pub fn control(props: &ControlProps) -> Html {
-    let class = classes!("control", props.classes.clone(), props.expanded.then_some("is-expanded"));
-    html! {
+    let class = classes!(
+        "control",
+        props.classes.clone(),
+        props.expanded.then_some("is-expanded"),
+        props.loading.then_some("is-loading"),
+    );
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
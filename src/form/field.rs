@@ -1,12 +1,21 @@
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::{ExtraAttrs, FormContext};
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct FieldProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The name of a field registered with the surrounding [`Form`](crate::Form); its visible validation error
+    /// replaces `help` and sets `help_has_error`.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
     /// A text label for the field.
     #[prop_or_default]
     pub label: Option<String>,
@@ -90,15 +99,20 @@ pub fn field(props: &FieldProps) -> Html {
         None => html! {},
     };
 
+    // A visible form error takes the place of the help message.
+    let form = use_context::<FormContext>();
+    let error = props.name.as_ref().zip(form.as_ref()).and_then(|(name, form)| form.error(name));
+    let help_has_error = props.help_has_error || error.is_some();
+
     // Build the help label if present.
-    let help = match &props.help {
+    let help = match error.map(str::to_owned).or_else(|| props.help.clone()) {
         Some(help_content) => {
             if props.help_classes.is_empty() {
-                let class = classes!("help", props.help_has_error.then_some("is-danger"));
-                html! {<label {class}>{help_content.clone()}</label>}
+                let class = classes!("help", help_has_error.then_some("is-danger"));
+                html! {<label {class}>{help_content}</label>}
             } else {
-                let class = classes!("help", props.help_classes.clone(), props.help_has_error.then_some("is-danger"));
-                html! {<label {class}>{help_content.clone()}</label>}
+                let class = classes!("help", props.help_classes.clone(), help_has_error.then_some("is-danger"));
+                html! {<label {class}>{help_content}</label>}
             }
         }
         None => html! {},
@@ -111,13 +125,13 @@ pub fn field(props: &FieldProps) -> Html {
         html! {<>{props.children.clone()}</>}
     };
 
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             {label}
             {body}
             {help}
         </div>
-    }
+    })
 }
 
 /// The two alignment options available for field addons.
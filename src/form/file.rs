@@ -1,8 +1,82 @@
 use wasm_bindgen::UnwrapThrowExt;
-use web_sys::{File as SysFile, HtmlInputElement};
+use web_sys::{File as SysFile, HtmlInputElement, Url};
 use yew::prelude::*;
 
-use crate::{Alignment, Size};
+use crate::common::use_style;
+use crate::{Alignment, Delete, ExtraAttrs, Size};
+
+/// Why a chosen or dropped file was not added.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileRejectReason {
+    /// The file matches none of the `accept` patterns.
+    Type,
+    /// The file is larger than `max_size` bytes.
+    TooLarge { max_size: u64 },
+    /// Adding the file would exceed `max_files`.
+    TooMany { max_files: usize },
+}
+
+/// A file which was not added, and why.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileRejection {
+    pub file: SysFile,
+    pub reason: FileRejectReason,
+}
+
+impl FileRejection {
+    /// The default English error message.
+    pub fn message(&self) -> String {
+        let name = self.file.name();
+        match self.reason {
+            FileRejectReason::Type => format!("{} is not an accepted file type.", name),
+            FileRejectReason::TooLarge { max_size } => {
+                format!("{} is larger than {}.", name, format_file_size(max_size as f64))
+            }
+            FileRejectReason::TooMany { max_files } => {
+                format!("{} was not added: at most {} files are allowed.", name, max_files)
+            }
+        }
+    }
+}
+
+/// Format a size in bytes for display, e.g. `"1.5 MB"`.
+pub fn format_file_size(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024.0 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Whether `file` matches the comma-separated `accept` patterns: extensions (`.pdf`), MIME types
+/// (`application/pdf`) and MIME groups (`image/*`). Empty patterns accept everything.
+fn is_accepted(accept: &str, file: &SysFile) -> bool {
+    let name = file.name().to_lowercase();
+    let mime = file.type_().to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(group) = pattern.strip_suffix("/*") {
+            mime.split_once('/').is_some_and(|(prefix, _)| prefix == group)
+        } else {
+            mime == pattern
+        }
+    })
+}
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct FileProps {
@@ -22,6 +96,9 @@ pub struct FileProps {
 
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// An option to control if file names will be displayed; if a value is provided, then the
     /// `has-name` class will be added to this form element and the given value will be used as a
     /// placeholder until files are selected.
@@ -45,6 +122,56 @@ pub struct FileProps {
     /// The alignment of this component within its parent.
     #[prop_or_default]
     pub alignment: Option<Alignment>,
+
+    /// The `accept` attribute, e.g. `"image/*,.pdf"`; dropped files are checked against it as well.
+    #[prop_or_default]
+    pub accept: AttrValue,
+    /// The largest accepted file in bytes.
+    #[prop_or_default]
+    pub max_size: Option<u64>,
+    /// The largest number of files; further files are rejected.
+    #[prop_or_default]
+    pub max_files: Option<usize>,
+    /// Receives the files which were rejected by `accept`, `max_size` or `max_files`. The component also lists
+    /// their error messages below itself.
+    #[prop_or_default]
+    pub on_reject: Callback<Vec<FileRejection>>,
+    /// Render a drop zone which files can be dragged onto. It lists the files with a remove button each and, for
+    /// images, a thumbnail. With `multiple`, new files are added to the current ones.
+    #[prop_or_default]
+    pub drop_zone: bool,
+    /// The text of the drop zone.
+    #[prop_or_else(|| "Drop files here or click to browse".into())]
+    pub drop_label: AttrValue,
+    /// Show thumbnails of images in the drop zone.
+    #[prop_or(true)]
+    pub previews: bool,
+    /// The `aria-label` of the remove buttons, followed by the file name.
+    #[prop_or_else(|| "Remove".into())]
+    pub remove_label: AttrValue,
+}
+
+/// Split `incoming` into the files to keep and the rejected ones; with `append`, the current files stay first.
+fn select_files(props: &FileProps, incoming: Vec<SysFile>, append: bool) -> (Vec<SysFile>, Vec<FileRejection>) {
+    let mut files = if append { props.files.clone() } else { Vec::new() };
+    let mut rejections = Vec::new();
+    for file in incoming {
+        let too_large = props.max_size.filter(|max_size| file.size() > *max_size as f64);
+        let too_many = props.max_files.filter(|max_files| files.len() >= *max_files);
+        let reason = if !is_accepted(&props.accept, &file) {
+            Some(FileRejectReason::Type)
+        } else if let Some(max_size) = too_large {
+            Some(FileRejectReason::TooLarge { max_size })
+        } else {
+            too_many.map(|max_files| FileRejectReason::TooMany { max_files })
+        };
+        match reason {
+            Some(reason) => rejections.push(FileRejection { file, reason }),
+            None if props.multiple => files.push(file),
+            None => files = vec![file],
+        }
+    }
+    (files, rejections)
 }
 
 /// A custom file upload input.
@@ -56,6 +183,7 @@ pub struct FileProps {
 /// component via callback.
 #[component(File)]
 pub fn file(props: &FileProps) -> Html {
+    use_style("ybc-file-style", FILE_STYLE);
     let class = classes!(
         "file",
         props.classes.clone(),
@@ -71,31 +199,389 @@ pub fn file(props: &FileProps) -> Html {
         .iter()
         .map(|file| html! {<span class="file-name">{file.name()}</span>})
         .collect::<Vec<_>>();
-    let onchange = props.update.reform(|ev: web_sys::Event| {
-        let input: HtmlInputElement = ev.target_dyn_into().expect_throw("event target should be an input");
-        let list = input.files().expect_throw("input should have a file list");
-        (0..list.length()).filter_map(|idx| list.item(idx)).collect::<Vec<_>>()
-    });
+
+    let rejections = use_state(Vec::<FileRejection>::new);
+    let choose = {
+        let props = props.clone();
+        let rejections = rejections.clone();
+        move |incoming: Vec<SysFile>, append: bool| {
+            let (files, rejected) = select_files(&props, incoming, append);
+            if !rejected.is_empty() {
+                props.on_reject.emit(rejected.clone());
+            }
+            rejections.set(rejected);
+            if files != props.files {
+                props.update.emit(files);
+            }
+        }
+    };
+
+    let onchange = {
+        let choose = choose.clone();
+        let append = props.drop_zone && props.multiple;
+        Callback::from(move |ev: web_sys::Event| {
+            let input: HtmlInputElement = ev.target_dyn_into().expect_throw("event target should be an input");
+            let list = input.files().expect_throw("input should have a file list");
+            choose((0..list.length()).filter_map(|idx| list.item(idx)).collect(), append);
+            if append {
+                // Allow choosing the same file again after removing it.
+                input.set_value("");
+            }
+        })
+    };
+
+    let errors = if rejections.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <ul class="help is-danger ybc-file-errors" role="alert">
+                { for rejections.iter().map(|rejection| html! { <li>{rejection.message()}</li> }) }
+            </ul>
+        }
+    };
+
+    let input = html! {
+        <input
+            type="file"
+            class="file-input"
+            name={props.name.clone()}
+            multiple={props.multiple}
+            accept={(!props.accept.is_empty()).then(|| props.accept.clone())}
+            {onchange}
+            />
+    };
+
+    if !props.drop_zone {
+        return props.attrs.apply(html! {
+            <div {class}>
+                <label class="file-label">
+                    {input}
+                    <span class="file-cta">
+                        <span class="file-icon">
+                            {props.selector_icon.clone()}
+                        </span>
+                        <span class="file-label">
+                            {props.selector_label.clone()}
+                        </span>
+                    </span>
+                    {filenames}
+                </label>
+                {errors}
+            </div>
+        });
+    }
+
+    let multiple = props.multiple;
+    props.attrs.apply(html! {
+        <div class="ybc-file-field">
+            <FileDropZone {class} label={props.drop_label.clone()} icon={props.selector_icon.clone()} ondrop={Callback::from(move |files| choose(files, multiple))}>
+                {input}
+            </FileDropZone>
+            {errors}
+            <FileList files={props.files.clone()} update={props.update.clone()} previews={props.previews} remove_label={props.remove_label.clone()} />
+        </div>
+    })
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct FileDropZoneProps {
+    children: Children,
+    class: Classes,
+    label: AttrValue,
+    icon: Html,
+    ondrop: Callback<Vec<SysFile>>,
+}
+
+/// The drag and drop target wrapping the file input.
+#[component(FileDropZone)]
+fn file_drop_zone(props: &FileDropZoneProps) -> Html {
+    let dragover = use_state(|| false);
+    // dragenter/dragleave also fire when moving between children, so count them.
+    let depth = use_mut_ref(|| 0u32);
+
+    let ondragenter = {
+        let dragover = dragover.clone();
+        let depth = depth.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            *depth.borrow_mut() += 1;
+            dragover.set(true);
+        })
+    };
+    let ondragover = Callback::from(|event: DragEvent| event.prevent_default());
+    let ondragleave = {
+        let dragover = dragover.clone();
+        let depth = depth.clone();
+        Callback::from(move |_: DragEvent| {
+            let mut depth = depth.borrow_mut();
+            *depth = depth.saturating_sub(1);
+            if *depth == 0 {
+                dragover.set(false);
+            }
+        })
+    };
+    let ondrop = {
+        let dragover = dragover.clone();
+        let ondrop = props.ondrop.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            *depth.borrow_mut() = 0;
+            dragover.set(false);
+            if let Some(list) = event.data_transfer().and_then(|transfer| transfer.files()) {
+                ondrop.emit((0..list.length()).filter_map(|idx| list.item(idx)).collect());
+            }
+        })
+    };
+
+    let class = classes!(props.class.clone(), "is-boxed", "ybc-file-drop-zone", dragover.then_some("is-dragover"));
     html! {
-        <div {class}>
+        <div {class} {ondragenter} {ondragover} {ondragleave} {ondrop}>
             <label class="file-label">
-                <input
-                    type="file"
-                    class="file-input"
-                    name={props.name.clone()}
-                    multiple={props.multiple}
-                    {onchange}
-                    />
+                {props.children.clone()}
                 <span class="file-cta">
-                    <span class="file-icon">
-                        {props.selector_icon.clone()}
-                    </span>
-                    <span class="file-label">
-                        {props.selector_label.clone()}
-                    </span>
+                    <span class="file-icon">{props.icon.clone()}</span>
+                    <span class="file-label">{props.label.clone()}</span>
                 </span>
-                {filenames}
             </label>
         </div>
     }
 }
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct FileListProps {
+    files: Vec<SysFile>,
+    update: Callback<Vec<SysFile>>,
+    previews: bool,
+    remove_label: AttrValue,
+}
+
+/// The files of a drop zone with thumbnails and remove buttons.
+#[component(FileList)]
+fn file_list(props: &FileListProps) -> Html {
+    // Object URLs of image thumbnails, revoked when the files change or the list unmounts.
+    let thumbnails = use_state(Vec::<(SysFile, String)>::new);
+    {
+        let thumbnails = thumbnails.clone();
+        use_effect_with((props.files.clone(), props.previews), move |(files, previews)| {
+            let created: Vec<(SysFile, String)> = files
+                .iter()
+                .filter(|file| *previews && file.type_().starts_with("image/"))
+                .filter_map(|file| Url::create_object_url_with_blob(file).ok().map(|url| (file.clone(), url)))
+                .collect();
+            let urls: Vec<String> = created.iter().map(|(_, url)| url.clone()).collect();
+            thumbnails.set(created);
+            move || {
+                for url in urls {
+                    let _ = Url::revoke_object_url(&url);
+                }
+            }
+        });
+    }
+
+    if props.files.is_empty() {
+        return html! {};
+    }
+    html! {
+        <ul class="ybc-file-list">
+            { for props.files.iter().enumerate().map(|(index, file)| {
+                let thumbnail = thumbnails.iter().find(|(candidate, _)| candidate == file).map(|(_, url)| url.clone());
+                let onclick = {
+                    let files = props.files.clone();
+                    let update = props.update.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        let mut files = files.clone();
+                        files.remove(index);
+                        update.emit(files);
+                    })
+                };
+                html! {
+                    <li class="ybc-file-item">
+                        if let Some(src) = thumbnail {
+                            <img class="ybc-file-thumbnail" {src} alt="" />
+                        }
+                        <span class="ybc-file-item-name">{file.name()}</span>
+                        <span class="ybc-file-item-size">{format_file_size(file.size())}</span>
+                        <Delete {onclick} aria_label={format!("{} {}", props.remove_label, file.name())} />
+                    </li>
+                }
+            }) }
+        </ul>
+    }
+}
+
+const FILE_STYLE: &str = r#"
+.file:has(> .ybc-file-errors) {
+    flex-wrap: wrap;
+}
+.file > .ybc-file-errors {
+    flex-basis: 100%;
+}
+.ybc-file-drop-zone .file-label {
+    width: 100%;
+}
+.ybc-file-drop-zone .file-cta {
+    width: 100%;
+    padding: 2rem 1rem;
+    border-style: dashed;
+    border-width: 2px;
+    align-items: center;
+    text-align: center;
+}
+.ybc-file-drop-zone.is-dragover .file-cta {
+    border-color: var(--bulma-primary, #00d1b2);
+    background-color: var(--bulma-primary-light, #ebfffc);
+}
+.ybc-file-list {
+    list-style: none;
+    margin: 0.75rem 0 0;
+}
+.ybc-file-item {
+    display: flex;
+    align-items: center;
+    gap: 0.75rem;
+    padding: 0.25rem 0;
+}
+.ybc-file-thumbnail {
+    width: 3rem;
+    height: 3rem;
+    object-fit: cover;
+    border-radius: 4px;
+}
+.ybc-file-item-name {
+    flex: 1;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+.ybc-file-item-size {
+    font-size: 0.75rem;
+    opacity: 0.7;
+}
+"#;
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_sizes_use_binary_units() {
+        assert_eq!(format_file_size(0.0), "0 B");
+        assert_eq!(format_file_size(1023.0), "1023 B");
+        assert_eq!(format_file_size(1024.0), "1.0 KB");
+        assert_eq!(format_file_size(1536.0), "1.5 KB");
+        assert_eq!(format_file_size(5.0 * 1024.0 * 1024.0), "5.0 MB");
+        assert_eq!(format_file_size(1024.0_f64.powi(3) * 2.5), "2.5 GB");
+        assert_eq!(format_file_size(1024.0_f64.powi(5)), "1024.0 TB");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::FilePropertyBag;
+    use web_sys::js_sys::Array;
+    use yew::props;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn file(name: &str, mime: &str, size: usize) -> SysFile {
+        let options = FilePropertyBag::new();
+        options.set_type(mime);
+        let content = Array::of1(&JsValue::from_str(&"x".repeat(size)));
+        SysFile::new_with_str_sequence_and_options(&content, name, &options).unwrap()
+    }
+
+    fn file_props() -> FileProps {
+        props!(FileProps {
+            name: "attachments",
+            files: vec![],
+            update: Callback::noop()
+        })
+    }
+
+    fn names(files: &[SysFile]) -> Vec<String> {
+        files.iter().map(SysFile::name).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn accept_matches_extensions_mime_types_and_groups() {
+        let report = file("Report.PDF", "application/pdf", 1);
+        let photo = file("photo.jpeg", "image/jpeg", 1);
+        let notes = file("notes.txt", "", 1);
+
+        assert!(is_accepted("", &notes));
+        assert!(is_accepted(" , ", &notes));
+        assert!(is_accepted(".pdf", &report));
+        assert!(is_accepted("application/pdf", &report));
+        assert!(is_accepted("image/*", &photo));
+        assert!(is_accepted(".doc, image/*", &photo));
+        assert!(is_accepted("IMAGE/JPEG", &photo));
+        assert!(!is_accepted("image/*", &report));
+        assert!(!is_accepted("application/*,.pdf", &notes));
+        assert!(!is_accepted("image/png", &photo));
+    }
+
+    #[wasm_bindgen_test]
+    fn files_are_checked_for_type_then_size_then_count() {
+        let mut props = file_props();
+        props.multiple = true;
+        props.accept = "image/*".into();
+        props.max_size = Some(10);
+        props.max_files = Some(2);
+        let incoming = vec![
+            file("a.png", "image/png", 5),
+            file("b.pdf", "application/pdf", 50),
+            file("c.png", "image/png", 50),
+            file("d.png", "image/png", 5),
+            file("e.png", "image/png", 5),
+        ];
+        let (files, rejections) = select_files(&props, incoming, false);
+        assert_eq!(names(&files), ["a.png", "d.png"]);
+        let reasons: Vec<_> = rejections
+            .iter()
+            .map(|rejection| (rejection.file.name(), rejection.reason.clone()))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                ("b.pdf".to_string(), FileRejectReason::Type),
+                ("c.png".to_string(), FileRejectReason::TooLarge { max_size: 10 }),
+                ("e.png".to_string(), FileRejectReason::TooMany { max_files: 2 }),
+            ]
+        );
+        assert_eq!(rejections[0].message(), "b.pdf is not an accepted file type.");
+        assert_eq!(rejections[1].message(), "c.png is larger than 10 B.");
+        assert_eq!(rejections[2].message(), "e.png was not added: at most 2 files are allowed.");
+    }
+
+    #[wasm_bindgen_test]
+    fn appending_keeps_the_current_files_first() {
+        let mut props = file_props();
+        props.multiple = true;
+        props.max_files = Some(2);
+        props.files = vec![file("a.txt", "text/plain", 1)];
+
+        let (files, rejections) = select_files(&props, vec![file("b.txt", "text/plain", 1), file("c.txt", "text/plain", 1)], true);
+        assert_eq!(names(&files), ["a.txt", "b.txt"]);
+        assert_eq!(
+            names(&rejections.into_iter().map(|rejection| rejection.file).collect::<Vec<_>>()),
+            ["c.txt"]
+        );
+
+        let (files, rejections) = select_files(&props, vec![file("b.txt", "text/plain", 1), file("c.txt", "text/plain", 1)], false);
+        assert_eq!(names(&files), ["b.txt", "c.txt"]);
+        assert!(rejections.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn single_file_inputs_keep_the_last_accepted_file() {
+        let mut props = file_props();
+        props.files = vec![file("a.txt", "text/plain", 1)];
+        let (files, rejections) = select_files(&props, vec![file("b.txt", "text/plain", 1), file("c.txt", "text/plain", 1)], true);
+        assert_eq!(names(&files), ["c.txt"]);
+        assert!(rejections.is_empty());
+    }
+}
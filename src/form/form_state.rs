@@ -0,0 +1,1416 @@
+/*!
+Typed form state: [`use_form`], [`Form`] and field registration.
+
+Summary
+- [`use_form`] keeps a model `T` (usually a plain struct) together with the fields which have been touched and the
+  number of submit attempts. The returned [`FormHandle`] replaces one `use_state` per control.
+- Fields are registered by name with a [`FormFieldDef`]: a getter and a setter into the model plus any number of
+  [`Validator`]s. [`FormHandle::register`] returns a [`FormFieldHandle`] carrying the `value` and `update` props for
+  the control, its dirty/touched state and its validation error.
+- [`Form`] renders the `<form>` element. It marks a field as touched when a control with the field's `name` loses
+  focus, validates every registered field on submit and emits the model only when all of them are valid. Submit it
+  with a [`ButtonInputSubmit`] inside the form.
+- A field may also have an [`AsyncValidator`], e.g. a server request. It runs debounced after the value changed and
+  passed the synchronous validators; answers for outdated values are dropped. A submit runs it right away for values
+  it has not checked yet, like a pre-filled initial value. While it runs the field reports
+  [`is_pending`](FormFieldHandle::is_pending), meant for the `loading` prop of its [`Control`], and the form refuses
+  to submit.
+- Errors are shown once the field was touched or a submit was attempted. A [`Field`] inside a `Form` whose `name`
+  matches a registered field renders that error in its `help` slot with `help_has_error` set.
+
+Example
+```rust,ignore
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Signup {
+    email: String,
+    terms: bool,
+}
+
+let form = use_form::<Signup>();
+let email = form.register(
+    FormFieldDef::new("email", |signup: &Signup| signup.email.clone(), |signup, email| signup.email = email)
+        .validate(Validator::required("Please enter your email address."))
+        .validate(Validator::email("This is not an email address.")),
+);
+let terms = form.register(
+    FormFieldDef::new("terms", |signup: &Signup| signup.terms, |signup, terms| signup.terms = terms)
+        .validate(Validator::required("Please accept the terms.")),
+);
+let onsubmit = Callback::from(|signup: Signup| log::info!("{signup:?}"));
+html! {
+    <Form<Signup> form={form.clone()} {onsubmit}>
+        <Field label="Email" name="email">
+            <Control><Input name={email.name()} value={email.value()} update={email.update()} /></Control>
+        </Field>
+        <Field name="terms">
+            <Control><Checkbox name={terms.name()} checked={terms.value()} update={terms.update()}>{" I agree"}</Checkbox></Control>
+        </Field>
+        <ButtonInputSubmit value="Sign up" classes={classes!("is-primary")} />
+    </Form<Signup>>
+}
+```
+
+[`ButtonInputSubmit`]: crate::ButtonInputSubmit
+[`Control`]: crate::Control
+[`Field`]: crate::Field
+*/
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::ExtraAttrs;
+
+/// Values which can be checked by [`Validator::required`].
+pub trait FormValue {
+    /// Whether the value counts as not filled in.
+    fn is_blank(&self) -> bool;
+}
+
+impl FormValue for String {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl FormValue for AttrValue {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+/// An unchecked checkbox is blank.
+impl FormValue for bool {
+    fn is_blank(&self) -> bool {
+        !*self
+    }
+}
+
+impl<V> FormValue for Vec<V> {
+    fn is_blank(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<V: FormValue> FormValue for Option<V> {
+    fn is_blank(&self) -> bool {
+        self.as_ref().is_none_or(FormValue::is_blank)
+    }
+}
+
+type ValidateFn<V> = Rc<dyn Fn(&V) -> Result<(), String>>;
+
+/// Values offered as the options of a [`Select`](crate::Select), usually a fieldless enum.
+///
+/// `#[derive(YbcForm)]` implements this for enums and renders struct fields of such types as selects.
+pub trait FormOptions: Sized + Clone + PartialEq + 'static {
+    /// All options in display order.
+    fn options() -> Vec<Self>;
+
+    /// The `value` attribute of the option, unique among the options.
+    fn option_value(&self) -> AttrValue;
+
+    /// The text shown for the option.
+    fn option_label(&self) -> AttrValue;
+
+    /// The option whose [`option_value`](Self::option_value) is `value`.
+    fn from_option_value(value: &str) -> Option<Self> {
+        Self::options().into_iter().find(|option| option.option_value() == value)
+    }
+}
+
+/// A synchronous check of a field value, returning the error message on failure.
+pub struct Validator<V>(ValidateFn<V>);
+
+impl<V: 'static> Validator<V> {
+    /// A validator from a function returning the error message for invalid values.
+    pub fn new(validate: impl Fn(&V) -> Result<(), String> + 'static) -> Self {
+        Self(Rc::new(validate))
+    }
+
+    /// Check `value`.
+    pub fn validate(&self, value: &V) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+impl<V: FormValue + 'static> Validator<V> {
+    /// Fails for blank values: empty or whitespace-only text, unchecked checkboxes and empty lists.
+    pub fn required(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |value: &V| if value.is_blank() { Err(message.clone()) } else { Ok(()) })
+    }
+}
+
+impl Validator<String> {
+    /// Fails for non-empty text shorter than `min` characters; combine with [`required`](Self::required) if needed.
+    pub fn min_length(min: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |value: &String| {
+            let length = value.chars().count();
+            if length > 0 && length < min { Err(message.clone()) } else { Ok(()) }
+        })
+    }
+
+    /// Fails for text longer than `max` characters.
+    pub fn max_length(max: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |value: &String| if value.chars().count() > max { Err(message.clone()) } else { Ok(()) })
+    }
+
+    /// Fails for non-empty text which does not look like `local@domain.tld`.
+    pub fn email(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |value: &String| {
+            let value = value.trim();
+            if value.is_empty() {
+                return Ok(());
+            }
+            let valid = value.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty()
+                    && !value.contains(char::is_whitespace)
+                    && domain.split('.').count() > 1
+                    && domain.split('.').all(|part| !part.is_empty())
+            });
+            if valid { Ok(()) } else { Err(message.clone()) }
+        })
+    }
+}
+
+impl<V> Clone for Validator<V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<V> std::fmt::Debug for Validator<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Validator(..)")
+    }
+}
+
+impl<V> PartialEq for Validator<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The future returned by an [`AsyncValidator`].
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+type AsyncValidateFn<V> = Rc<dyn Fn(V) -> ValidationFuture>;
+
+/// An asynchronous check of a field value, e.g. asking a server whether a username is taken.
+///
+/// The check starts once the value has not changed for the debounce delay; answers for values which have changed
+/// since are discarded.
+pub struct AsyncValidator<V> {
+    validate: AsyncValidateFn<V>,
+    debounce_ms: u32,
+}
+
+impl<V: 'static> AsyncValidator<V> {
+    /// The debounce delay used unless [`debounce`](Self::debounce) is called.
+    pub const DEFAULT_DEBOUNCE_MS: u32 = 300;
+
+    /// A validator from a function returning a future which resolves to the error message for invalid values.
+    pub fn new<F, Fut>(validate: F) -> Self
+    where
+        F: Fn(V) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self {
+            validate: Rc::new(move |value| Box::pin(validate(value))),
+            debounce_ms: Self::DEFAULT_DEBOUNCE_MS,
+        }
+    }
+
+    /// A validator answering immediately, e.g. an in-process stand-in for a server check in tests and demos.
+    pub fn ready(validate: impl Fn(&V) -> Result<(), String> + 'static) -> Self {
+        let validate = Rc::new(validate);
+        Self::new(move |value: V| std::future::ready(validate(&value)))
+    }
+
+    /// Wait `debounce_ms` milliseconds after the last change before starting the check.
+    pub fn debounce(mut self, debounce_ms: u32) -> Self {
+        self.debounce_ms = debounce_ms;
+        self
+    }
+
+    /// Start checking `value`.
+    pub fn validate(&self, value: V) -> ValidationFuture {
+        (self.validate)(value)
+    }
+}
+
+impl<V> Clone for AsyncValidator<V> {
+    fn clone(&self) -> Self {
+        Self {
+            validate: self.validate.clone(),
+            debounce_ms: self.debounce_ms,
+        }
+    }
+}
+
+impl<V> std::fmt::Debug for AsyncValidator<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncValidator").field("debounce_ms", &self.debounce_ms).finish()
+    }
+}
+
+impl<V> PartialEq for AsyncValidator<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.validate, &other.validate) && self.debounce_ms == other.debounce_ms
+    }
+}
+
+type FieldGetter<T, V> = Rc<dyn Fn(&T) -> V>;
+type FieldSetter<T, V> = Rc<dyn Fn(&mut T, V)>;
+type FieldCheck<T, V> = Rc<dyn Fn(&V, &T) -> Result<(), String>>;
+type RegisteredCheck<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+
+/// A field registered during the current render.
+struct RegisteredField<T> {
+    check: RegisteredCheck<T>,
+    /// Starts the async validator for a value it has not checked yet; returns whether a check was started.
+    check_unverified: Option<Rc<dyn Fn() -> bool>>,
+}
+
+/// The definition of a form field: its name, how to read and write it in the model `T`, and its validators.
+pub struct FormFieldDef<T, V> {
+    name: AttrValue,
+    get: FieldGetter<T, V>,
+    set: FieldSetter<T, V>,
+    checks: Vec<FieldCheck<T, V>>,
+    async_check: Option<AsyncValidator<V>>,
+}
+
+impl<T: 'static, V: 'static> FormFieldDef<T, V> {
+    /// A field `name` read from the model with `get` and written with `set`.
+    ///
+    /// The name should match the `name` prop of the control, so that [`Form`] can mark the field as touched on blur.
+    pub fn new(name: impl Into<AttrValue>, get: impl Fn(&T) -> V + 'static, set: impl Fn(&mut T, V) + 'static) -> Self {
+        Self {
+            name: name.into(),
+            get: Rc::new(get),
+            set: Rc::new(set),
+            checks: Vec::new(),
+            async_check: None,
+        }
+    }
+
+    /// Add a validator; validators run in order and the first error wins.
+    pub fn validate(mut self, validator: Validator<V>) -> Self {
+        self.checks.push(Rc::new(move |value, _| validator.validate(value)));
+        self
+    }
+
+    /// Add a validator which also sees the whole model, e.g. to compare a confirmation field.
+    pub fn validate_with(mut self, validate: impl Fn(&V, &T) -> Result<(), String> + 'static) -> Self {
+        self.checks.push(Rc::new(validate));
+        self
+    }
+
+    /// Set the asynchronous validator of the field, replacing any previous one.
+    ///
+    /// It runs after the value was changed through the field's `update` callback and all synchronous validators
+    /// passed. Values which were never checked, like the initial one, are checked without debounce on submit.
+    pub fn validate_async(mut self, validator: AsyncValidator<V>) -> Self {
+        self.async_check = Some(validator);
+        self
+    }
+
+    /// The name of the field.
+    pub fn name(&self) -> &AttrValue {
+        &self.name
+    }
+
+    fn check(&self, model: &T) -> Result<(), String> {
+        let value = (self.get)(model);
+        self.checks.iter().try_for_each(|check| check(&value, model))
+    }
+}
+
+impl<T, V> Clone for FormFieldDef<T, V> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            get: self.get.clone(),
+            set: self.set.clone(),
+            checks: self.checks.clone(),
+            async_check: self.async_check.clone(),
+        }
+    }
+}
+
+impl<T, V> std::fmt::Debug for FormFieldDef<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormFieldDef")
+            .field("name", &self.name)
+            .field("validators", &self.checks.len())
+            .field("async_check", &self.async_check)
+            .finish()
+    }
+}
+
+enum FormAction<T> {
+    Update(Box<dyn FnOnce(&mut T)>),
+    Touch(AttrValue),
+    Submit,
+    /// Hold a submit of [`Form`] until the async checks answered, or release it.
+    HoldSubmit(bool),
+    Reset(Option<T>),
+    Pending(AttrValue, u64),
+    Idle(AttrValue),
+    Resolve(AttrValue, u64, AsyncResult),
+}
+
+/// The answer of an async validator together with the value it checked.
+#[derive(Clone)]
+struct AsyncResult {
+    value: Rc<dyn Any>,
+    error: Option<String>,
+}
+
+impl std::fmt::Debug for AsyncResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncResult").field("error", &self.error).finish()
+    }
+}
+
+impl PartialEq for AsyncResult {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.value, &other.value) && self.error == other.error
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FormState<T> {
+    values: T,
+    initial: T,
+    touched: BTreeSet<AttrValue>,
+    submit_count: usize,
+    /// The generation of the async check in flight, by field name.
+    pending: BTreeMap<AttrValue, u64>,
+    async_results: BTreeMap<AttrValue, AsyncResult>,
+    /// Whether a submit of [`Form`] waits for async checks to answer.
+    submit_held: bool,
+}
+
+impl<T: Clone + PartialEq> Reducible for FormState<T> {
+    type Action = FormAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            FormAction::Update(update) => {
+                let mut values = self.values.clone();
+                update(&mut values);
+                if values == self.values {
+                    return self;
+                }
+                // A held submit was meant for the values it saw, not for later edits.
+                Rc::new(Self {
+                    values,
+                    submit_held: false,
+                    ..(*self).clone()
+                })
+            }
+            FormAction::Touch(name) => {
+                if self.touched.contains(&name) {
+                    return self;
+                }
+                let mut touched = self.touched.clone();
+                touched.insert(name);
+                Rc::new(Self { touched, ..(*self).clone() })
+            }
+            FormAction::Submit => Rc::new(Self {
+                submit_count: self.submit_count + 1,
+                ..(*self).clone()
+            }),
+            FormAction::HoldSubmit(submit_held) => {
+                if self.submit_held == submit_held {
+                    return self;
+                }
+                Rc::new(Self {
+                    submit_held,
+                    ..(*self).clone()
+                })
+            }
+            FormAction::Reset(values) => {
+                let initial = values.unwrap_or_else(|| self.initial.clone());
+                Rc::new(Self {
+                    values: initial.clone(),
+                    initial,
+                    touched: BTreeSet::new(),
+                    submit_count: 0,
+                    pending: BTreeMap::new(),
+                    async_results: BTreeMap::new(),
+                    submit_held: false,
+                })
+            }
+            FormAction::Pending(name, generation) => {
+                let mut pending = self.pending.clone();
+                pending.insert(name, generation);
+                Rc::new(Self { pending, ..(*self).clone() })
+            }
+            FormAction::Idle(name) => {
+                if !self.pending.contains_key(&name) {
+                    return self;
+                }
+                let mut pending = self.pending.clone();
+                pending.remove(&name);
+                Rc::new(Self { pending, ..(*self).clone() })
+            }
+            FormAction::Resolve(name, generation, result) => {
+                if self.pending.get(&name) != Some(&generation) {
+                    return self;
+                }
+                let mut pending = self.pending.clone();
+                pending.remove(&name);
+                let mut async_results = self.async_results.clone();
+                async_results.insert(name, result);
+                Rc::new(Self {
+                    pending,
+                    async_results,
+                    ..(*self).clone()
+                })
+            }
+        }
+    }
+}
+
+/// The debounce timer and request counter of a field with an async validator.
+#[derive(Default)]
+struct AsyncSlot {
+    generation: Cell<u64>,
+    timer: RefCell<Option<Timeout>>,
+}
+
+impl AsyncSlot {
+    /// Cancel the waiting check, if any, and return the generation of the next one.
+    fn restart(&self) -> u64 {
+        self.timer.borrow_mut().take();
+        self.generation.set(self.generation.get() + 1);
+        self.generation.get()
+    }
+}
+
+/// Debounce and run `validator` for the new `value` of the field `name`, or just cancel a waiting check when the
+/// value already fails a synchronous validator.
+fn start_async_check<T, V>(
+    state: &UseReducerHandle<FormState<T>>, name: &AttrValue, slot: &AsyncSlot, validator: AsyncValidator<V>, value: V, run: bool,
+) where
+    T: Clone + PartialEq + 'static,
+    V: Clone + 'static,
+{
+    let generation = slot.restart();
+    if !run {
+        state.dispatch(FormAction::Idle(name.clone()));
+        return;
+    }
+    state.dispatch(FormAction::Pending(name.clone(), generation));
+    let state = state.clone();
+    let name = name.clone();
+    let timer = Timeout::new(validator.debounce_ms, move || {
+        wasm_bindgen_futures::spawn_local(async move {
+            let error = validator.validate(value.clone()).await.err();
+            let result = AsyncResult {
+                value: Rc::new(value),
+                error,
+            };
+            state.dispatch(FormAction::Resolve(name, generation, result));
+        });
+    });
+    *slot.timer.borrow_mut() = Some(timer);
+}
+
+/// The state returned by [`use_form`].
+pub struct FormHandle<T: Clone + PartialEq + 'static> {
+    state: UseReducerHandle<FormState<T>>,
+    registry: Rc<RefCell<BTreeMap<AttrValue, RegisteredField<T>>>>,
+    slots: Rc<RefCell<BTreeMap<AttrValue, Rc<AsyncSlot>>>>,
+}
+
+impl<T: Clone + PartialEq + 'static> Clone for FormHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            registry: self.registry.clone(),
+            slots: self.slots.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> PartialEq for FormHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.registry, &other.registry)
+    }
+}
+
+impl<T: Clone + PartialEq + std::fmt::Debug + 'static> std::fmt::Debug for FormHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormHandle")
+            .field("values", &self.state.values)
+            .field("touched", &self.state.touched)
+            .field("submit_count", &self.state.submit_count)
+            .field("pending", &self.state.pending)
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> FormHandle<T> {
+    /// Register the field `def` and return its current state.
+    ///
+    /// Call this on every render for every field of the form; fields which are not registered during a render are
+    /// not validated on submit.
+    pub fn register<V: Clone + PartialEq + 'static>(&self, def: FormFieldDef<T, V>) -> FormFieldHandle<V> {
+        let value = (def.get)(&self.state.values);
+        let initial = (def.get)(&self.state.initial);
+        let async_result = self
+            .state
+            .async_results
+            .get(&def.name)
+            .filter(|result| result.value.downcast_ref::<V>() == Some(&value));
+        let async_error = async_result.and_then(|result| result.error.clone());
+        let error = def.check(&self.state.values).err().or_else(|| async_error.clone());
+        let pending = self.state.pending.contains_key(&def.name);
+        let check_unverified = match def.async_check.clone() {
+            Some(validator) if async_result.is_none() && !pending && error.is_none() => {
+                let state = self.state.clone();
+                let name = def.name.clone();
+                let slot = self.slots.borrow_mut().entry(name.clone()).or_default().clone();
+                let value = value.clone();
+                Some(Rc::new(move || {
+                    start_async_check(&state, &name, &slot, validator.clone().debounce(0), value.clone(), true);
+                    true
+                }) as Rc<dyn Fn() -> bool>)
+            }
+            _ => None,
+        };
+        let touched = self.state.touched.contains(&def.name);
+        let update = {
+            let state = self.state.clone();
+            let def = def.clone();
+            let slot = def
+                .async_check
+                .is_some()
+                .then(|| self.slots.borrow_mut().entry(def.name.clone()).or_default().clone());
+            Callback::from(move |value: V| {
+                if let (Some(validator), Some(slot)) = (def.async_check.clone(), slot.as_ref()) {
+                    let mut model = state.values.clone();
+                    (def.set)(&mut model, value.clone());
+                    let run = def.check(&model).is_ok();
+                    start_async_check(&state, &def.name, slot, validator, value.clone(), run);
+                }
+                let set = def.set.clone();
+                state.dispatch(FormAction::Update(Box::new(move |model| set(model, value))));
+            })
+        };
+        let handle = FormFieldHandle {
+            name: def.name.clone(),
+            dirty: value != initial,
+            value,
+            update,
+            error,
+            touched,
+            submitted: self.state.submit_count > 0,
+            pending,
+        };
+        let name = def.name.clone();
+        let check = move |model: &T| {
+            def.check(model)?;
+            async_error.clone().map_or(Ok(()), Err)
+        };
+        self.registry.borrow_mut().insert(
+            name,
+            RegisteredField {
+                check: Rc::new(check),
+                check_unverified,
+            },
+        );
+        handle
+    }
+
+    /// The current model.
+    pub fn values(&self) -> &T {
+        &self.state.values
+    }
+
+    /// The model the form started with or was last reset to.
+    pub fn initial(&self) -> &T {
+        &self.state.initial
+    }
+
+    /// Whether the model differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.state.values != self.state.initial
+    }
+
+    /// Whether the field `name` has lost focus at least once.
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.state.touched.contains(name)
+    }
+
+    /// The number of submit attempts since the last reset.
+    pub fn submit_count(&self) -> usize {
+        self.state.submit_count
+    }
+
+    /// The errors of all registered fields, whether visible yet or not.
+    pub fn errors(&self) -> BTreeMap<AttrValue, String> {
+        let values = &self.state.values;
+        self.registry
+            .borrow()
+            .iter()
+            .filter_map(|(name, field)| (field.check)(values).err().map(|error| (name.clone(), error)))
+            .collect()
+    }
+
+    /// The errors which should be shown: those of touched fields, or of all fields after a submit attempt.
+    pub fn visible_errors(&self) -> BTreeMap<AttrValue, String> {
+        let mut errors = self.errors();
+        if self.state.submit_count == 0 {
+            errors.retain(|name, _| self.state.touched.contains(name));
+        }
+        errors
+    }
+
+    /// Whether an async validator of any field has not answered yet.
+    pub fn is_pending(&self) -> bool {
+        !self.state.pending.is_empty()
+    }
+
+    /// Whether all registered fields are valid.
+    pub fn is_valid(&self) -> bool {
+        let values = &self.state.values;
+        self.registry.borrow().values().all(|field| (field.check)(values).is_ok())
+    }
+
+    /// Change the model directly, e.g. to fill in several fields at once.
+    pub fn update(&self, update: impl FnOnce(&mut T) + 'static) {
+        self.state.dispatch(FormAction::Update(Box::new(update)));
+    }
+
+    /// Mark the field `name` as touched, showing its error.
+    pub fn touch(&self, name: impl Into<AttrValue>) {
+        self.state.dispatch(FormAction::Touch(name.into()));
+    }
+
+    /// Count a submit attempt, showing all errors, and return the model if all fields are valid and no async
+    /// validation is pending.
+    ///
+    /// Async validators which have not checked the current value of their field yet are started, and the model is
+    /// held back until they answered.
+    ///
+    /// [`Form`] calls this on submit; call it directly for forms submitted by other means.
+    pub fn submit(&self) -> Option<T> {
+        self.state.dispatch(FormAction::Submit);
+        let checks: Vec<_> = self
+            .registry
+            .borrow()
+            .values()
+            .filter_map(|field| field.check_unverified.clone())
+            .collect();
+        let started = checks.iter().filter(|check_unverified| check_unverified()).count();
+        (started == 0 && !self.is_pending() && self.is_valid()).then(|| self.state.values.clone())
+    }
+
+    /// Whether a submit would start async checks of values which were never checked.
+    fn has_unverified(&self) -> bool {
+        self.registry.borrow().values().any(|field| field.check_unverified.is_some())
+    }
+
+    /// Restore the initial model and forget touched fields, submit attempts and async validation results.
+    pub fn reset(&self) {
+        self.cancel_async_checks();
+        self.state.dispatch(FormAction::Reset(None));
+    }
+
+    /// Replace the model and its initial value, e.g. after loading or saving it.
+    pub fn reset_to(&self, values: T) {
+        self.cancel_async_checks();
+        self.state.dispatch(FormAction::Reset(Some(values)));
+    }
+
+    fn cancel_async_checks(&self) {
+        for slot in self.slots.borrow().values() {
+            slot.restart();
+        }
+    }
+}
+
+/// The state of one registered field, returned by [`FormHandle::register`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormFieldHandle<V: 'static> {
+    name: AttrValue,
+    value: V,
+    update: Callback<V>,
+    error: Option<String>,
+    dirty: bool,
+    touched: bool,
+    submitted: bool,
+    pending: bool,
+}
+
+impl<V: Clone + 'static> FormFieldHandle<V> {
+    /// The field name, for the `name` prop of the control.
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    /// The current value, for the `value` (or `checked`) prop of the control.
+    pub fn value(&self) -> V {
+        self.value.clone()
+    }
+
+    /// The callback storing a new value, for the `update` prop of the control.
+    pub fn update(&self) -> Callback<V> {
+        self.update.clone()
+    }
+
+    /// The current validation error, whether visible yet or not.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The validation error once the field was touched or a submit was attempted.
+    pub fn visible_error(&self) -> Option<&str> {
+        self.error.as_deref().filter(|_| self.touched || self.submitted)
+    }
+
+    /// Whether a visible error should be shown; pass to `help_has_error` of a [`Field`](crate::Field).
+    pub fn has_error(&self) -> bool {
+        self.visible_error().is_some()
+    }
+
+    /// Whether the value differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Whether the control has lost focus at least once.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    /// Whether the async validator has not answered for the current value yet; pass to `loading` of a
+    /// [`Control`](crate::Control).
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+}
+
+/// The visible errors of the surrounding [`Form`], read by [`Field`](crate::Field).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormContext {
+    errors: Rc<BTreeMap<AttrValue, String>>,
+}
+
+impl FormContext {
+    /// The visible error of the field `name`.
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.errors.get(name).map(String::as_str)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// Keeps the state of a form whose model starts out as `T::default()`.
+#[hook]
+pub fn use_form<T>() -> FormHandle<T>
+where
+    T: Clone + Default + PartialEq + 'static,
+{
+    use_form_with(T::default)
+}
+
+/// Keeps the state of a form whose model starts out as `init()`.
+///
+/// Fields are registered anew on every render, see [`FormHandle::register`].
+#[hook]
+pub fn use_form_with<T, F>(init: F) -> FormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer(move || {
+        let values = init();
+        FormState {
+            initial: values.clone(),
+            values,
+            touched: BTreeSet::new(),
+            submit_count: 0,
+            pending: BTreeMap::new(),
+            async_results: BTreeMap::new(),
+            submit_held: false,
+        }
+    });
+    let registry = use_mut_ref(BTreeMap::new);
+    registry.borrow_mut().clear();
+    let slots = use_mut_ref(BTreeMap::new);
+    FormHandle { state, registry, slots }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct FormProps<T: Clone + PartialEq + 'static> {
+    /// The handle returned by [`use_form`].
+    pub form: FormHandle<T>,
+    /// Receives the model when the form is submitted with all fields valid.
+    #[prop_or_default]
+    pub onsubmit: Callback<T>,
+    /// Receives all errors when the form is submitted with invalid fields.
+    #[prop_or_default]
+    pub on_invalid: Callback<BTreeMap<AttrValue, String>>,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// Skip the browser's own constraint validation, leaving it to the registered validators.
+    #[prop_or(true)]
+    pub novalidate: bool,
+    /// The accessible label of the form.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+}
+
+/// A `<form>` element backed by a [`FormHandle`].
+///
+/// Blurred controls mark the field of the same `name` as touched; submitting validates all registered fields and
+/// emits `onsubmit` with the model when they are valid. Submitting while an async validator is pending, or has yet to
+/// check a value, holds the submit until the checks answered and then emits `onsubmit` or `on_invalid`; editing the
+/// model in the meantime drops the held submit. Errors already known when submitting are emitted right away.
+#[component(Form)]
+pub fn form<T: Clone + PartialEq + 'static>(props: &FormProps<T>) -> Html {
+    let context = FormContext {
+        errors: Rc::new(props.form.visible_errors()),
+    };
+
+    let onsubmit = {
+        let form = props.form.clone();
+        let on_valid = props.onsubmit.clone();
+        let on_invalid = props.on_invalid.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            let unverified = form.has_unverified();
+            match form.submit() {
+                Some(values) => on_valid.emit(values),
+                None if (unverified || form.is_pending()) && form.is_valid() => form.state.dispatch(FormAction::HoldSubmit(true)),
+                None => on_invalid.emit(form.errors()),
+            }
+        })
+    };
+    {
+        let on_valid = props.onsubmit.clone();
+        let on_invalid = props.on_invalid.clone();
+        use_effect_with(props.form.clone(), move |form| {
+            if form.state.submit_held && !form.is_pending() && !form.has_unverified() {
+                form.state.dispatch(FormAction::HoldSubmit(false));
+                if form.is_valid() {
+                    on_valid.emit(form.state.values.clone());
+                } else {
+                    on_invalid.emit(form.errors());
+                }
+            }
+        });
+    }
+    let onfocusout = {
+        let form = props.form.clone();
+        Callback::from(move |event: FocusEvent| {
+            let name = event
+                .target_dyn_into::<web_sys::Element>()
+                .and_then(|element| element.get_attribute("name"))
+                .filter(|name| !name.is_empty());
+            if let Some(name) = name {
+                form.touch(name);
+            }
+        })
+    };
+
+    let aria_label = (!props.aria_label.is_empty()).then(|| props.aria_label.clone());
+    let form = props.attrs.apply(html! {
+        <form class={props.classes.clone()} novalidate={props.novalidate} {onsubmit} {onfocusout} aria-label={aria_label}>
+            {props.children.clone()}
+        </form>
+    });
+    html! {
+        <ContextProvider<FormContext> {context}>
+            {form}
+        </ContextProvider<FormContext>>
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+    use crate::{Field, Input};
+
+    #[test]
+    fn required_rejects_blank_values() {
+        let required = Validator::<String>::required("Required");
+        assert_eq!(required.validate(&"  ".to_string()), Err("Required".to_string()));
+        assert_eq!(required.validate(&" a ".to_string()), Ok(()));
+        assert!(Validator::<bool>::required("Required").validate(&false).is_err());
+        assert!(Validator::<bool>::required("Required").validate(&true).is_ok());
+        assert!(Validator::<Vec<u8>>::required("Required").validate(&vec![]).is_err());
+        assert!(Validator::<Option<String>>::required("Required").validate(&Some(String::new())).is_err());
+        assert!(Validator::<Option<String>>::required("Required").validate(&Some("a".into())).is_ok());
+    }
+
+    #[test]
+    fn length_validators_count_characters() {
+        let min = Validator::min_length(3, "Too short");
+        assert_eq!(min.validate(&String::new()), Ok(()));
+        assert_eq!(min.validate(&"ab".to_string()), Err("Too short".to_string()));
+        assert_eq!(min.validate(&"äöü".to_string()), Ok(()));
+
+        let max = Validator::max_length(3, "Too long");
+        assert_eq!(max.validate(&"äöü".to_string()), Ok(()));
+        assert_eq!(max.validate(&"abcd".to_string()), Err("Too long".to_string()));
+    }
+
+    #[test]
+    fn email_accepts_empty_and_well_formed_addresses() {
+        let email = Validator::email("Invalid");
+        for valid in ["", "  ", "a@b.co", " jane.doe@mail.example.org "] {
+            assert_eq!(email.validate(&valid.to_string()), Ok(()), "{valid:?}");
+        }
+        for invalid in ["jane", "@b.co", "a@b", "a@b.", "a@.co", "a b@c.de"] {
+            assert_eq!(email.validate(&invalid.to_string()), Err("Invalid".to_string()), "{invalid:?}");
+        }
+    }
+
+    fn empty_state() -> Rc<FormState<String>> {
+        Rc::new(FormState {
+            values: String::new(),
+            initial: String::new(),
+            touched: BTreeSet::new(),
+            submit_count: 0,
+            pending: BTreeMap::new(),
+            async_results: BTreeMap::new(),
+            submit_held: false,
+        })
+    }
+
+    #[test]
+    fn reducer_tracks_touched_fields_and_submits() {
+        let state = empty_state();
+        let unchanged = state.clone().reduce(FormAction::Update(Box::new(|_| {})));
+        assert!(Rc::ptr_eq(&state, &unchanged));
+
+        let state = state.reduce(FormAction::Update(Box::new(|value| value.push('a'))));
+        let state = state.reduce(FormAction::Touch("name".into()));
+        assert!(Rc::ptr_eq(&state, &state.clone().reduce(FormAction::Touch("name".into()))));
+        let state = state.reduce(FormAction::Submit);
+        assert_eq!((state.values.as_str(), state.submit_count), ("a", 1));
+        assert!(state.touched.contains("name"));
+
+        let state = state.reduce(FormAction::HoldSubmit(true));
+        assert!(Rc::ptr_eq(&state, &state.clone().reduce(FormAction::HoldSubmit(true))));
+        let edited = state.clone().reduce(FormAction::Update(Box::new(|value| value.push('b'))));
+        assert!(state.submit_held && !edited.submit_held);
+
+        let state = state.reduce(FormAction::Reset(None));
+        assert_eq!((state.values.as_str(), state.submit_count), ("", 0));
+        assert!(!state.submit_held);
+        assert!(state.touched.is_empty());
+        let state = state.reduce(FormAction::Reset(Some("b".into())));
+        assert_eq!((state.values.as_str(), state.initial.as_str()), ("b", "b"));
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Signup {
+        email: String,
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Properties)]
+    struct SignupFormProps {
+        #[prop_or_default]
+        email: String,
+        /// Typed into the field while rendering.
+        #[prop_or_default]
+        typed: Option<String>,
+        /// Blur the field while rendering.
+        #[prop_or_default]
+        touch: bool,
+        /// Submit while rendering.
+        #[prop_or_default]
+        submit: bool,
+    }
+
+    #[component(SignupForm)]
+    fn signup_form(props: &SignupFormProps) -> Html {
+        let email = props.email.clone();
+        let form = use_form_with(move || Signup { email });
+        // Edit once, before the field reads the state: the markup of the first render is the one rendered.
+        let first_render = !use_mut_ref(|| false).replace(true);
+        if first_render {
+            if let Some(typed) = props.typed.clone() {
+                form.update(move |signup| signup.email = typed);
+            }
+            if props.touch {
+                form.touch("email");
+            }
+        }
+        let field = form.register(
+            FormFieldDef::new("email", |signup: &Signup| signup.email.clone(), |signup, email| signup.email = email)
+                .validate(Validator::required("Please enter your email address."))
+                .validate(Validator::email("This is not an email address.")),
+        );
+        let submitted = (first_render && props.submit).then(|| form.submit());
+        html! {
+            <Form<Signup> form={form.clone()}>
+                <Field label="Email" name="email" help={Some("We never share it.".to_string())}>
+                    <Input name={field.name()} value={field.value()} update={field.update()} />
+                </Field>
+                <p data-testid="state">
+                    {format!("dirty={} touched={} errors={}", field.is_dirty(), field.is_touched(), form.errors().len())}
+                </p>
+                <p data-testid="submitted">{format!("{:?}", submitted)}</p>
+            </Form<Signup>>
+        }
+    }
+
+    #[test]
+    fn errors_stay_hidden_until_touched_or_submitted() {
+        let rendered = render_blocking(|| html! { <SignupForm /> });
+        let help = rendered.get_by_text("We never share it.");
+        assert!(help.has_class("help") && !help.has_class("is-danger"));
+        assert_eq!(rendered.get_by_test_id("state").text(), "dirty=false touched=false errors=1");
+    }
+
+    #[test]
+    fn touched_fields_show_their_error_instead_of_the_help() {
+        let rendered = render_blocking(|| html! { <SignupForm email="jane" touch=true /> });
+        assert!(rendered.query_by_text("We never share it.").is_none());
+        assert!(rendered.get_by_text("This is not an email address.").has_class("is-danger"));
+        assert_eq!(rendered.get_by_test_id("state").text(), "dirty=false touched=true errors=1");
+    }
+
+    #[test]
+    fn typed_values_make_the_field_dirty() {
+        let rendered = render_blocking(|| html! { <SignupForm typed={Some("jane@example.com".to_string())} /> });
+        assert_eq!(rendered.get_by_test_id("state").text(), "dirty=true touched=false errors=0");
+        assert_eq!(rendered.get_by_role("textbox").attr("value").as_deref(), Some("jane@example.com"));
+    }
+
+    #[test]
+    fn submit_is_blocked_while_errors_exist() {
+        let rendered = render_blocking(|| html! { <SignupForm submit=true /> });
+        assert_eq!(rendered.get_by_test_id("submitted").text(), "Some(None)");
+        assert!(rendered.get_by_text("Please enter your email address.").has_class("is-danger"));
+
+        let rendered = render_blocking(|| html! { <SignupForm email="jane@example.com" submit=true /> });
+        assert_eq!(
+            rendered.get_by_test_id("submitted").text(),
+            r#"Some(Some(Signup { email: "jane@example.com" }))"#
+        );
+        assert_eq!(rendered.get_by_text("We never share it.").attr("class").as_deref(), Some("help"));
+    }
+
+    fn checked(name: &str, error: Option<&str>) -> AsyncResult {
+        AsyncResult {
+            value: Rc::new(name.to_string()),
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn async_answers_only_resolve_the_latest_generation() {
+        let state = empty_state();
+        let name = AttrValue::from("name");
+        let state = state
+            .reduce(FormAction::Pending(name.clone(), 1))
+            .reduce(FormAction::Pending(name.clone(), 2));
+        let stale = state.clone().reduce(FormAction::Resolve(name.clone(), 1, checked("a", Some("Taken"))));
+        assert!(Rc::ptr_eq(&state, &stale));
+        assert_eq!(state.pending.get("name"), Some(&2));
+
+        let state = state.reduce(FormAction::Resolve(name.clone(), 2, checked("ab", None)));
+        assert!(state.pending.is_empty());
+        assert_eq!(
+            state
+                .async_results
+                .get("name")
+                .and_then(|result| result.value.downcast_ref::<String>())
+                .map(String::as_str),
+            Some("ab")
+        );
+
+        let late = state.clone().reduce(FormAction::Resolve(name.clone(), 2, checked("ab", Some("Taken"))));
+        assert!(Rc::ptr_eq(&state, &late));
+    }
+
+    #[test]
+    fn idle_drops_the_pending_check() {
+        let state = empty_state();
+        let name = AttrValue::from("name");
+        assert!(Rc::ptr_eq(&state, &state.clone().reduce(FormAction::Idle(name.clone()))));
+        let state = state.reduce(FormAction::Pending(name.clone(), 1)).reduce(FormAction::Idle(name.clone()));
+        assert!(state.pending.is_empty());
+        let state = state.reduce(FormAction::Resolve(name, 1, checked("a", Some("Taken"))));
+        assert!(state.async_results.is_empty());
+    }
+
+    #[test]
+    fn restarting_a_slot_starts_a_new_generation() {
+        let slot = AsyncSlot::default();
+        assert_eq!(slot.restart(), 1);
+        assert_eq!(slot.restart(), 2);
+        assert_eq!(slot.generation.get(), 2);
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct UsernameFormProps {
+        name: AttrValue,
+        /// Start a check while rendering.
+        #[prop_or_default]
+        pending: bool,
+        /// Resolve a check of this value with this error while rendering.
+        #[prop_or_default]
+        answer: Option<(&'static str, Option<&'static str>)>,
+        /// Submit while rendering.
+        #[prop_or_default]
+        submit: bool,
+    }
+
+    #[component(UsernameForm)]
+    fn username_form(props: &UsernameFormProps) -> Html {
+        let initial = props.name.to_string();
+        let form = use_form_with(move || initial);
+        let first_render = !use_mut_ref(|| false).replace(true);
+        if first_render && (props.pending || props.answer.is_some()) {
+            form.state.dispatch(FormAction::Pending("name".into(), 1));
+            if let Some((value, error)) = props.answer {
+                form.state.dispatch(FormAction::Resolve("name".into(), 1, checked(value, error)));
+            }
+        }
+        let field = form.register(
+            FormFieldDef::new("name", String::clone, |name, value| *name = value)
+                .validate(Validator::required("Please pick a name."))
+                .validate_async(AsyncValidator::ready(|_: &String| Ok(()))),
+        );
+        let submitted = (first_render && props.submit).then(|| form.submit());
+        html! {
+            <p>
+                {format!(
+                    "pending={} error={:?} unverified={} submitted={:?}",
+                    field.is_pending(),
+                    field.error(),
+                    form.has_unverified(),
+                    submitted,
+                )}
+            </p>
+        }
+    }
+
+    #[test]
+    fn pending_checks_hold_back_the_submit() {
+        let rendered = render_blocking(|| html! { <UsernameForm name="jane" pending=true submit=true /> });
+        assert_eq!(rendered.text(), "pending=true error=None unverified=false submitted=Some(None)");
+    }
+
+    #[test]
+    fn async_errors_apply_to_the_value_they_checked() {
+        let rendered = render_blocking(|| html! { <UsernameForm name="jane" answer={Some(("jane", Some("Taken")))} submit=true /> });
+        assert_eq!(
+            rendered.text(),
+            r#"pending=false error=Some("Taken") unverified=false submitted=Some(None)"#
+        );
+
+        let rendered = render_blocking(|| html! { <UsernameForm name="janet" answer={Some(("jane", Some("Taken")))} /> });
+        assert_eq!(rendered.text(), "pending=false error=None unverified=true submitted=None");
+
+        let rendered = render_blocking(|| html! { <UsernameForm name="jane" answer={Some(("jane", None))} submit=true /> });
+        assert_eq!(
+            rendered.text(),
+            r#"pending=false error=None unverified=false submitted=Some(Some("jane"))"#
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use gloo_timers::future::TimeoutFuture;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::Input;
+    use crate::testing::{Queries, click, input_text, mount, settle};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[component(Signup)]
+    fn signup() -> Html {
+        let submitted = use_state(|| 0);
+        let invalid = use_state(|| 0);
+        let form = use_form_with(|| "taken".to_string());
+        let name = form.register(
+            FormFieldDef::new("name", |name: &String| name.clone(), |name, value| *name = value).validate_async(AsyncValidator::ready(
+                |name: &String| {
+                    if name == "taken" {
+                        Err("That name is taken.".to_string())
+                    } else {
+                        Ok(())
+                    }
+                },
+            )),
+        );
+        let onsubmit = {
+            let submitted = submitted.clone();
+            Callback::from(move |_: String| submitted.set(*submitted + 1))
+        };
+        let on_invalid = {
+            let invalid = invalid.clone();
+            Callback::from(move |_| invalid.set(*invalid + 1))
+        };
+        html! {
+            <Form<String> form={form.clone()} {onsubmit} {on_invalid}>
+                <Input name={name.name()} value={name.value()} update={name.update()} />
+                <p data-testid="error">{name.visible_error().unwrap_or_default()}</p>
+                <p data-testid="submitted">{*submitted}</p>
+                <p data-testid="invalid">{*invalid}</p>
+                <button type="submit">{"Sign up"}</button>
+            </Form<String>>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn submit_checks_values_the_async_validator_never_saw() {
+        let mounted = mount(html! { <Signup /> }).await;
+        click(&mounted.get_by_role_and_name("button", "Sign up")).await;
+        settle().await;
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "0");
+        assert_eq!(mounted.get_by_test_id("invalid").text(), "1");
+        assert_eq!(mounted.get_by_test_id("error").text(), "That name is taken.");
+
+        click(&mounted.get_by_role_and_name("button", "Sign up")).await;
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "0");
+        assert_eq!(mounted.get_by_test_id("invalid").text(), "2");
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct UsernameProps {
+        debounce_ms: u32,
+        /// The values the async validator was asked to check.
+        checked: Rc<RefCell<Vec<String>>>,
+    }
+
+    /// Names containing `taken` are taken; checks of names starting with `slow` answer after 100ms.
+    #[component(Username)]
+    fn username(props: &UsernameProps) -> Html {
+        let submitted = use_state(|| 0);
+        let form = use_form::<String>();
+        let checked = props.checked.clone();
+        let validator = AsyncValidator::new(move |name: String| {
+            checked.borrow_mut().push(name.clone());
+            async move {
+                if name.starts_with("slow") {
+                    TimeoutFuture::new(100).await;
+                }
+                if name.contains("taken") {
+                    Err("That name is taken.".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+        });
+        let name = form
+            .register(FormFieldDef::new("name", String::clone, |name, value| *name = value).validate_async(validator.debounce(props.debounce_ms)));
+        let onsubmit = {
+            let submitted = submitted.clone();
+            Callback::from(move |_: String| submitted.set(*submitted + 1))
+        };
+        html! {
+            <Form<String> form={form.clone()} {onsubmit}>
+                <Input name={name.name()} value={name.value()} update={name.update()} />
+                <p data-testid="state">{format!("pending={} error={:?}", name.is_pending(), name.error())}</p>
+                <p data-testid="submitted">{*submitted}</p>
+                <button type="submit">{"Save"}</button>
+            </Form<String>>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn checks_start_once_the_value_rests_for_the_debounce_delay() {
+        let checked = Rc::new(RefCell::new(Vec::new()));
+        let mounted = mount(html! { <Username debounce_ms={50} checked={checked.clone()} /> }).await;
+        input_text(&mounted.get_by_role("textbox"), "ta").await;
+        input_text(&mounted.get_by_role("textbox"), "taken").await;
+        assert_eq!(mounted.get_by_test_id("state").text(), "pending=true error=None");
+        assert!(checked.borrow().is_empty());
+
+        TimeoutFuture::new(100).await;
+        settle().await;
+        assert_eq!(*checked.borrow(), ["taken"]);
+        assert_eq!(
+            mounted.get_by_test_id("state").text(),
+            r#"pending=false error=Some("That name is taken.")"#
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn answers_for_outdated_values_are_dropped() {
+        let checked = Rc::new(RefCell::new(Vec::new()));
+        let mounted = mount(html! { <Username debounce_ms={0} checked={checked.clone()} /> }).await;
+        input_text(&mounted.get_by_role("textbox"), "slow-taken").await;
+        settle().await;
+        input_text(&mounted.get_by_role("textbox"), "jane").await;
+        TimeoutFuture::new(200).await;
+        settle().await;
+        assert_eq!(*checked.borrow(), ["slow-taken", "jane"]);
+        assert_eq!(mounted.get_by_test_id("state").text(), "pending=false error=None");
+    }
+
+    #[wasm_bindgen_test]
+    async fn submit_waits_for_the_pending_check() {
+        let checked = Rc::new(RefCell::new(Vec::new()));
+        let mounted = mount(html! { <Username debounce_ms={50} checked={checked.clone()} /> }).await;
+        input_text(&mounted.get_by_role("textbox"), "jane").await;
+        click(&mounted.get_by_role_and_name("button", "Save")).await;
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "0");
+
+        TimeoutFuture::new(100).await;
+        settle().await;
+        assert_eq!(*checked.borrow(), ["jane"]);
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "1");
+    }
+
+    #[wasm_bindgen_test]
+    async fn held_submits_are_emitted_once_the_check_answers_valid() {
+        let checked = Rc::new(RefCell::new(Vec::new()));
+        let mounted = mount(html! { <Username debounce_ms={0} checked={checked.clone()} /> }).await;
+        // Rendered without typing: the empty name was never checked, so submitting starts the check.
+        click(&mounted.get_by_role_and_name("button", "Save")).await;
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "0");
+
+        TimeoutFuture::new(50).await;
+        settle().await;
+        assert_eq!(*checked.borrow(), [""]);
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "1");
+    }
+
+    #[wasm_bindgen_test]
+    async fn edits_drop_a_held_submit() {
+        let checked = Rc::new(RefCell::new(Vec::new()));
+        let mounted = mount(html! { <Username debounce_ms={0} checked={checked.clone()} /> }).await;
+        input_text(&mounted.get_by_role("textbox"), "slow").await;
+        click(&mounted.get_by_role_and_name("button", "Save")).await;
+        input_text(&mounted.get_by_role("textbox"), "jane").await;
+
+        TimeoutFuture::new(200).await;
+        settle().await;
+        assert_eq!(mounted.get_by_test_id("state").text(), "pending=false error=None");
+        assert_eq!(mounted.get_by_test_id("submitted").text(), "0");
+    }
+}
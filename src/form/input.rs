@@ -3,7 +3,7 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::Size;
+use crate::{ExtraAttrs, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct InputProps {
@@ -16,6 +16,9 @@ pub struct InputProps {
 
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The input type of this component.
     #[prop_or_else(|| InputType::Text)]
     pub r#type: InputType,
@@ -47,6 +50,62 @@ pub struct InputProps {
     /// attribute (no limit); `Some(n)` caps input to `n` characters.
     #[prop_or_default]
     pub maxlength: Option<u32>,
+    /// The `minlength` attribute for this form element. `None` omits the attribute.
+    #[prop_or_default]
+    pub minlength: Option<u32>,
+    /// The `min` attribute, e.g. `"0"` or `"2024-01-01"`; omitted when empty.
+    #[prop_or_default]
+    pub min: AttrValue,
+    /// The `max` attribute; omitted when empty.
+    #[prop_or_default]
+    pub max: AttrValue,
+    /// Mark this form element as required.
+    #[prop_or_default]
+    pub required: bool,
+    /// The message shown when an `InputType::Number` value does not match the pattern. See
+    /// [`NumberInput`](crate::NumberInput) for typed numbers with configurable precision.
+    #[prop_or_else(|| "Please enter a number with up to two decimal places.".into())]
+    pub validity_message: AttrValue,
+    /// The `pattern` attribute; omitted when empty. For `InputType::Number` it replaces the default
+    /// two-decimal pattern.
+    #[prop_or_default]
+    pub pattern: AttrValue,
+    /// The `autocomplete` attribute, e.g. `"email"` or `"off"`; omitted when empty.
+    #[prop_or_default]
+    pub autocomplete: AttrValue,
+    /// The `id` attribute, e.g. for a `<label for=...>`; omitted when empty.
+    #[prop_or_default]
+    pub id: AttrValue,
+    /// The id of a `<datalist>` with suggestions; omitted when empty.
+    #[prop_or_default]
+    pub list: AttrValue,
+    /// The `aria-label` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// The `aria-labelledby` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_labelledby: AttrValue,
+    /// The `aria-describedby` attribute, e.g. the id of a help text; omitted when empty.
+    #[prop_or_default]
+    pub aria_describedby: AttrValue,
+    /// Set `aria-invalid="true"`, e.g. while the field shows a validation error.
+    #[prop_or_default]
+    pub aria_invalid: bool,
+    /// Called when this form element receives focus.
+    #[prop_or_default]
+    pub onfocus: Callback<FocusEvent>,
+    /// Called when this form element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+    /// Called for key presses in this form element.
+    #[prop_or_default]
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// The `inputmode` attribute selecting the on-screen keyboard, e.g. `"numeric"`; omitted when empty.
+    #[prop_or_default]
+    pub inputmode: AttrValue,
+    /// A reference to the underlying `<input>` element, e.g. to move the caret.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
 }
 
 /// A text input element.
@@ -71,7 +130,7 @@ pub fn input(props: &InputProps) -> Html {
         input.value()
     });
 
-    let input_ref = use_node_ref();
+    let input_ref = props.node_ref.clone();
 
     let oninput_number = props.update.reform({
         let input_ref = input_ref.clone();
@@ -87,53 +146,65 @@ pub fn input(props: &InputProps) -> Html {
 
     let oninvalid = Callback::from({
         let input_ref = input_ref.clone();
+        let validity_message = props.validity_message.clone();
         move |_| {
             if let Some(input) = input_ref.cast::<HtmlInputElement>() {
                 if input.value() == "" {
                     input.set_custom_validity("");
                 } else {
-                    input.set_custom_validity("Please enter a number with up to two decimal places.");
+                    input.set_custom_validity(&validity_message);
                 }
             }
         }
     });
 
-    html! {
-        if props.r#type == InputType::Number {
-            <input
-                name={props.name.clone()}
-                value={props.value.clone()}
-                {class}
-                type={props.r#type.to_string()}
-                ref={input_ref.clone()}
-                oninput={oninput_number}
-                oninvalid={oninvalid}
-                placeholder={props.placeholder.clone()}
-                disabled={props.disabled}
-                readonly={props.readonly}
-                step={props.step.to_string()}
-                pattern="[0-9]+([.][0-9]{0,2})?"
-                maxlength={props.maxlength.map(|m| m.to_string())}
-                />
-        } else {
-            <input
-                name={props.name.clone()}
-                value={props.value.clone()}
-                oninput={oninput_text}
-                {class}
-                type={props.r#type.to_string()}
-                placeholder={props.placeholder.clone()}
-                disabled={props.disabled}
-                readonly={props.readonly}
-                maxlength={props.maxlength.map(|m| m.to_string())}
-                />
-        }
-    }
+    let is_number = props.r#type == InputType::Number;
+    let non_empty = |value: &AttrValue| (!value.is_empty()).then(|| value.clone());
+    let pattern = match non_empty(&props.pattern) {
+        Some(pattern) => Some(pattern),
+        None if is_number => Some(AttrValue::Static("[0-9]+([.][0-9]{0,2})?")),
+        None => None,
+    };
+    let step = (is_number || props.step > 0.0).then(|| props.step.to_string());
+
+    props.attrs.apply(html! {
+        <input
+            name={props.name.clone()}
+            value={props.value.clone()}
+            {class}
+            type={props.r#type.to_string()}
+            ref={input_ref}
+            oninput={if is_number { oninput_number } else { oninput_text }}
+            oninvalid={is_number.then_some(oninvalid)}
+            onfocus={props.onfocus.clone()}
+            onblur={props.onblur.clone()}
+            onkeydown={props.onkeydown.clone()}
+            placeholder={props.placeholder.clone()}
+            disabled={props.disabled}
+            readonly={props.readonly}
+            required={props.required}
+            {step}
+            {pattern}
+            min={non_empty(&props.min)}
+            max={non_empty(&props.max)}
+            maxlength={props.maxlength.map(|m| m.to_string())}
+            minlength={props.minlength.map(|m| m.to_string())}
+            autocomplete={non_empty(&props.autocomplete)}
+            id={non_empty(&props.id)}
+            list={non_empty(&props.list)}
+            inputmode={non_empty(&props.inputmode)}
+            aria-label={non_empty(&props.aria_label)}
+            aria-labelledby={non_empty(&props.aria_labelledby)}
+            aria-describedby={non_empty(&props.aria_describedby)}
+            aria-invalid={props.aria_invalid.then_some("true")}
+            />
+    })
 }
 
-/// The 4 allowed types for an input component.
+/// The allowed types for an input component.
 ///
 /// https://bulma.io/documentation/form/input/
+/// https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types
 #[derive(Clone, Debug, Display, PartialEq, Eq)]
 pub enum InputType {
     #[display("text")]
@@ -146,4 +217,166 @@ pub enum InputType {
     Tel,
     #[display("number")]
     Number,
+    #[display("url")]
+    Url,
+    #[display("search")]
+    Search,
+    #[display("date")]
+    Date,
+    #[display("time")]
+    Time,
+    #[display("datetime-local")]
+    DateTimeLocal,
+    #[display("month")]
+    Month,
+    #[display("week")]
+    Week,
+    #[display("color")]
+    Color,
+    #[display("range")]
+    Range,
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, render_blocking};
+
+    #[test]
+    fn input_types_render_as_their_type_attribute() {
+        let types = [
+            (InputType::DateTimeLocal, "datetime-local"),
+            (InputType::Month, "month"),
+            (InputType::Week, "week"),
+            (InputType::Color, "color"),
+            (InputType::Range, "range"),
+            (InputType::Search, "search"),
+        ];
+        for (r#type, expected) in types {
+            let rendered = render_blocking(move || html! { <Input name="when" value="" update={Callback::noop()} {r#type} /> });
+            let input = rendered.find_all(|element| element.tag() == "input").remove(0);
+            assert_eq!(input.attr("type").as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn optional_attributes_are_left_out_when_empty() {
+        let rendered = render_blocking(|| html! { <Input name="city" value="" update={Callback::noop()} /> });
+        let input = rendered.get_by_role("textbox");
+        let omitted = [
+            "min",
+            "max",
+            "minlength",
+            "maxlength",
+            "required",
+            "pattern",
+            "step",
+            "autocomplete",
+            "id",
+            "list",
+            "inputmode",
+            "aria-label",
+            "aria-labelledby",
+            "aria-describedby",
+            "aria-invalid",
+        ];
+        for name in omitted {
+            assert_eq!(input.attr(name), None, "`{}` should be left out", name);
+        }
+    }
+
+    #[test]
+    fn attributes_are_rendered_when_set() {
+        let rendered = render_blocking(|| {
+            html! {
+                <Input name="due" value="" update={Callback::noop()} r#type={InputType::Date}
+                    min="2024-01-01" max="2024-12-31" required=true id="due" list="holidays" inputmode="numeric"
+                    minlength={Some(2)} maxlength={Some(10)} autocomplete="off" aria_label="Due date"
+                    aria_labelledby="due-label" aria_describedby="due-help" aria_invalid=true />
+            }
+        });
+        let input = rendered.find_all(|element| element.tag() == "input").remove(0);
+        let expected = [
+            ("min", "2024-01-01"),
+            ("max", "2024-12-31"),
+            ("id", "due"),
+            ("list", "holidays"),
+            ("inputmode", "numeric"),
+            ("minlength", "2"),
+            ("maxlength", "10"),
+            ("autocomplete", "off"),
+            ("aria-label", "Due date"),
+            ("aria-labelledby", "due-label"),
+            ("aria-describedby", "due-help"),
+            ("aria-invalid", "true"),
+        ];
+        for (name, value) in expected {
+            assert_eq!(input.attr(name).as_deref(), Some(value), "`{}`", name);
+        }
+        assert!(input.attr("required").is_some());
+        assert_eq!(input.attr("pattern"), None);
+    }
+
+    #[test]
+    fn a_custom_pattern_replaces_the_two_decimal_default_of_numbers() {
+        let number = |pattern: &'static str| {
+            let rendered = render_blocking(move || {
+                html! { <Input name="amount" value="" update={Callback::noop()} r#type={InputType::Number} {pattern} /> }
+            });
+            rendered.find_all(|element| element.tag() == "input").remove(0).attr("pattern")
+        };
+        assert_eq!(number("").as_deref(), Some("[0-9]+([.][0-9]{0,2})?"));
+        assert_eq!(number("[0-9]+").as_deref(), Some("[0-9]+"));
+
+        let text = render_blocking(|| html! { <Input name="code" value="" update={Callback::noop()} pattern="[A-Z]{3}" /> });
+        assert_eq!(text.get_by_role("textbox").attr("pattern").as_deref(), Some("[A-Z]{3}"));
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::Element;
+
+    use super::*;
+    use crate::testing::{Queries, input_text, key_down, mount, settle};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn callbacks_and_the_node_ref_are_wired_to_the_input() {
+        let events = Rc::new(RefCell::new(Vec::<String>::new()));
+        let record = |name: &'static str| {
+            let events = events.clone();
+            move |_| events.borrow_mut().push(name.to_string())
+        };
+        let update = Callback::from({
+            let events = events.clone();
+            move |value: String| events.borrow_mut().push(format!("update {}", value))
+        });
+        let onfocus = Callback::from(record("focus"));
+        let onblur = Callback::from(record("blur"));
+        let onkeydown = Callback::from({
+            let events = events.clone();
+            move |event: KeyboardEvent| events.borrow_mut().push(format!("keydown {}", event.key()))
+        });
+        let node_ref = NodeRef::default();
+        let mounted = mount(html! {
+            <Input name="city" value="" {update} {onfocus} {onblur} {onkeydown} node_ref={node_ref.clone()} />
+        })
+        .await;
+
+        let input = mounted.get_by_role("textbox");
+        assert_eq!(node_ref.cast::<Element>(), Some(input.clone()));
+        // Synthetic focus events, since a headless browser window may not have the focus itself.
+        input.dispatch_event(&FocusEvent::new("focus").unwrap()).unwrap();
+        key_down(&input, "Enter").await;
+        input_text(&input, "Oslo").await;
+        input.dispatch_event(&FocusEvent::new("blur").unwrap()).unwrap();
+        settle().await;
+        assert_eq!(*events.borrow(), ["focus", "keydown Enter", "update Oslo", "blur"]);
+    }
 }
@@ -0,0 +1,350 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{ExtraAttrs, Input, InputType, Size};
+
+/// One position of a [`Mask`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MaskToken {
+    /// `9`: an ASCII digit.
+    Digit,
+    /// `a`: a letter.
+    Letter,
+    /// `*`: a letter or an ASCII digit.
+    Alphanumeric,
+    /// Any other character, or one escaped with `\`: shown as is.
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphabetic() || c.is_ascii_digit(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// A parsed input mask such as `"(999) 999-9999"`.
+///
+/// `9` stands for a digit, `a` for a letter and `*` for a letter or digit; every other character is a literal shown
+/// as is. Escape `9`, `a`, `*` and `\` with a backslash to use them as literals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mask {
+    tokens: Vec<MaskToken>,
+}
+
+/// The value of a [`MaskedInput`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaskedValue {
+    /// The characters typed into the slots of the mask, without literals, e.g. `"5551234567"`.
+    pub raw: String,
+    /// The text as displayed, e.g. `"(555) 123-4567"`.
+    pub formatted: String,
+    /// Whether every slot of the mask is filled.
+    pub complete: bool,
+}
+
+impl Mask {
+    /// Parse `pattern`.
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                '*' => MaskToken::Alphanumeric,
+                '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+                literal => MaskToken::Literal(literal),
+            });
+        }
+        Self { tokens }
+    }
+
+    /// The number of characters the mask takes.
+    pub fn slots(&self) -> usize {
+        self.tokens.iter().filter(|token| !matches!(token, MaskToken::Literal(_))).count()
+    }
+
+    /// Whether the mask only takes digits, e.g. to show a numeric on-screen keyboard.
+    pub fn is_numeric(&self) -> bool {
+        self.tokens.iter().all(|token| matches!(token, MaskToken::Digit | MaskToken::Literal(_)))
+    }
+
+    /// Format `raw` characters, skipping those which do not fit their slot.
+    pub fn format(&self, raw: &str) -> MaskedValue {
+        self.fill(raw, false).0
+    }
+
+    /// Format text typed or pasted by the user, which may contain literals of the mask or other separators,
+    /// e.g. `"555.123.4567"` or `"(555) 123-4567"`.
+    pub fn parse(&self, text: &str) -> MaskedValue {
+        self.fill(text, true).0
+    }
+
+    /// Fill the slots from `input`; with `consume_literals`, input characters equal to the literal at their
+    /// position are taken as that literal. Also returns the length of the formatted text after each raw character.
+    fn fill(&self, input: &str, consume_literals: bool) -> (MaskedValue, Vec<usize>) {
+        let mut input = input.chars().peekable();
+        let mut value = MaskedValue::default();
+        let mut positions = Vec::new();
+        let mut length = 0;
+        // Literals are only shown once a slot after them is filled.
+        let mut pending = String::new();
+        let mut filled = 0;
+        for token in &self.tokens {
+            match *token {
+                MaskToken::Literal(literal) => {
+                    if consume_literals && input.peek() == Some(&literal) {
+                        input.next();
+                    }
+                    pending.push(literal);
+                }
+                slot => {
+                    let Some(c) = input.by_ref().find(|c| slot.accepts(*c)) else { break };
+                    length += pending.chars().count() + 1;
+                    value.formatted.push_str(&pending);
+                    value.formatted.push(c);
+                    value.raw.push(c);
+                    pending.clear();
+                    positions.push(length);
+                    filled += 1;
+                }
+            }
+        }
+        value.complete = filled == self.slots();
+        (value, positions)
+    }
+}
+
+/// The char index of a caret given in UTF-16 code units, as the DOM counts them.
+fn char_index(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    text.chars()
+        .take_while(|c| {
+            units += c.len_utf16();
+            units <= utf16_offset
+        })
+        .count()
+}
+
+/// `c` in upper case, or `c` itself when its upper case takes several chars (e.g. `ß`), so that caret positions
+/// still match.
+fn uppercase_char(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+/// Apply the text the user left in the input after an edit, given the previously formatted text and the caret in
+/// UTF-16 code units (`None` meaning the end). Returns the new value and the caret behind the last character typed,
+/// in UTF-16 code units.
+fn edit(mask: &Mask, previous: &str, typed: &str, caret: Option<u32>, uppercase: bool) -> (MaskedValue, u32) {
+    let caret = caret.map_or(typed.chars().count(), |caret| char_index(typed, caret as usize));
+    let typed: String = if uppercase {
+        typed.chars().map(uppercase_char).collect()
+    } else {
+        typed.to_owned()
+    };
+    let before_caret: String = typed.chars().take(caret).collect();
+    let mut raw_before = mask.parse(&before_caret).raw.chars().count();
+
+    let (mut next, mut positions) = mask.fill(&typed, true);
+    if typed.chars().count() > previous.chars().count() + 1 && next.complete {
+        // A paste with more characters than the mask takes, e.g. a country code: keep the last ones.
+        let chars: Vec<char> = typed.chars().collect();
+        if let Some(suffix) = (0..chars.len())
+            .rev()
+            .map(|skip| chars[skip..].iter().collect::<String>())
+            .find(|suffix| mask.parse(suffix).complete)
+        {
+            (next, positions) = mask.fill(&suffix, true);
+            raw_before = next.raw.chars().count();
+        }
+    } else if typed.chars().count() < previous.chars().count() && next.formatted == previous && raw_before > 0 {
+        // Only a literal was deleted: delete the character in front of it instead.
+        let mut raw: Vec<char> = next.raw.chars().collect();
+        raw.remove(raw_before - 1);
+        raw_before -= 1;
+        (next, positions) = mask.fill(&raw.into_iter().collect::<String>(), false);
+    }
+
+    let caret = raw_before.checked_sub(1).and_then(|index| positions.get(index)).copied().unwrap_or(0);
+    let caret = next.formatted.chars().take(caret).map(char::len_utf16).sum::<usize>();
+    (next, caret as u32)
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct MaskedInputProps {
+    /// The `name` attribute for this form element.
+    pub name: String,
+    /// The controlled raw value of this form element, without the literals of the mask.
+    pub value: String,
+    /// Receives the raw and the formatted value on every change.
+    pub update: Callback<MaskedValue>,
+    /// The mask, e.g. `"(999) 999-9999"`; see [`Mask`].
+    pub mask: AttrValue,
+
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The input type of this component.
+    #[prop_or_else(|| InputType::Text)]
+    pub r#type: InputType,
+    /// Convert letters to upper case, e.g. for IBANs.
+    #[prop_or_default]
+    pub uppercase: bool,
+    /// The placeholder value for this component, e.g. `"(___) ___-____"`.
+    #[prop_or_default]
+    pub placeholder: String,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Use rounded appearance.
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Make this component read-only.
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Mark this form element as required.
+    #[prop_or_default]
+    pub required: bool,
+    /// The `autocomplete` attribute, e.g. `"tel-national"`; omitted when empty.
+    #[prop_or_default]
+    pub autocomplete: AttrValue,
+    /// The `id` attribute; omitted when empty.
+    #[prop_or_default]
+    pub id: AttrValue,
+    /// The `aria-label` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// The `aria-describedby` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_describedby: AttrValue,
+    /// Set `aria-invalid="true"`, e.g. while the field shows a validation error.
+    #[prop_or_default]
+    pub aria_invalid: bool,
+    /// Called when this form element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+}
+
+/// A text input which formats what is typed or pasted into a fixed shape, e.g. phone numbers, IBANs, credit card
+/// numbers or time codes.
+///
+/// [https://bulma.io/documentation/form/input/](https://bulma.io/documentation/form/input/)
+///
+/// Characters which do not fit the next slot of the mask are dropped and literals are inserted automatically. The
+/// caret stays behind the character just typed, and deleting a literal deletes the character before it.
+/// Pasted text may be formatted differently, e.g. `"555.123.4567"`, or start with an extra prefix such as a country
+/// code, which is dropped.
+///
+/// All YBC form components are controlled components. This means that the value of the field must
+/// be provided from a parent component, and changes to this component are propagated to the parent
+/// component via callback.
+#[component(MaskedInput)]
+pub fn masked_input(props: &MaskedInputProps) -> Html {
+    let mask = Mask::new(&props.mask);
+    let formatted = mask.format(&props.value).formatted;
+    let node_ref = use_node_ref();
+
+    let update = {
+        let mask = mask.clone();
+        let node_ref = node_ref.clone();
+        let previous = formatted.clone();
+        let uppercase = props.uppercase;
+        let update = props.update.clone();
+        Callback::from(move |typed: String| {
+            let input = node_ref.cast::<HtmlInputElement>();
+            let caret = input.as_ref().and_then(|input| input.selection_start().ok().flatten());
+            let (next, caret) = edit(&mask, &previous, &typed, caret, uppercase);
+            if let Some(input) = input {
+                input.set_value(&next.formatted);
+                let _ = input.set_selection_range(caret, caret);
+            }
+            update.emit(next);
+        })
+    };
+
+    let inputmode = if mask.is_numeric() { "numeric" } else { "" };
+    html! {
+        <Input
+            name={props.name.clone()}
+            value={formatted}
+            {update}
+            {node_ref}
+            classes={props.classes.clone()}
+            attrs={props.attrs.clone()}
+            r#type={props.r#type.clone()}
+            {inputmode}
+            placeholder={props.placeholder.clone()}
+            size={props.size.clone()}
+            rounded={props.rounded}
+            disabled={props.disabled}
+            readonly={props.readonly}
+            required={props.required}
+            autocomplete={props.autocomplete.clone()}
+            id={props.id.clone()}
+            aria_label={props.aria_label.clone()}
+            aria_describedby={props.aria_describedby.clone()}
+            aria_invalid={props.aria_invalid}
+            onblur={props.onblur.clone()}
+            />
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_moves_the_caret_past_literals() {
+        let mask = Mask::new("(999) 999-9999");
+        let (value, caret) = edit(&mask, "(555", "(5551", None, false);
+        assert_eq!(value.formatted, "(555) 1");
+        assert_eq!(caret, 7);
+    }
+
+    #[test]
+    fn deleting_a_literal_deletes_the_character_in_front_of_it() {
+        let mask = Mask::new("(999) 999-9999");
+        let (value, caret) = edit(&mask, "(555) 1", "(555 1", Some(4), false);
+        assert_eq!(value.raw, "551");
+        assert_eq!(value.formatted, "(551");
+        assert_eq!(caret, 3);
+    }
+
+    #[test]
+    fn pastes_keep_the_last_characters_the_mask_takes() {
+        let mask = Mask::new("(999) 999-9999");
+        let (value, caret) = edit(&mask, "", "+1 555 123 4567", None, false);
+        assert_eq!(value.formatted, "(555) 123-4567");
+        assert_eq!(caret, 14);
+    }
+
+    #[test]
+    fn carets_are_counted_in_utf16_code_units() {
+        let mask = Mask::new("a-a-a");
+        // `𝒜` takes two UTF-16 code units but is a single char.
+        assert_eq!(char_index("𝒜ab", 2), 1);
+        let (value, caret) = edit(&mask, "é", "éü", Some(2), true);
+        assert_eq!(value.formatted, "É-Ü");
+        assert_eq!(caret, 3);
+    }
+
+    #[test]
+    fn uppercase_keeps_the_character_count() {
+        assert_eq!(uppercase_char('a'), 'A');
+        assert_eq!(uppercase_char('ß'), 'ß');
+    }
+}
@@ -2,7 +2,11 @@ pub mod checkbox;
 pub mod control;
 pub mod field;
 pub mod file;
+pub mod form_state;
 pub mod input;
+pub mod masked_input;
+pub mod number_input;
 pub mod radio;
 pub mod select;
 pub mod textarea;
+pub mod upload_queue;
@@ -0,0 +1,614 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{ExtraAttrs, Size};
+
+/// Numeric types accepted by [`NumberInput`].
+pub trait Num: Copy + Debug + Default + PartialOrd + Display + FromStr + 'static {
+    /// Whether the type has a fractional part.
+    const FRACTIONAL: bool;
+    /// Whether the type has negative values.
+    const SIGNED: bool;
+
+    /// `self + step` (or `self - step` when `up` is false), or `None` on overflow.
+    fn step_by(self, step: Self, up: bool) -> Option<Self>;
+}
+
+macro_rules! impl_num_int {
+    ($signed:expr => $($ty:ty),*) => {
+        $(
+            impl Num for $ty {
+                const FRACTIONAL: bool = false;
+                const SIGNED: bool = $signed;
+
+                fn step_by(self, step: Self, up: bool) -> Option<Self> {
+                    if up { self.checked_add(step) } else { self.checked_sub(step) }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_num_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Num for $ty {
+                const FRACTIONAL: bool = true;
+                const SIGNED: bool = true;
+
+                fn step_by(self, step: Self, up: bool) -> Option<Self> {
+                    Some(if up { self + step } else { self - step })
+                }
+            }
+        )*
+    };
+}
+
+impl_num_int!(true => i8, i16, i32, i64, i128, isize);
+impl_num_int!(false => u8, u16, u32, u64, u128, usize);
+impl_num_float!(f32, f64);
+
+/// Why the text of a [`NumberInput`] is not an acceptable number.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberInputError {
+    /// The text is not a number.
+    Invalid,
+    /// The number has more decimal places than allowed; `0` for whole numbers.
+    TooManyDecimals(u32),
+    /// The number is negative although negative numbers are not allowed.
+    Negative,
+    /// The number is below `min`, given as formatted text.
+    BelowMin(String),
+    /// The number is above `max`, given as formatted text.
+    AboveMax(String),
+}
+
+impl NumberInputError {
+    /// The default English validity message.
+    pub fn message(&self) -> String {
+        match self {
+            Self::Invalid => "Please enter a number.".into(),
+            Self::TooManyDecimals(0) => "Please enter a whole number.".into(),
+            Self::TooManyDecimals(1) => "Please enter a number with up to one decimal place.".into(),
+            Self::TooManyDecimals(places) => format!("Please enter a number with up to {} decimal places.", places),
+            Self::Negative => "Please enter a number that is not negative.".into(),
+            Self::BelowMin(min) => format!("Please enter a number of at least {}.", min),
+            Self::AboveMax(max) => format!("Please enter a number of at most {}.", max),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct NumberInputProps<T: Num> {
+    /// The `name` attribute for this form element.
+    pub name: String,
+    /// The controlled value of this form element; `None` when empty or invalid.
+    pub value: Option<T>,
+    /// The callback to be used for propagating changes to this element's value; emits `None` when the text is
+    /// empty or not an acceptable number.
+    pub update: Callback<Option<T>>,
+
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The placeholder value for this component.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Use rounded appearance.
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Make this component read-only.
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Mark this form element as required.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// The maximum number of decimal places; values are also displayed with exactly this many. `None` allows any
+    /// number for floating point types. Integer types never accept decimals.
+    #[prop_or_default]
+    pub precision: Option<u32>,
+    /// The smallest acceptable value.
+    #[prop_or_default]
+    pub min: Option<T>,
+    /// The largest acceptable value.
+    #[prop_or_default]
+    pub max: Option<T>,
+    /// The amount added or subtracted by the arrow up/down keys; the keys do nothing when `None`.
+    #[prop_or_default]
+    pub step: Option<T>,
+    /// Accept negative numbers.
+    #[prop_or(true)]
+    pub negative: bool,
+    /// The decimal separator typed and displayed, e.g. `','` for most European locales.
+    #[prop_or('.')]
+    pub decimal_separator: char,
+    /// A digit grouping separator which is ignored while parsing, e.g. `'.'` for "1.234,5".
+    #[prop_or_default]
+    pub grouping_separator: Option<char>,
+    /// Builds the validity message for unacceptable text; defaults to [`NumberInputError::message`].
+    #[prop_or_else(|| Callback::from(|error: NumberInputError| error.message()))]
+    pub validity_message: Callback<NumberInputError, String>,
+
+    /// The `id` attribute; omitted when empty.
+    #[prop_or_default]
+    pub id: AttrValue,
+    /// The `aria-label` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// The `aria-describedby` attribute; omitted when empty.
+    #[prop_or_default]
+    pub aria_describedby: AttrValue,
+}
+
+/// Display `value` with the configured precision and decimal separator.
+fn format_number<T: Num>(value: T, props: &NumberInputProps<T>) -> String {
+    let text = match props.precision {
+        Some(precision) if T::FRACTIONAL => format!("{:.*}", precision as usize, value),
+        _ => value.to_string(),
+    };
+    if props.decimal_separator == '.' {
+        text
+    } else {
+        text.replace('.', &props.decimal_separator.to_string())
+    }
+}
+
+/// Parse the typed `text`; empty text is `Ok(None)`.
+fn parse_number<T: Num>(text: &str, props: &NumberInputProps<T>) -> Result<Option<T>, NumberInputError> {
+    let stripped: String = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && Some(*c) != props.grouping_separator)
+        .collect();
+    if stripped.is_empty() {
+        return Ok(None);
+    }
+    if props.decimal_separator != '.' && stripped.contains('.') {
+        return Err(NumberInputError::Invalid);
+    }
+    let normalized = stripped.replace(props.decimal_separator, ".");
+    let well_formed = normalized.chars().any(|c| c.is_ascii_digit())
+        && normalized.matches('.').count() <= 1
+        && normalized
+            .char_indices()
+            .all(|(index, c)| c.is_ascii_digit() || c == '.' || (index == 0 && (c == '-' || c == '+')));
+    if !well_formed {
+        return Err(NumberInputError::Invalid);
+    }
+    if normalized.starts_with('-') && (!T::SIGNED || !props.negative) {
+        return Err(NumberInputError::Negative);
+    }
+
+    let decimals = decimal_places(&normalized);
+    let allowed = if T::FRACTIONAL { props.precision.unwrap_or(u32::MAX) } else { 0 };
+    if decimals > allowed as usize {
+        return Err(NumberInputError::TooManyDecimals(allowed));
+    }
+    let value = normalized.trim_end_matches('.').parse::<T>().map_err(|_| NumberInputError::Invalid)?;
+
+    if let Some(min) = props.min
+        && value < min
+    {
+        return Err(NumberInputError::BelowMin(format_number(min, props)));
+    }
+    if let Some(max) = props.max
+        && value > max
+    {
+        return Err(NumberInputError::AboveMax(format_number(max, props)));
+    }
+    Ok(Some(value))
+}
+
+/// The decimal places of a number's text, e.g. 2 for "0.25".
+fn decimal_places(text: &str) -> usize {
+    text.split_once('.').map_or(0, |(_, decimals)| decimals.len())
+}
+
+/// `current` moved by `step` within `min` and `max`, or `None` on overflow.
+///
+/// Without a `precision`, floats are rounded to the decimal places of `step` or `current`, whichever has more, so
+/// that stepping 0.2 by 0.1 gives 0.3 rather than 0.30000000000000004.
+fn step_value<T: Num>(current: T, step: T, up: bool, props: &NumberInputProps<T>) -> Option<T> {
+    let mut next = current.step_by(step, up)?;
+    if T::FRACTIONAL && props.precision.is_none() {
+        let places = decimal_places(&step.to_string()).max(decimal_places(&current.to_string()));
+        next = format!("{:.*}", places, next).parse().unwrap_or(next);
+    }
+    if let Some(min) = props.min
+        && next < min
+    {
+        next = min;
+    }
+    if let Some(max) = props.max
+        && next > max
+    {
+        next = max;
+    }
+    Some(next)
+}
+
+/// A typed numeric input.
+///
+/// [https://bulma.io/documentation/form/input/](https://bulma.io/documentation/form/input/)
+///
+/// The number is typed into a text input with a numeric on-screen keyboard, so that locale decimal separators work.
+/// Text which is not an acceptable number is kept as typed, marked with `is-danger` and a custom validity message,
+/// and emitted as `None`. When `step` is set, the arrow up/down keys change the value within `min` and `max`.
+///
+/// All YBC form components are controlled components. This means that the value of the field must
+/// be provided from a parent component, and changes to this component are propagated to the parent
+/// component via callback.
+#[component(NumberInput)]
+pub fn number_input<T: Num>(props: &NumberInputProps<T>) -> Html {
+    let text = use_state(|| props.value.map(|value| format_number(value, props)).unwrap_or_default());
+    let error = use_state(|| None::<NumberInputError>);
+    let input_ref = use_node_ref();
+
+    // Show values set by the parent, unless the text already stands for them.
+    {
+        let text = text.clone();
+        let error = error.clone();
+        let props = props.clone();
+        use_effect_with(props.value, move |value| {
+            if parse_number(&text, &props).ok().flatten() != *value {
+                text.set(value.map(|value| format_number(value, &props)).unwrap_or_default());
+                error.set(None);
+            }
+            || ()
+        });
+    }
+
+    {
+        let input_ref = input_ref.clone();
+        let validity_message = props.validity_message.clone();
+        use_effect_with((*error).clone(), move |error| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let message = error.clone().map(|error| validity_message.emit(error)).unwrap_or_default();
+                input.set_custom_validity(&message);
+            }
+            || ()
+        });
+    }
+
+    let apply = {
+        let text = text.clone();
+        let error = error.clone();
+        let props = props.clone();
+        move |typed: String| {
+            let parsed = parse_number(&typed, &props);
+            text.set(typed);
+            match parsed {
+                Ok(value) => {
+                    error.set(None);
+                    props.update.emit(value);
+                }
+                Err(reason) => {
+                    error.set(Some(reason));
+                    props.update.emit(None);
+                }
+            }
+        }
+    };
+
+    let oninput = {
+        let apply = apply.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            apply(input.value());
+        })
+    };
+
+    let onkeydown = {
+        let text = text.clone();
+        let props = props.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let up = match event.key().as_str() {
+                "ArrowUp" => true,
+                "ArrowDown" => false,
+                _ => return,
+            };
+            // Read-only inputs still receive key presses.
+            if props.readonly || props.disabled {
+                return;
+            }
+            let Some(step) = props.step else { return };
+            event.prevent_default();
+            let current = parse_number(&text, &props).ok().flatten().or(props.min).unwrap_or_default();
+            if let Some(next) = step_value(current, step, up, &props) {
+                apply(format_number(next, &props));
+            }
+        })
+    };
+
+    // Show the canonical form, e.g. "1,50" for "1,5" with a precision of 2.
+    let onblur = {
+        let text = text.clone();
+        let props = props.clone();
+        Callback::from(move |_: FocusEvent| {
+            if let Ok(Some(value)) = parse_number(&text, &props) {
+                text.set(format_number(value, &props));
+            }
+        })
+    };
+
+    let class = classes!(
+        "input",
+        props.classes.clone(),
+        props.size.as_ref().map(|size| size.to_string()),
+        props.rounded.then_some("is-rounded"),
+        error.is_some().then_some("is-danger"),
+    );
+    let inputmode = if T::FRACTIONAL && props.precision != Some(0) {
+        "decimal"
+    } else {
+        "numeric"
+    };
+    let non_empty = |value: &AttrValue| (!value.is_empty()).then(|| value.clone());
+    props.attrs.apply(html! {
+        <input
+            type="text"
+            {inputmode}
+            name={props.name.clone()}
+            value={(*text).clone()}
+            {class}
+            ref={input_ref}
+            {oninput}
+            {onkeydown}
+            {onblur}
+            placeholder={non_empty(&props.placeholder)}
+            disabled={props.disabled}
+            readonly={props.readonly}
+            required={props.required}
+            id={non_empty(&props.id)}
+            aria-label={non_empty(&props.aria_label)}
+            aria-describedby={non_empty(&props.aria_describedby)}
+            aria-invalid={error.is_some().then_some("true")}
+            />
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use yew::{Callback, props};
+
+    use super::*;
+
+    fn float_props() -> NumberInputProps<f64> {
+        props!(NumberInputProps<f64> { name: "amount", value: None, update: Callback::noop() })
+    }
+
+    fn int_props() -> NumberInputProps<i32> {
+        props!(NumberInputProps<i32> { name: "count", value: None, update: Callback::noop() })
+    }
+
+    fn european(mut props: NumberInputProps<f64>) -> NumberInputProps<f64> {
+        props.decimal_separator = ',';
+        props.grouping_separator = Some('.');
+        props
+    }
+
+    #[test]
+    fn numbers_format_with_precision_and_separator() {
+        let mut props = float_props();
+        assert_eq!(format_number(0.1, &props), "0.1");
+        assert_eq!(format_number(-2.0, &props), "-2");
+        props.precision = Some(2);
+        assert_eq!(format_number(1.5, &props), "1.50");
+        assert_eq!(format_number(1.005_1, &props), "1.01");
+        assert_eq!(format_number(1234.5, &european(props)), "1234,50");
+
+        let mut props = int_props();
+        props.precision = Some(2);
+        assert_eq!(format_number(5, &props), "5");
+    }
+
+    #[test]
+    fn empty_text_is_no_value() {
+        assert_eq!(parse_number("", &float_props()), Ok(None));
+        assert_eq!(parse_number("  ", &int_props()), Ok(None));
+        assert_eq!(parse_number(".", &european(float_props())), Ok(None));
+    }
+
+    #[test]
+    fn separators_are_normalized() {
+        assert_eq!(parse_number("1.5", &float_props()), Ok(Some(1.5)));
+        assert_eq!(parse_number(" 2. ", &float_props()), Ok(Some(2.0)));
+        assert_eq!(parse_number("1.234,5", &european(float_props())), Ok(Some(1234.5)));
+        assert_eq!(parse_number("1 234,5", &european(float_props())), Ok(Some(1234.5)));
+
+        let mut props = float_props();
+        props.grouping_separator = Some(',');
+        assert_eq!(parse_number("1,234.5", &props), Ok(Some(1234.5)));
+
+        // Without a grouping separator, a '.' next to a ',' decimal separator is a typo rather than a grouping.
+        let mut props = float_props();
+        props.decimal_separator = ',';
+        assert_eq!(parse_number("1.5", &props), Err(NumberInputError::Invalid));
+        assert_eq!(parse_number("1,5", &props), Ok(Some(1.5)));
+    }
+
+    #[test]
+    fn malformed_text_is_invalid() {
+        for text in ["abc", "1-2", "1.2.3", "-", "+", "1e3", "--1", "0x10"] {
+            assert_eq!(parse_number(text, &float_props()), Err(NumberInputError::Invalid), "{:?}", text);
+        }
+        let u8_props = props!(NumberInputProps<u8> { name: "byte", value: None, update: Callback::noop() });
+        assert_eq!(parse_number("300", &u8_props), Err(NumberInputError::Invalid));
+    }
+
+    #[test]
+    fn signs_follow_the_type_and_the_negative_prop() {
+        assert_eq!(parse_number("-3", &int_props()), Ok(Some(-3)));
+        assert_eq!(parse_number("+3", &int_props()), Ok(Some(3)));
+
+        let mut props = int_props();
+        props.negative = false;
+        assert_eq!(parse_number("-3", &props), Err(NumberInputError::Negative));
+        assert_eq!(parse_number("+3", &props), Ok(Some(3)));
+
+        let unsigned = props!(NumberInputProps<u32> { name: "count", value: None, update: Callback::noop() });
+        assert_eq!(parse_number("-3", &unsigned), Err(NumberInputError::Negative));
+    }
+
+    #[test]
+    fn decimals_are_limited_by_type_and_precision() {
+        assert_eq!(parse_number("1.5", &int_props()), Err(NumberInputError::TooManyDecimals(0)));
+        assert_eq!(parse_number("1.", &int_props()), Ok(Some(1)));
+        assert_eq!(parse_number("0.123456", &float_props()), Ok(Some(0.123456)));
+
+        let mut props = float_props();
+        props.precision = Some(1);
+        assert_eq!(parse_number("1.25", &props), Err(NumberInputError::TooManyDecimals(1)));
+        assert_eq!(parse_number("1.2", &props), Ok(Some(1.2)));
+        props.precision = Some(0);
+        assert_eq!(parse_number("1.2", &props), Err(NumberInputError::TooManyDecimals(0)));
+    }
+
+    #[test]
+    fn range_errors_carry_the_formatted_bound() {
+        let mut props = european(float_props());
+        props.precision = Some(2);
+        props.min = Some(1.0);
+        props.max = Some(1000.5);
+        assert_eq!(parse_number("0,5", &props), Err(NumberInputError::BelowMin("1,00".into())));
+        assert_eq!(parse_number("1.000,51", &props), Err(NumberInputError::AboveMax("1000,50".into())));
+        assert_eq!(parse_number("1.000,5", &props), Ok(Some(1000.5)));
+        assert_eq!(parse_number("1", &props), Ok(Some(1.0)));
+    }
+
+    #[test]
+    fn messages_name_the_problem() {
+        assert_eq!(NumberInputError::TooManyDecimals(0).message(), "Please enter a whole number.");
+        assert_eq!(
+            NumberInputError::TooManyDecimals(1).message(),
+            "Please enter a number with up to one decimal place."
+        );
+        assert_eq!(
+            NumberInputError::TooManyDecimals(3).message(),
+            "Please enter a number with up to 3 decimal places."
+        );
+        assert_eq!(
+            NumberInputError::BelowMin("1,00".into()).message(),
+            "Please enter a number of at least 1,00."
+        );
+    }
+
+    #[test]
+    fn steps_round_to_the_step_and_stay_in_range() {
+        let props = float_props();
+        assert_eq!(step_value(0.2, 0.1, true, &props), Some(0.3));
+        assert_eq!(step_value(0.3, 0.1, false, &props), Some(0.2));
+        assert_eq!(step_value(0.15, 0.1, true, &props), Some(0.25));
+        assert_eq!(step_value(1.0, 0.25, true, &props), Some(1.25));
+
+        let mut props = float_props();
+        props.min = Some(0.0);
+        props.max = Some(1.0);
+        assert_eq!(step_value(0.95, 0.1, true, &props), Some(1.0));
+        assert_eq!(step_value(0.05, 0.1, false, &props), Some(0.0));
+
+        let mut props = int_props();
+        assert_eq!(step_value(i32::MAX, 1, true, &props), None);
+        props.max = Some(10);
+        assert_eq!(step_value(9, 5, true, &props), Some(10));
+    }
+
+    #[test]
+    fn the_on_screen_keyboard_follows_the_type_and_precision() {
+        use crate::testing::{Queries, render_blocking};
+
+        let inputmode = |view: fn() -> Html| render_blocking(view).get_by_role("textbox").attr("inputmode");
+        assert_eq!(
+            inputmode(|| html! { <NumberInput<f64> name="amount" value={None} update={Callback::noop()} /> }).as_deref(),
+            Some("decimal")
+        );
+        assert_eq!(
+            inputmode(|| html! { <NumberInput<f64> name="amount" value={None} update={Callback::noop()} precision={Some(0)} /> }).as_deref(),
+            Some("numeric")
+        );
+        assert_eq!(
+            inputmode(|| html! { <NumberInput<i32> name="count" value={Some(3)} update={Callback::noop()} /> }).as_deref(),
+            Some("numeric")
+        );
+
+        let valid = render_blocking(|| html! { <NumberInput<i32> name="count" value={Some(3)} update={Callback::noop()} /> });
+        let input = valid.get_by_role("textbox");
+        assert_eq!(input.attr("value").as_deref(), Some("3"));
+        assert_eq!(input.attr("aria-invalid"), None);
+        assert!(!input.has_class("is-danger"));
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::testing::{Queries, input_text, key_down, mount};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// The values emitted through `update`.
+    type Updates = Rc<RefCell<Vec<Option<i32>>>>;
+
+    fn updates() -> (Updates, Callback<Option<i32>>) {
+        let updates = Rc::new(RefCell::new(Vec::new()));
+        let update = Callback::from({
+            let updates = updates.clone();
+            move |value| updates.borrow_mut().push(value)
+        });
+        (updates, update)
+    }
+
+    #[wasm_bindgen_test]
+    async fn bad_text_is_marked_invalid() {
+        let (updates, update) = updates();
+        let mounted = mount(html! { <NumberInput<i32> name="count" value={Some(3)} {update} /> }).await;
+        let input = mounted.get_by_role("textbox");
+
+        input_text(&input, "3.5").await;
+        assert!(input.has_class("is-danger"));
+        assert_eq!(input.attr("aria-invalid").as_deref(), Some("true"));
+        assert!(!input.unchecked_ref::<HtmlInputElement>().validation_message().unwrap().is_empty());
+
+        input_text(&input, "4").await;
+        assert!(!input.has_class("is-danger"));
+        assert_eq!(input.attr("aria-invalid"), None);
+        assert_eq!(*updates.borrow(), [None, Some(4)]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_arrow_keys_step_unless_read_only() {
+        let (updates, update) = updates();
+        let mounted = mount(html! {
+            <>
+                <NumberInput<i32> name="editable" value={Some(3)} step={Some(2)} update={update.clone()} />
+                <NumberInput<i32> name="fixed" value={Some(3)} step={Some(2)} {update} readonly=true />
+            </>
+        })
+        .await;
+        let input = |name: &str| mounted.find_all(|element| element.attr("name").as_deref() == Some(name)).remove(0);
+
+        assert!(key_down(&input("editable"), "ArrowUp").await);
+        assert_eq!(input("editable").unchecked_ref::<HtmlInputElement>().value(), "5");
+        assert!(!key_down(&input("fixed"), "ArrowUp").await);
+        assert!(!key_down(&input("fixed"), "ArrowDown").await);
+        assert_eq!(input("fixed").unchecked_ref::<HtmlInputElement>().value(), "3");
+        assert_eq!(*updates.borrow(), [Some(5)]);
+    }
+}
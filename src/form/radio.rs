@@ -2,6 +2,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct RadioProps {
     /// The `name` attribute for this form element.
@@ -21,6 +23,9 @@ pub struct RadioProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
@@ -40,7 +45,7 @@ pub fn radio(props: &RadioProps) -> Html {
         let input: HtmlInputElement = ev.target_dyn_into().expect_throw("event target should be an input");
         input.value()
     });
-    html! {
+    props.attrs.apply(html! {
         <label {class} disabled={props.disabled}>
             <input
                 type="radio"
@@ -52,5 +57,5 @@ pub fn radio(props: &RadioProps) -> Html {
                 />
             {props.children.clone()}
         </label>
-    }
+    })
 }
@@ -1,8 +1,13 @@
-use wasm_bindgen::UnwrapThrowExt;
-use web_sys::HtmlSelectElement;
+use std::marker::PhantomData;
+
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+use web_sys::{HtmlInputElement, HtmlOptionElement, HtmlSelectElement};
 use yew::prelude::*;
+use yew::virtual_dom::VNode;
 
-use crate::Size;
+use crate::common::inject_style;
+use crate::id::next_component_id;
+use crate::{Delete, ExtraAttrs, Size, Tag, Tags};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct SelectProps {
@@ -28,6 +33,9 @@ pub struct SelectProps {
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A wrapper around an HTML `select` tag.
@@ -38,6 +46,8 @@ pub struct SelectProps {
 /// be provided from a parent component, and changes to this component are propagated to the parent
 /// component via callback.
 ///
+/// For a searchable list of typed options, see [`Combobox`].
+///
 /// **NOTE WELL:** not all browsers will honor the value of the select element's value on initial
 /// load. So if you have an initial `value` set for this component, ensure that the corresponding
 /// option element also has the `selected=true` attribute.
@@ -53,7 +63,7 @@ pub fn select(props: &SelectProps) -> Html {
         let select: HtmlSelectElement = ev.target_dyn_into().expect_throw("event target should be a select");
         select.value()
     });
-    html! {
+    props.attrs.apply(html! {
         <div {class}>
             <select
                 name={props.name.clone()}
@@ -64,7 +74,7 @@ pub fn select(props: &SelectProps) -> Html {
                 {props.children.clone()}
             </select>
         </div>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -97,6 +107,18 @@ pub struct MultiSelectProps {
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// Render the selected `option` children as removable tags, followed by a select for adding the others.
+    #[prop_or_default]
+    pub chips: bool,
+    /// The placeholder option of the select adding options in `chips` mode.
+    #[prop_or_else(|| "Add...".into())]
+    pub add_label: AttrValue,
+    /// The `aria-label` of the buttons removing a chip, followed by its label.
+    #[prop_or_else(|| "Remove".into())]
+    pub remove_label: AttrValue,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A wrapper around an HTML `select` tag with the `multiple=true` attribute.
@@ -107,11 +129,54 @@ pub struct MultiSelectProps {
 /// be provided from a parent component, and changes to this component are propagated to the parent
 /// component via callback.
 ///
-/// **NOTE WELL:** not all browsers will honor the value of the select element's value on initial
-/// load. So if you have an initial `value` set for this component, ensure that the corresponding
-/// option element also has the `selected=true` attribute.
-#[component(MultiSelect)]
-pub fn multi_select(props: &MultiSelectProps) -> Html {
+/// With `chips`, the selected `option` children are shown as removable tags, followed by a select offering the others.
+/// For a searchable list of typed options, see [`MultiCombobox`].
+///
+/// The options whose `value` attribute (or text, without one) is contained in `value` are selected after every
+/// render, so the `option` children need no `selected` attributes.
+pub struct MultiSelect {
+    select_ref: NodeRef,
+}
+
+impl Component for MultiSelect {
+    type Message = ();
+    type Properties = MultiSelectProps;
+
+    fn create(_: &Context<Self>) -> Self {
+        Self {
+            select_ref: NodeRef::default(),
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        // The DOM ignores a `value` on `select multiple`, and an option's `selected` attribute once the user has
+        // toggled it, so set the `selected` property of every option instead.
+        let props = ctx.props();
+        let Some(select) = self.select_ref.cast::<HtmlSelectElement>() else {
+            return;
+        };
+        if props.chips {
+            select.set_value("");
+            return;
+        }
+        for index in 0..select.length() {
+            if let Some(option) = select.item(index).and_then(|option| option.dyn_into::<HtmlOptionElement>().ok()) {
+                option.set_selected(props.value.contains(&option.value()));
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        props.attrs.apply(if props.chips {
+            chips_multi_select(props, &self.select_ref)
+        } else {
+            native_multi_select(props, &self.select_ref)
+        })
+    }
+}
+
+fn native_multi_select(props: &MultiSelectProps, select_ref: &NodeRef) -> Html {
     let class = classes!(
         "select",
         "is-multiple",
@@ -134,8 +199,8 @@ pub fn multi_select(props: &MultiSelectProps) -> Html {
                 multiple=true
                 size={size}
                 name={props.name.clone()}
-                value={props.value.join(",")}
                 disabled={props.disabled}
+                ref={select_ref.clone()}
                 {onchange}
             >
                 {props.children.clone()}
@@ -143,3 +208,1078 @@ pub fn multi_select(props: &MultiSelectProps) -> Html {
         </div>
     }
 }
+
+fn chips_multi_select(props: &MultiSelectProps, select_ref: &NodeRef) -> Html {
+    let mut options = Vec::new();
+    collect_options(&props.children.iter().collect::<Vec<_>>(), &mut options);
+    // The selection in the order of the options, so that chips do not move around.
+    let selected: Vec<&(String, String)> = options.iter().filter(|(value, _)| props.value.contains(value)).collect();
+
+    let chips = selected
+        .iter()
+        .map(|(value, label)| {
+            let onclick = {
+                let value = value.clone();
+                let selected: Vec<String> = selected.iter().map(|(value, _)| value.clone()).collect();
+                props
+                    .update
+                    .reform(move |_: MouseEvent| selected.iter().filter(|other| **other != value).cloned().collect())
+            };
+            html! {
+                <Tag>
+                    {label.clone()}
+                    if !props.disabled {
+                        <Delete classes={classes!("is-small")} aria_label={format!("{} {}", props.remove_label, label)} {onclick} />
+                    }
+                </Tag>
+            }
+        })
+        .collect::<Html>();
+
+    let onchange = {
+        let values: Vec<String> = options.iter().map(|(value, _)| value.clone()).collect();
+        let current = props.value.clone();
+        let update = props.update.clone();
+        Callback::from(move |ev: web_sys::Event| {
+            let select: HtmlSelectElement = ev.target_dyn_into().expect_throw("event target should be a select");
+            let added = select.value();
+            if !added.is_empty() {
+                update.emit(
+                    values
+                        .iter()
+                        .filter(|value| current.contains(value) || **value == added)
+                        .cloned()
+                        .collect(),
+                );
+            }
+        })
+    };
+    let class = classes!(
+        "select",
+        props.size.as_ref().map(|size| size.to_string()),
+        props.loading.then_some("is-loading"),
+    );
+    let remaining = options.iter().filter(|(value, _)| !props.value.contains(value));
+    html! {
+        <div class={classes!("ybc-multi-select-chips", props.classes.clone())}>
+            if !selected.is_empty() {
+                <Tags>{chips}</Tags>
+            }
+            <div {class}>
+                <select
+                    name={props.name.clone()}
+                    aria-label={props.add_label.clone()}
+                    disabled={props.disabled}
+                    ref={select_ref.clone()}
+                    {onchange}
+                >
+                    <option value="" selected=true>{props.add_label.clone()}</option>
+                    { for remaining.map(|(value, label)| html! { <option value={value.clone()}>{label.clone()}</option> }) }
+                </select>
+            </div>
+        </div>
+    }
+}
+
+/// Collect the value and label of the `option` tags among `nodes`, including those inside `optgroup`s.
+fn collect_options(nodes: &[VNode], options: &mut Vec<(String, String)>) {
+    for node in nodes {
+        match node {
+            VNode::VTag(tag) if tag.tag() == "option" => {
+                let mut label = String::new();
+                if let Some(children) = tag.children() {
+                    collect_text(children, &mut label);
+                }
+                let value = tag
+                    .attributes
+                    .iter()
+                    .find(|(name, _)| *name == "value")
+                    .map_or_else(|| label.clone(), |(_, value)| value.to_owned());
+                options.push((value, label));
+            }
+            VNode::VTag(tag) => {
+                if let Some(children) = tag.children() {
+                    collect_options(std::slice::from_ref(children), options);
+                }
+            }
+            VNode::VList(list) => collect_options(list, options),
+            _ => {}
+        }
+    }
+}
+
+fn collect_text(node: &VNode, text: &mut String) {
+    match node {
+        VNode::VText(vtext) => text.push_str(&vtext.text),
+        VNode::VList(list) => list.iter().for_each(|child| collect_text(child, text)),
+        _ => {}
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct ComboboxProps<T: Clone + PartialEq + 'static> {
+    /// The `name` attribute of the filter input.
+    pub name: String,
+    /// The typed options and their labels.
+    pub options: Vec<(T, AttrValue)>,
+    /// The controlled selection.
+    pub selected: Option<T>,
+    /// Receives the chosen option, or `None` when the selection is cleared.
+    pub on_select: Callback<Option<T>>,
+
+    /// Groups the options under the returned headings.
+    #[prop_or_default]
+    pub option_group: Option<Callback<T, AttrValue>>,
+    /// Show a button which clears the selection.
+    #[prop_or_default]
+    pub clearable: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Display a loading spinner within this component.
+    #[prop_or_default]
+    pub loading: bool,
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// The placeholder while nothing is selected.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+    /// The text shown when no option matches the typed filter.
+    #[prop_or_else(|| "No results".into())]
+    pub no_results: AttrValue,
+    /// The `aria-label` of the clear button.
+    #[prop_or_else(|| "Clear".into())]
+    pub clear_label: AttrValue,
+    /// The `id` of the filter input. Generated automatically when empty.
+    #[prop_or_default]
+    pub id: AttrValue,
+    /// The `aria-label` of the filter input.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// A searchable select of typed options, rendered without a native `select`.
+///
+/// [https://bulma.io/documentation/components/dropdown/](https://bulma.io/documentation/components/dropdown/)
+///
+/// Like all YBC form components, it is controlled: the selection is given through `selected`, and changes are
+/// propagated through `on_select`.
+///
+/// Typing filters the options, the arrow keys, Home and End move through them, Enter chooses one and Escape closes
+/// the list. The input has the ARIA `combobox` role and controls a `listbox`.
+#[component(Combobox)]
+pub fn combobox<T: Clone + PartialEq + 'static>(props: &ComboboxProps<T>) -> Html {
+    let on_change = props.on_select.reform(|selected: Vec<T>| selected.into_iter().next());
+    html! {
+        <ComboboxBase<T>
+            id={props.id.clone()}
+            name={props.name.clone()}
+            options={props.options.clone()}
+            option_group={props.option_group.clone()}
+            selected={props.selected.iter().cloned().collect::<Vec<_>>()}
+            {on_change}
+            multiple=false
+            clearable={props.clearable}
+            classes={props.classes.clone()}
+            size={props.size.clone()}
+            loading={props.loading}
+            disabled={props.disabled}
+            placeholder={props.placeholder.clone()}
+            no_results={props.no_results.clone()}
+            clear_label={props.clear_label.clone()}
+            aria_label={props.aria_label.clone()}
+            attrs={props.attrs.clone()}
+        />
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct MultiComboboxProps<T: Clone + PartialEq + 'static> {
+    /// The `name` attribute of the filter input.
+    pub name: String,
+    /// The typed options and their labels.
+    pub options: Vec<(T, AttrValue)>,
+    /// The controlled selection.
+    pub selected: Vec<T>,
+    /// Receives the selected options in the order of `options` whenever the selection changes.
+    pub on_select: Callback<Vec<T>>,
+
+    /// Groups the options under the returned headings.
+    #[prop_or_default]
+    pub option_group: Option<Callback<T, AttrValue>>,
+    /// Show a button which clears the selection.
+    #[prop_or_default]
+    pub clearable: bool,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The size of this component.
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Display a loading spinner within this component.
+    #[prop_or_default]
+    pub loading: bool,
+    /// Disable this component.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// The placeholder while nothing is selected.
+    #[prop_or_default]
+    pub placeholder: AttrValue,
+    /// The text shown when no option matches the typed filter.
+    #[prop_or_else(|| "No results".into())]
+    pub no_results: AttrValue,
+    /// The `aria-label` of the clear button.
+    #[prop_or_else(|| "Clear".into())]
+    pub clear_label: AttrValue,
+    /// The `aria-label` of the buttons removing a selected option, followed by its label.
+    #[prop_or_else(|| "Remove".into())]
+    pub remove_label: AttrValue,
+    /// The `id` of the filter input. Generated automatically when empty.
+    #[prop_or_default]
+    pub id: AttrValue,
+    /// The `aria-label` of the filter input.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// A searchable multi-select of typed options, rendered without a native `select`.
+///
+/// Works like [`Combobox`]; the selected options are shown as removable tags in front of the filter input. Choosing
+/// an option in the list adds or removes it, and Backspace in the empty filter removes the last one.
+#[component(MultiCombobox)]
+pub fn multi_combobox<T: Clone + PartialEq + 'static>(props: &MultiComboboxProps<T>) -> Html {
+    html! {
+        <ComboboxBase<T>
+            id={props.id.clone()}
+            name={props.name.clone()}
+            options={props.options.clone()}
+            option_group={props.option_group.clone()}
+            selected={props.selected.clone()}
+            on_change={props.on_select.clone()}
+            multiple=true
+            clearable={props.clearable}
+            classes={props.classes.clone()}
+            size={props.size.clone()}
+            loading={props.loading}
+            disabled={props.disabled}
+            placeholder={props.placeholder.clone()}
+            no_results={props.no_results.clone()}
+            clear_label={props.clear_label.clone()}
+            remove_label={props.remove_label.clone()}
+            aria_label={props.aria_label.clone()}
+            attrs={props.attrs.clone()}
+        />
+    }
+}
+
+const COMBOBOX_STYLE: &str = r#"
+.dropdown.ybc-combobox {
+    display: flex;
+    width: 100%;
+}
+
+.ybc-combobox .dropdown-trigger,
+.ybc-combobox .dropdown-trigger > .control,
+.ybc-combobox .dropdown-menu {
+    width: 100%;
+}
+
+.ybc-combobox .dropdown-content {
+    max-height: 16rem;
+    overflow-y: auto;
+}
+
+.ybc-combobox-input.input {
+    flex-wrap: wrap;
+    gap: 0.25rem;
+    height: auto;
+    min-height: 2.5em;
+    cursor: text;
+}
+
+.ybc-combobox-input.input[aria-disabled="true"] {
+    background-color: var(--bulma-input-disabled-background-color);
+    border-color: var(--bulma-input-disabled-border-color);
+    box-shadow: none;
+    color: var(--bulma-input-disabled-color);
+    cursor: not-allowed;
+}
+
+.ybc-combobox-input .tag {
+    margin: 0;
+}
+
+.ybc-combobox-field {
+    flex: 1 1 6em;
+    min-width: 6em;
+    border: 0;
+    outline: 0;
+    box-shadow: none;
+    background: transparent;
+    color: inherit;
+    font: inherit;
+    padding: 0;
+}
+
+.ybc-combobox-group {
+    font-size: 0.75em;
+    font-weight: 600;
+    text-transform: uppercase;
+    opacity: 0.7;
+}
+
+.ybc-combobox .dropdown-item.is-selected:not(.is-active) {
+    font-weight: 600;
+}
+"#;
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct ComboboxBaseProps<T: Clone + PartialEq + 'static> {
+    id: AttrValue,
+    name: String,
+    options: Vec<(T, AttrValue)>,
+    option_group: Option<Callback<T, AttrValue>>,
+    selected: Vec<T>,
+    on_change: Callback<Vec<T>>,
+    multiple: bool,
+    clearable: bool,
+    classes: Classes,
+    size: Option<Size>,
+    loading: bool,
+    disabled: bool,
+    placeholder: AttrValue,
+    no_results: AttrValue,
+    clear_label: AttrValue,
+    #[prop_or_default]
+    remove_label: AttrValue,
+    aria_label: AttrValue,
+    attrs: ExtraAttrs,
+}
+
+/// The options matching the filter, as indexes into `options`, grouped under their headings in order of appearance.
+type OptionGroups = Vec<(Option<AttrValue>, Vec<usize>)>;
+
+enum ComboboxMsg {
+    Input(String),
+    KeyDown(KeyboardEvent),
+    Focus,
+    Blur,
+    /// A click anywhere on the input box.
+    Activate,
+    Highlight(usize),
+    Choose(usize),
+    Remove(usize),
+    Clear,
+}
+
+/// The searchable list behind [`Combobox`] and [`MultiCombobox`].
+struct ComboboxBase<T: Clone + PartialEq + 'static> {
+    id: AttrValue,
+    input_ref: NodeRef,
+    query: String,
+    open: bool,
+    /// The highlighted option, as an index into the filtered options.
+    highlighted: Option<usize>,
+    item: PhantomData<T>,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for ComboboxBase<T> {
+    type Message = ComboboxMsg;
+    type Properties = ComboboxBaseProps<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let id = ctx.props().id.clone();
+        Self {
+            id: if id.is_empty() { next_component_id(ctx, "select") } else { id },
+            input_ref: NodeRef::default(),
+            query: String::new(),
+            open: false,
+            highlighted: None,
+            item: PhantomData,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.id != old_props.id && !props.id.is_empty() {
+            self.id = props.id.clone();
+        }
+        if props.options != old_props.options {
+            self.highlighted = None;
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            ComboboxMsg::Input(query) => {
+                self.query = query;
+                self.open = true;
+                self.highlighted = (!self.visible(props).is_empty()).then_some(0);
+            }
+            ComboboxMsg::KeyDown(event) => return self.on_key_down(ctx, event),
+            ComboboxMsg::Focus => self.open(props),
+            ComboboxMsg::Blur => self.close(),
+            ComboboxMsg::Activate if self.open => return false,
+            ComboboxMsg::Activate => {
+                self.open(props);
+                if let Some(input) = self.input_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+            }
+            ComboboxMsg::Highlight(position) => self.highlighted = Some(position),
+            ComboboxMsg::Choose(index) => self.choose(ctx, index),
+            ComboboxMsg::Remove(index) => {
+                // The index is from the render the click landed on; ignore it if the options changed since.
+                let Some((removed, _)) = props.options.get(index) else { return false };
+                props.on_change.emit(self.selection(props, |item| item != removed));
+            }
+            ComboboxMsg::Clear => {
+                self.query.clear();
+                props.on_change.emit(Vec::new());
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let listbox_id = format!("{}-listbox", self.id);
+        let groups = self.groups(props);
+        let is_expanded = self.open && !props.disabled;
+        let visible: Vec<usize> = groups.iter().flat_map(|(_, indexes)| indexes.iter().copied()).collect();
+        let active_descendant = self
+            .highlighted
+            .filter(|_| is_expanded)
+            .and_then(|position| visible.get(position))
+            .map(|index| format!("{}-option-{}", self.id, index));
+
+        let tags = if props.multiple {
+            props
+                .options
+                .iter()
+                .enumerate()
+                .filter(|(_, (item, _))| props.selected.contains(item))
+                .map(|(index, (_, label))| {
+                    let label = label.clone();
+                    let onclick = link.callback(move |event: MouseEvent| {
+                        event.stop_propagation();
+                        ComboboxMsg::Remove(index)
+                    });
+                    let aria_label = format!("{} {}", props.remove_label, label);
+                    html! {
+                        <Tag>
+                            {label}
+                            if !props.disabled {
+                                <Delete classes={classes!("is-small")} {aria_label} {onclick} />
+                            }
+                        </Tag>
+                    }
+                })
+                .collect::<Html>()
+        } else {
+            html! {}
+        };
+
+        let mut position = 0;
+        let options = groups
+            .iter()
+            .enumerate()
+            .map(|(group_index, (heading, indexes))| {
+                let items = indexes
+                    .iter()
+                    .filter_map(|&index| props.options.get(index).map(|option| (index, option)))
+                    .map(|(index, (item, label))| {
+                        let current = position;
+                        position += 1;
+                        let is_highlighted = self.highlighted == Some(current);
+                        let is_selected = props.selected.contains(item);
+                        let onmousedown = link.callback(move |event: MouseEvent| {
+                            // Keep the focus on the input so the list does not close before the click lands.
+                            event.prevent_default();
+                            ComboboxMsg::Choose(index)
+                        });
+                        let onmouseenter = link.callback(move |_: MouseEvent| ComboboxMsg::Highlight(current));
+                        html! {
+                            <a
+                                id={format!("{}-option-{}", self.id, index)}
+                                class={classes!("dropdown-item", is_highlighted.then_some("is-active"), is_selected.then_some("is-selected"))}
+                                role="option"
+                                aria-selected={if is_selected { "true" } else { "false" }}
+                                {onmousedown}
+                                {onmouseenter}
+                            >
+                                {label.clone()}
+                            </a>
+                        }
+                    })
+                    .collect::<Html>();
+                match heading {
+                    Some(heading) => {
+                        let heading_id = format!("{}-group-{}", self.id, group_index);
+                        html! {
+                            <div role="group" aria-labelledby={heading_id.clone()}>
+                                <p id={heading_id} class="dropdown-item ybc-combobox-group" role="presentation">{heading.clone()}</p>
+                                {items}
+                            </div>
+                        }
+                    }
+                    None => items,
+                }
+            })
+            .collect::<Html>();
+        let options = if visible.is_empty() {
+            html! { <div class="dropdown-item">{props.no_results.clone()}</div> }
+        } else {
+            options
+        };
+
+        let selected_label = (!props.multiple)
+            .then(|| {
+                props
+                    .options
+                    .iter()
+                    .find(|(item, _)| props.selected.contains(item))
+                    .map(|(_, label)| label.clone())
+            })
+            .flatten();
+        // A single select shows its selection while closed, and offers it as the placeholder while filtering.
+        let value = match &selected_label {
+            Some(label) if !self.open => label.to_string(),
+            _ => self.query.clone(),
+        };
+        let placeholder = match &selected_label {
+            Some(label) => Some(label.clone()),
+            None if props.selected.is_empty() => Some(props.placeholder.clone()),
+            None => None,
+        }
+        .filter(|placeholder| !placeholder.is_empty());
+
+        let clear = if props.clearable && !props.selected.is_empty() && !props.disabled {
+            let onclick = link.callback(|event: MouseEvent| {
+                event.stop_propagation();
+                ComboboxMsg::Clear
+            });
+            html! { <Delete classes={classes!("is-small", "ybc-combobox-clear")} aria_label={props.clear_label.clone()} {onclick} /> }
+        } else {
+            html! {}
+        };
+
+        let onclick = link.callback(|_: MouseEvent| ComboboxMsg::Activate);
+        let oninput = link.callback(|event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            ComboboxMsg::Input(input.value())
+        });
+        let onkeydown = link.callback(ComboboxMsg::KeyDown);
+        let onfocus = link.callback(|_: FocusEvent| ComboboxMsg::Focus);
+        let onblur = link.callback(|_: FocusEvent| ComboboxMsg::Blur);
+        let input_class = classes!(
+            "input",
+            "ybc-combobox-input",
+            props.classes.clone(),
+            props.size.as_ref().map(|size| size.to_string()),
+        );
+        let non_empty = |value: &AttrValue| (!value.is_empty()).then(|| value.clone());
+
+        props.attrs.apply(html! {
+            <div class={classes!("dropdown", "ybc-combobox", is_expanded.then_some("is-active"))}>
+                <div class="dropdown-trigger">
+                    <div class={classes!("control", props.loading.then_some("is-loading"))}>
+                        <div class={input_class} aria-disabled={props.disabled.then_some("true")} {onclick}>
+                            {tags}
+                            <input
+                                id={self.id.clone()}
+                                ref={self.input_ref.clone()}
+                                name={props.name.clone()}
+                                class="ybc-combobox-field"
+                                type="text"
+                                autocomplete="off"
+                                role="combobox"
+                                aria-autocomplete="list"
+                                aria-haspopup="listbox"
+                                aria-expanded={if is_expanded { "true" } else { "false" }}
+                                aria-controls={listbox_id.clone()}
+                                aria-activedescendant={active_descendant}
+                                aria-label={non_empty(&props.aria_label)}
+                                disabled={props.disabled}
+                                {value}
+                                {placeholder}
+                                {oninput}
+                                {onkeydown}
+                                {onfocus}
+                                {onblur}
+                            />
+                            {clear}
+                        </div>
+                    </div>
+                </div>
+                <div class="dropdown-menu">
+                    <div
+                        class="dropdown-content"
+                        id={listbox_id}
+                        role="listbox"
+                        aria-multiselectable={props.multiple.then_some("true")}
+                    >
+                        {options}
+                    </div>
+                </div>
+            </div>
+        })
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            inject_style("ybc-combobox-style", COMBOBOX_STYLE);
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> ComboboxBase<T> {
+    fn groups(&self, props: &ComboboxBaseProps<T>) -> OptionGroups {
+        let query = self.query.trim().to_lowercase();
+        let mut groups: OptionGroups = Vec::new();
+        for (index, (item, label)) in props.options.iter().enumerate() {
+            if !label.to_lowercase().contains(&query) {
+                continue;
+            }
+            let heading = props.option_group.as_ref().map(|group| group.emit(item.clone()));
+            match groups.iter_mut().find(|(existing, _)| *existing == heading) {
+                Some((_, indexes)) => indexes.push(index),
+                None => groups.push((heading, vec![index])),
+            }
+        }
+        groups
+    }
+
+    /// The filtered options in display order.
+    fn visible(&self, props: &ComboboxBaseProps<T>) -> Vec<usize> {
+        self.groups(props).into_iter().flat_map(|(_, indexes)| indexes).collect()
+    }
+
+    fn selected_indexes(&self, props: &ComboboxBaseProps<T>) -> Vec<usize> {
+        props
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, (item, _))| props.selected.contains(item))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The options in `options` order which pass `keep` among the selected ones.
+    fn selection(&self, props: &ComboboxBaseProps<T>, keep: impl Fn(&T) -> bool) -> Vec<T> {
+        props
+            .options
+            .iter()
+            .map(|(item, _)| item)
+            .filter(|item| props.selected.contains(item) && keep(item))
+            .cloned()
+            .collect()
+    }
+
+    fn open(&mut self, props: &ComboboxBaseProps<T>) {
+        if props.disabled {
+            return;
+        }
+        self.open = true;
+        self.query.clear();
+        let visible = self.visible(props);
+        let selected = visible
+            .iter()
+            .position(|index| props.options.get(*index).is_some_and(|(item, _)| props.selected.contains(item)));
+        self.highlighted = selected.or((!visible.is_empty()).then_some(0));
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+        self.query.clear();
+        self.highlighted = None;
+    }
+
+    fn choose(&mut self, ctx: &Context<Self>, index: usize) {
+        let props = ctx.props();
+        let Some((item, _)) = props.options.get(index) else { return };
+        if !props.multiple {
+            props.on_change.emit(vec![item.clone()]);
+            self.close();
+            return;
+        }
+        let selection = if props.selected.contains(item) {
+            self.selection(props, |selected| selected != item)
+        } else {
+            let mut selection = self.selection(props, |_| true);
+            selection.insert(
+                self.selected_indexes(props).iter().filter(|selected| **selected < index).count(),
+                item.clone(),
+            );
+            selection
+        };
+        props.on_change.emit(selection);
+        if !self.query.is_empty() {
+            self.query.clear();
+            self.highlighted = self.visible(props).iter().position(|visible| *visible == index);
+        }
+    }
+
+    fn on_key_down(&mut self, ctx: &Context<Self>, event: KeyboardEvent) -> bool {
+        let props = ctx.props();
+        let visible = self.visible(props);
+        let count = visible.len();
+        match event.key().as_str() {
+            "ArrowDown" | "ArrowUp" if !self.open => {
+                event.prevent_default();
+                self.open(props);
+            }
+            "ArrowDown" if count > 0 => {
+                event.prevent_default();
+                self.highlighted = Some(self.highlighted.map(|position| (position + 1) % count).unwrap_or(0));
+            }
+            "ArrowUp" if count > 0 => {
+                event.prevent_default();
+                self.highlighted = Some(self.highlighted.map(|position| (position + count - 1) % count).unwrap_or(count - 1));
+            }
+            "Home" if self.open && count > 0 => {
+                event.prevent_default();
+                self.highlighted = Some(0);
+            }
+            "End" if self.open && count > 0 => {
+                event.prevent_default();
+                self.highlighted = Some(count - 1);
+            }
+            "Enter" if self.open => {
+                event.prevent_default();
+                if let Some(index) = self.highlighted.and_then(|position| visible.get(position)) {
+                    self.choose(ctx, *index);
+                }
+            }
+            "Escape" if self.open => {
+                event.prevent_default();
+                self.close();
+            }
+            "Backspace" if props.multiple && self.query.is_empty() => {
+                let mut selection = self.selection(props, |_| true);
+                if selection.pop().is_none() {
+                    return false;
+                }
+                props.on_change.emit(selection);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use yew::prelude::*;
+
+    use super::collect_options;
+    use crate::testing::{Queries, render_blocking};
+    use crate::{Combobox, MultiCombobox, MultiSelect, Select};
+
+    #[test]
+    fn options_are_collected_from_optgroups_with_their_text_as_fallback_value() {
+        let children = html! {
+            <>
+                <option value="apple">{"Apple"}</option>
+                <optgroup label="Citrus">
+                    <option>{"Lemon"}</option>
+                    <option value="orange">{"Orange "}{"(blood)"}</option>
+                </optgroup>
+                <option>{"Pear"}</option>
+            </>
+        };
+        let mut options = Vec::new();
+        collect_options(&[children], &mut options);
+        let options: Vec<_> = options.iter().map(|(value, label)| (value.as_str(), label.as_str())).collect();
+        assert_eq!(
+            options,
+            [("apple", "Apple"), ("Lemon", "Lemon"), ("orange", "Orange (blood)"), ("Pear", "Pear")]
+        );
+    }
+
+    fn fruits() -> Html {
+        html! {
+            <>
+                <option value="apple">{"Apple"}</option>
+                <optgroup label="Citrus">
+                    <option>{"Lemon"}</option>
+                    <option value="orange">{"Orange"}</option>
+                </optgroup>
+                <option value="pear">{"Pear"}</option>
+            </>
+        }
+    }
+
+    #[test]
+    fn chips_show_the_selection_and_the_select_offers_the_rest() {
+        let rendered = render_blocking(|| {
+            html! {
+                <MultiSelect name="fruit" value={vec!["pear".to_string(), "Lemon".to_string()]} update={Callback::noop()} chips=true>
+                    {fruits()}
+                </MultiSelect>
+            }
+        });
+        // The chips follow the order of the options, not of `value`.
+        let chips: Vec<_> = rendered.find_all(|element| element.has_class("tag")).iter().map(Queries::text).collect();
+        assert_eq!(chips, ["Lemon", "Pear"]);
+        assert!(rendered.query_by_role_and_name("button", "Remove Lemon").is_some());
+        assert!(rendered.query_by_role_and_name("button", "Remove Pear").is_some());
+
+        let select = rendered.get_by_role_and_name("combobox", "Add...");
+        assert_eq!(select.attr("multiple"), None);
+        let remaining: Vec<_> = select
+            .children()
+            .iter()
+            .map(|option| (option.attr("value").unwrap_or_default(), option.text()))
+            .collect();
+        assert_eq!(
+            remaining,
+            [
+                (String::new(), "Add...".to_string()),
+                ("apple".to_string(), "Apple".to_string()),
+                ("orange".to_string(), "Orange".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn disabled_chips_cannot_be_removed() {
+        let rendered = render_blocking(|| {
+            html! {
+                <MultiSelect name="fruit" value={vec!["apple".to_string()]} update={Callback::noop()} chips=true disabled=true>
+                    {fruits()}
+                </MultiSelect>
+            }
+        });
+        assert_eq!(rendered.find_all(|element| element.has_class("tag")).len(), 1);
+        assert!(rendered.find_all(|element| element.has_class("delete")).is_empty());
+        assert!(rendered.get_by_role("combobox").attr("disabled").is_some());
+    }
+
+    #[test]
+    fn comboboxes_render_a_filter_input_even_without_options() {
+        let native = render_blocking(|| html! { <Select name="country" value="" update={Callback::noop()} /> });
+        assert_eq!(native.get_by_role("combobox").tag(), "select");
+
+        let combobox = render_blocking(|| {
+            html! { <Combobox<u8> name="country" options={vec![]} selected={None} on_select={Callback::noop()} /> }
+        });
+        let input = combobox.get_by_role("combobox");
+        assert_eq!(input.tag(), "input");
+        assert_eq!(input.attr("name").as_deref(), Some("country"));
+
+        let multiple = render_blocking(|| {
+            html! { <MultiCombobox<u8> name="countries" options={vec![]} selected={vec![]} on_select={Callback::noop()} /> }
+        });
+        assert_eq!(multiple.get_by_role("combobox").tag(), "input");
+        assert_eq!(multiple.get_by_role("listbox").attr("aria-multiselectable").as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn comboboxes_show_the_selection() {
+        let options = || vec![(1, AttrValue::from("Small")), (2, AttrValue::from("Large"))];
+        let single = render_blocking(move || {
+            html! { <Combobox<u8> name="size" options={options()} selected={Some(2)} on_select={Callback::noop()} /> }
+        });
+        assert_eq!(single.get_by_role("combobox").attr("value").as_deref(), Some("Large"));
+        let chosen = single.get_by_role_and_name("option", "Large");
+        assert_eq!(chosen.attr("aria-selected").as_deref(), Some("true"));
+
+        let multiple = render_blocking(move || {
+            html! { <MultiCombobox<u8> name="sizes" options={options()} selected={vec![2, 1]} on_select={Callback::noop()} /> }
+        });
+        let tags: Vec<_> = multiple.find_all(|element| element.has_class("tag")).iter().map(Queries::text).collect();
+        assert_eq!(tags, ["Small", "Large"]);
+        assert!(multiple.query_by_role_and_name("button", "Remove Small").is_some());
+    }
+
+    #[test]
+    fn disabled_comboboxes_mark_their_wrapper_with_aria_disabled() {
+        let rendered = render_blocking(|| {
+            html! {
+                <Combobox<u8> name="size" options={vec![(1, AttrValue::from("Small"))]} selected={None}
+                    on_select={Callback::noop()} disabled=true />
+            }
+        });
+        let wrapper = rendered.find_all(|element| element.has_class("ybc-combobox-input")).remove(0);
+        assert_eq!(wrapper.attr("aria-disabled").as_deref(), Some("true"));
+        assert_eq!(wrapper.attr("disabled"), None);
+        assert!(rendered.get_by_role("combobox").attr("disabled").is_some());
+    }
+
+    #[test]
+    fn grouped_options_are_listed_under_their_headings_in_order_of_appearance() {
+        let rendered = render_blocking(|| {
+            let options = vec![
+                (1, AttrValue::from("Apple")),
+                (2, AttrValue::from("Carrot")),
+                (3, AttrValue::from("Banana")),
+            ];
+            let option_group = Callback::from(|id: u8| AttrValue::from(if id % 2 == 1 { "Fruit" } else { "Vegetable" }));
+            html! {
+                <Combobox<u8> id="food" name="food" {options} {option_group} selected={None} on_select={Callback::noop()} />
+            }
+        });
+        let groups: Vec<_> = rendered
+            .get_all_by_role("group")
+            .iter()
+            .map(|group| {
+                let heading = group.attr("aria-labelledby").unwrap_or_default();
+                let heading = rendered.find_all(|element| element.attr("id").as_ref() == Some(&heading)).remove(0);
+                let options: Vec<_> = group
+                    .get_all_by_role("option")
+                    .iter()
+                    .map(|option| option.attr("id").unwrap_or_default())
+                    .collect();
+                (heading.text(), options)
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ("Fruit".to_string(), vec!["food-option-0".to_string(), "food-option-2".to_string()]),
+                ("Vegetable".to_string(), vec!["food-option-1".to_string()]),
+            ]
+        );
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::Element;
+
+    use super::*;
+    use crate::testing::{Queries, input_text, key_down, mount};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn fruits() -> Vec<(u8, AttrValue)> {
+        vec![(1, "Apple".into()), (2, "Banana".into()), (3, "Cherry".into())]
+    }
+
+    /// A controlled [`Combobox`] of [`fruits`] which prints its selection.
+    #[component]
+    fn Single() -> Html {
+        let selected = use_state(|| None::<u8>);
+        let on_select = Callback::from({
+            let selected = selected.clone();
+            move |item| selected.set(item)
+        });
+        html! {
+            <>
+                <Combobox<u8> id="fruit" name="fruit" options={fruits()} selected={*selected} {on_select} />
+                <output>{format!("{:?}", *selected)}</output>
+            </>
+        }
+    }
+
+    /// A controlled [`MultiCombobox`] of [`fruits`] which prints its selection.
+    #[component]
+    fn Multi() -> Html {
+        let selected = use_state(|| vec![3]);
+        let on_select = Callback::from({
+            let selected = selected.clone();
+            move |items| selected.set(items)
+        });
+        html! {
+            <>
+                <MultiCombobox<u8> id="fruit" name="fruit" options={fruits()} selected={(*selected).clone()} {on_select} />
+                <output>{format!("{:?}", *selected)}</output>
+            </>
+        }
+    }
+
+    fn highlighted(input: &Element) -> Option<String> {
+        input.attr("aria-activedescendant")
+    }
+
+    fn expanded(input: &Element) -> bool {
+        input.attr("aria-expanded").as_deref() == Some("true")
+    }
+
+    fn output(mounted: &Element) -> String {
+        mounted.find_all(|element| element.tag() == "output").remove(0).text()
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_arrow_keys_home_and_end_move_the_highlight() {
+        let mounted = mount(html! { <Single /> }).await;
+        let input = mounted.get_by_role("combobox");
+        assert!(!expanded(&input));
+
+        assert!(key_down(&input, "ArrowDown").await);
+        assert!(expanded(&input));
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-0"));
+        key_down(&input, "ArrowDown").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-1"));
+        key_down(&input, "End").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-2"));
+        key_down(&input, "ArrowDown").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-0"));
+        key_down(&input, "ArrowUp").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-2"));
+        key_down(&input, "Home").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-0"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn enter_chooses_the_highlighted_option_and_escape_closes_without_choosing() {
+        let mounted = mount(html! { <Single /> }).await;
+        let input = mounted.get_by_role("combobox");
+
+        key_down(&input, "ArrowDown").await;
+        key_down(&input, "ArrowDown").await;
+        assert!(key_down(&input, "Escape").await);
+        assert!(!expanded(&input));
+        assert_eq!(output(&mounted), "None");
+
+        input_text(&input, "cher").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-2"));
+        assert!(key_down(&input, "Enter").await);
+        assert_eq!(output(&mounted), "Some(3)");
+        assert!(!expanded(&input));
+        assert_eq!(input.unchecked_ref::<HtmlInputElement>().value(), "Cherry");
+
+        // Reopening highlights the selection.
+        key_down(&input, "ArrowUp").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-2"));
+        assert!(!key_down(&input, "Backspace").await);
+        assert_eq!(output(&mounted), "Some(3)");
+    }
+
+    #[wasm_bindgen_test]
+    async fn enter_toggles_options_and_backspace_removes_the_last_one_of_a_multi_combobox() {
+        let mounted = mount(html! { <Multi /> }).await;
+        let input = mounted.get_by_role("combobox");
+
+        key_down(&input, "ArrowDown").await;
+        assert_eq!(highlighted(&input).as_deref(), Some("fruit-option-2"));
+        key_down(&input, "Home").await;
+        key_down(&input, "Enter").await;
+        assert_eq!(output(&mounted), "[1, 3]");
+        assert!(expanded(&input));
+        key_down(&input, "End").await;
+        key_down(&input, "Enter").await;
+        assert_eq!(output(&mounted), "[1]");
+
+        key_down(&input, "Backspace").await;
+        assert_eq!(output(&mounted), "[]");
+        key_down(&input, "Backspace").await;
+        assert_eq!(output(&mounted), "[]");
+    }
+}
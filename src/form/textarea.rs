@@ -1,4 +1,4 @@
-use crate::{Icon, Size};
+use crate::{ExtraAttrs, Icon, Size};
 use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlTextAreaElement;
 use yew::prelude::*;
@@ -14,6 +14,9 @@ pub struct TextAreaProps {
 
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The placeholder value for this component.
     #[prop_or_default]
     pub placeholder: String,
@@ -95,7 +98,7 @@ pub fn text_area(props: &TextAreaProps) -> Html {
         });
     }
 
-    html! {
+    props.attrs.apply(html! {
         if props.is_genai {
             <div id="context" style="position:relative">
                 if *genai {
@@ -130,5 +133,5 @@ pub fn text_area(props: &TextAreaProps) -> Html {
                 maxlength={props.maxlength.map(|m| m.to_string())}
                 />
         }
-    }
+    })
 }
@@ -0,0 +1,874 @@
+/*!
+An upload queue which sends files through a pluggable [`Uploader`] and shows their progress.
+
+Summary
+- [`UploadQueue`] uploads every file of its `files` prop, e.g. the files chosen in a [`File`] input, and shows a
+  [`Progress`] bar, a status [`Tag`] and cancel/retry buttons per file, plus the aggregate progress of all files.
+- [`Uploader`] performs one upload. Any `Fn(UploadRequest) -> impl Future<Output = Result<(), String>>` closure
+  implements it, so an XHR or fetch based upload can be plugged in directly. [`MemoryUploader`] simulates uploads in
+  memory for tests and demos.
+- An [`UploadRequest`] carries the file, reports the uploaded bytes and tells the uploader when the upload was
+  cancelled. Progress and results of cancelled or retried attempts are ignored.
+- At most `concurrency` files are uploaded at a time; the others wait as [`UploadStatus::Queued`]. Removing a file
+  from `files` cancels its upload.
+
+[`File`]: crate::File
+[`Progress`]: crate::Progress
+[`Tag`]: crate::Tag
+*/
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::File as SysFile;
+use yew::prelude::*;
+
+use crate::common::use_style;
+use crate::{Button, ButtonColor, ButtonSize, ButtonVariant, ExtraAttrs, Progress, Tag, format_file_size};
+
+/// The future returned by [`Uploader::upload`]; resolves to an error message on failure.
+pub type UploadFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// Uploads single files for an [`UploadQueue`].
+pub trait Uploader {
+    /// Upload `request.file()`, reporting the uploaded bytes through [`UploadRequest::report`].
+    fn upload(&self, request: UploadRequest) -> UploadFuture;
+}
+
+impl<F, Fut> Uploader for F
+where
+    F: Fn(UploadRequest) -> Fut,
+    Fut: Future<Output = Result<(), String>> + 'static,
+{
+    fn upload(&self, request: UploadRequest) -> UploadFuture {
+        Box::pin(self(request))
+    }
+}
+
+/// A shared [`Uploader`] implementation, as accepted by [`UploadQueueProps::uploader`].
+pub struct UploaderSource(pub Rc<dyn Uploader>);
+
+impl UploaderSource {
+    pub fn new(uploader: impl Uploader + 'static) -> Self {
+        Self(Rc::new(uploader))
+    }
+}
+
+impl Clone for UploaderSource {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl std::fmt::Debug for UploaderSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("UploaderSource(..)")
+    }
+}
+
+impl PartialEq for UploaderSource {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct CancelState {
+    cancelled: bool,
+    handlers: Vec<Box<dyn FnOnce()>>,
+}
+
+/// Cancels one upload attempt.
+#[derive(Clone, Default)]
+struct CancelToken(Rc<RefCell<CancelState>>);
+
+impl CancelToken {
+    fn cancel(&self) {
+        let handlers = {
+            let mut state = self.0.borrow_mut();
+            state.cancelled = true;
+            std::mem::take(&mut state.handlers)
+        };
+        for handler in handlers {
+            handler();
+        }
+    }
+}
+
+/// One upload attempt of a file, passed to [`Uploader::upload`].
+#[derive(Clone)]
+pub struct UploadRequest {
+    file: SysFile,
+    progress: Callback<f64>,
+    cancel: CancelToken,
+}
+
+impl UploadRequest {
+    /// The file to upload.
+    pub fn file(&self) -> &SysFile {
+        &self.file
+    }
+
+    /// Report that `loaded` bytes of the file have been uploaded.
+    pub fn report(&self, loaded: f64) {
+        self.progress.emit(loaded);
+    }
+
+    /// Whether the user cancelled this upload; its result is ignored then.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.0.borrow().cancelled
+    }
+
+    /// Call `handler` when the upload is cancelled, e.g. to abort a request; runs at once if it already was.
+    pub fn on_cancel(&self, handler: impl FnOnce() + 'static) {
+        let mut state = self.cancel.0.borrow_mut();
+        if state.cancelled {
+            drop(state);
+            handler();
+        } else {
+            state.handlers.push(Box::new(handler));
+        }
+    }
+}
+
+impl std::fmt::Debug for UploadRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadRequest")
+            .field("file", &self.file.name())
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+type FailWhen = Rc<dyn Fn(&SysFile) -> Option<String>>;
+
+/// An [`Uploader`] which keeps files in memory, for tests and demos.
+///
+/// Every upload reports its progress in `steps` equal parts, `step_ms` milliseconds apart, and then succeeds unless
+/// the `fail_when` check returns an error message for the file.
+#[derive(Clone)]
+pub struct MemoryUploader {
+    steps: u32,
+    step_ms: u32,
+    fail_when: Option<FailWhen>,
+    uploaded: Rc<RefCell<Vec<SysFile>>>,
+}
+
+impl Default for MemoryUploader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryUploader {
+    /// Upload in 10 steps of 100ms each.
+    pub fn new() -> Self {
+        Self {
+            steps: 10,
+            step_ms: 100,
+            fail_when: None,
+            uploaded: Rc::default(),
+        }
+    }
+
+    /// The number of progress reports per upload.
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = steps.max(1);
+        self
+    }
+
+    /// The delay before each progress report; `0` still yields to the event loop between reports.
+    pub fn step_ms(mut self, step_ms: u32) -> Self {
+        self.step_ms = step_ms;
+        self
+    }
+
+    /// Fail the uploads of files for which `check` returns an error message.
+    pub fn fail_when(mut self, check: impl Fn(&SysFile) -> Option<String> + 'static) -> Self {
+        self.fail_when = Some(Rc::new(check));
+        self
+    }
+
+    /// The files uploaded successfully so far, shared by all clones of this uploader.
+    pub fn uploaded(&self) -> Vec<SysFile> {
+        self.uploaded.borrow().clone()
+    }
+}
+
+impl Uploader for MemoryUploader {
+    fn upload(&self, request: UploadRequest) -> UploadFuture {
+        let uploader = self.clone();
+        Box::pin(async move {
+            let size = request.file().size();
+            for step in 1..=uploader.steps {
+                TimeoutFuture::new(uploader.step_ms).await;
+                if request.is_cancelled() {
+                    return Err("Cancelled".into());
+                }
+                request.report(size * f64::from(step) / f64::from(uploader.steps));
+            }
+            if let Some(error) = uploader.fail_when.as_ref().and_then(|check| check(request.file())) {
+                return Err(error);
+            }
+            uploader.uploaded.borrow_mut().push(request.file().clone());
+            Ok(())
+        })
+    }
+}
+
+impl std::fmt::Debug for MemoryUploader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryUploader")
+            .field("steps", &self.steps)
+            .field("step_ms", &self.step_ms)
+            .field("uploaded", &self.uploaded.borrow().len())
+            .finish()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// The state of one file in an [`UploadQueue`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UploadStatus {
+    /// Waiting for a free upload slot.
+    Queued,
+    /// Being uploaded.
+    Uploading,
+    /// Uploaded successfully.
+    Done,
+    /// The upload failed with the given message.
+    Failed(String),
+    /// The user cancelled the upload.
+    Cancelled,
+}
+
+impl UploadStatus {
+    /// The default English label of the status tag.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Queued => "Queued".into(),
+            Self::Uploading => "Uploading".into(),
+            Self::Done => "Uploaded".into(),
+            Self::Failed(_) => "Failed".into(),
+            Self::Cancelled => "Cancelled".into(),
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            Self::Queued => "is-light",
+            Self::Uploading => "is-info",
+            Self::Done => "is-success",
+            Self::Failed(_) => "is-danger",
+            Self::Cancelled => "is-warning",
+        }
+    }
+}
+
+/// The aggregate state of an [`UploadQueue`], shown above its files.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UploadSummary {
+    /// The number of files which are not cancelled.
+    pub total: usize,
+    /// The number of files uploaded successfully.
+    pub done: usize,
+    /// The number of failed uploads.
+    pub failed: usize,
+    /// The bytes uploaded, over all files which are not cancelled.
+    pub loaded: f64,
+    /// The total size of all files which are not cancelled.
+    pub size: f64,
+}
+
+impl UploadSummary {
+    /// The default English summary, e.g. `"2 of 3 files uploaded (1.2 MB of 3.4 MB), 1 failed"`.
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} of {} {} uploaded ({} of {})",
+            self.done,
+            self.total,
+            if self.total == 1 { "file" } else { "files" },
+            format_file_size(self.loaded),
+            format_file_size(self.size),
+        );
+        if self.failed > 0 {
+            message.push_str(&format!(", {} failed", self.failed));
+        }
+        message
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// A file tracked by a [`QueueState`]; only implemented by [`SysFile`] outside of the tests.
+trait QueuedFile: Clone + PartialEq {
+    fn size(&self) -> f64;
+}
+
+impl QueuedFile for SysFile {
+    fn size(&self) -> f64 {
+        web_sys::Blob::size(self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct UploadEntry<F = SysFile> {
+    id: u64,
+    /// Incremented by every cancel and retry; progress and results of other attempts are ignored.
+    attempt: u64,
+    file: F,
+    loaded: f64,
+    status: UploadStatus,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct QueueState<F = SysFile> {
+    entries: Vec<UploadEntry<F>>,
+    next_id: u64,
+}
+
+impl<F> Default for QueueState<F> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<F: QueuedFile> QueueState<F> {
+    fn attempt_mut(&mut self, id: u64, attempt: u64) -> Option<&mut UploadEntry<F>> {
+        self.entries.iter_mut().find(|entry| entry.id == id && entry.attempt == attempt)
+    }
+
+    fn summary(&self) -> UploadSummary {
+        let mut summary = UploadSummary::default();
+        for entry in self.entries.iter().filter(|entry| entry.status != UploadStatus::Cancelled) {
+            summary.total += 1;
+            summary.done += usize::from(entry.status == UploadStatus::Done);
+            summary.failed += usize::from(matches!(entry.status, UploadStatus::Failed(_)));
+            summary.loaded += entry.loaded;
+            summary.size += entry.file.size();
+        }
+        summary
+    }
+}
+
+enum QueueAction<F = SysFile> {
+    /// Add entries for new files and drop those of removed files.
+    Sync(Vec<F>),
+    Started(u64, u64),
+    Progress(u64, u64, f64),
+    Finished(u64, u64, Result<(), String>),
+    Cancel(u64),
+    Retry(u64),
+}
+
+impl<F: QueuedFile> Reducible for QueueState<F> {
+    type Action = QueueAction<F>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            QueueAction::Sync(files) => {
+                state.entries.retain(|entry| files.contains(&entry.file));
+                for file in files {
+                    if !state.entries.iter().any(|entry| entry.file == file) {
+                        let id = state.next_id;
+                        state.next_id += 1;
+                        state.entries.push(UploadEntry {
+                            id,
+                            attempt: 0,
+                            file,
+                            loaded: 0.0,
+                            status: UploadStatus::Queued,
+                        });
+                    }
+                }
+            }
+            QueueAction::Started(id, attempt) => match state.attempt_mut(id, attempt) {
+                Some(entry) if entry.status == UploadStatus::Queued => entry.status = UploadStatus::Uploading,
+                _ => return self,
+            },
+            QueueAction::Progress(id, attempt, loaded) => match state.attempt_mut(id, attempt) {
+                Some(entry) if entry.status == UploadStatus::Uploading => entry.loaded = loaded.clamp(0.0, entry.file.size()),
+                _ => return self,
+            },
+            QueueAction::Finished(id, attempt, result) => match state.attempt_mut(id, attempt) {
+                Some(entry) if entry.status == UploadStatus::Uploading => match result {
+                    Ok(()) => {
+                        entry.loaded = entry.file.size();
+                        entry.status = UploadStatus::Done;
+                    }
+                    Err(error) => entry.status = UploadStatus::Failed(error),
+                },
+                _ => return self,
+            },
+            QueueAction::Cancel(id) => match state.entries.iter_mut().find(|entry| entry.id == id) {
+                Some(entry) if matches!(entry.status, UploadStatus::Queued | UploadStatus::Uploading) => {
+                    entry.attempt += 1;
+                    entry.status = UploadStatus::Cancelled;
+                }
+                _ => return self,
+            },
+            QueueAction::Retry(id) => match state.entries.iter_mut().find(|entry| entry.id == id) {
+                Some(entry) if matches!(entry.status, UploadStatus::Failed(_) | UploadStatus::Cancelled) => {
+                    entry.attempt += 1;
+                    entry.loaded = 0.0;
+                    entry.status = UploadStatus::Queued;
+                }
+                _ => return self,
+            },
+        }
+        Rc::new(state)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct UploadQueueProps {
+    /// Performs the uploads.
+    pub uploader: UploaderSource,
+    /// The files to upload; new files are queued and removed files are cancelled.
+    pub files: Vec<SysFile>,
+
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+    /// The number of files uploaded at the same time.
+    #[prop_or(3)]
+    pub concurrency: usize,
+    /// Called with each file once it is uploaded.
+    #[prop_or_default]
+    pub on_uploaded: Callback<SysFile>,
+    /// Called with each file whose upload failed, and the error message.
+    #[prop_or_default]
+    pub on_failed: Callback<(SysFile, String)>,
+    /// Builds the text of the status tags; defaults to [`UploadStatus::label`].
+    #[prop_or_else(|| Callback::from(|status: UploadStatus| status.label()))]
+    pub status_label: Callback<UploadStatus, String>,
+    /// Builds the text of the aggregate progress; defaults to [`UploadSummary::message`].
+    #[prop_or_else(|| Callback::from(|summary: UploadSummary| summary.message()))]
+    pub summary_label: Callback<UploadSummary, String>,
+    /// The text of the cancel buttons.
+    #[prop_or_else(|| "Cancel".into())]
+    pub cancel_label: AttrValue,
+    /// The text of the retry buttons.
+    #[prop_or_else(|| "Retry".into())]
+    pub retry_label: AttrValue,
+}
+
+/// A list of file uploads with per-file progress, status and cancel/retry buttons.
+///
+/// [https://bulma.io/documentation/elements/progress/](https://bulma.io/documentation/elements/progress/)
+///
+/// ```rust,ignore
+/// let files = use_state(Vec::<web_sys::File>::new);
+/// let uploader = use_memo((), |_| {
+///     UploaderSource::new(|request: UploadRequest| async move {
+///         let response = send_file(request.file(), |loaded| request.report(loaded)).await;
+///         response.map_err(|error| error.to_string())
+///     })
+/// });
+/// html! {
+///     <>
+///         <File name="attachments" files={(*files).clone()} update={move |chosen| files.set(chosen)} multiple=true />
+///         <UploadQueue uploader={(*uploader).clone()} files={(*files).clone()} />
+///     </>
+/// }
+/// ```
+#[component(UploadQueue)]
+pub fn upload_queue(props: &UploadQueueProps) -> Html {
+    use_style("ybc-upload-queue-style", UPLOAD_QUEUE_STYLE);
+    let state = use_reducer(QueueState::default);
+    // The cancel tokens of the running attempts by entry id; kept outside of the reducer so that an upload is never
+    // started twice.
+    let running = use_mut_ref(BTreeMap::<u64, (u64, CancelToken)>::new);
+
+    {
+        let dispatcher = state.dispatcher();
+        use_effect_with(props.files.clone(), move |files| {
+            dispatcher.dispatch(QueueAction::Sync(files.clone()));
+            || ()
+        });
+    }
+
+    // Cancel the running uploads on unmount.
+    {
+        let running = running.clone();
+        use_effect_with((), move |_| {
+            move || {
+                for (_, cancel) in std::mem::take(&mut *running.borrow_mut()).into_values() {
+                    cancel.cancel();
+                }
+            }
+        });
+    }
+
+    // Stop the uploads of removed or cancelled entries and start queued ones while slots are free.
+    {
+        let state = state.clone();
+        let running = running.clone();
+        let props = props.clone();
+        use_effect(move || {
+            let mut tasks = running.borrow_mut();
+            tasks.retain(|id, (attempt, cancel)| {
+                let active = state
+                    .entries
+                    .iter()
+                    .any(|entry| entry.id == *id && entry.attempt == *attempt && !matches!(entry.status, UploadStatus::Cancelled));
+                if !active {
+                    cancel.cancel();
+                }
+                active
+            });
+            for entry in state.entries.iter().filter(|entry| entry.status == UploadStatus::Queued) {
+                if tasks.len() >= props.concurrency.max(1) {
+                    break;
+                }
+                if tasks.contains_key(&entry.id) {
+                    continue;
+                }
+                let (id, attempt) = (entry.id, entry.attempt);
+                let cancel = CancelToken::default();
+                tasks.insert(id, (attempt, cancel.clone()));
+
+                let dispatcher = state.dispatcher();
+                let progress = {
+                    let dispatcher = dispatcher.clone();
+                    Callback::from(move |loaded| dispatcher.dispatch(QueueAction::Progress(id, attempt, loaded)))
+                };
+                let request = UploadRequest {
+                    file: entry.file.clone(),
+                    progress,
+                    cancel,
+                };
+                dispatcher.dispatch(QueueAction::Started(id, attempt));
+                let upload = props.uploader.0.upload(request.clone());
+                let running = running.clone();
+                let on_uploaded = props.on_uploaded.clone();
+                let on_failed = props.on_failed.clone();
+                spawn_local(async move {
+                    let result = upload.await;
+                    if request.is_cancelled() {
+                        return;
+                    }
+                    running.borrow_mut().remove(&id);
+                    match &result {
+                        Ok(()) => on_uploaded.emit(request.file.clone()),
+                        Err(error) => on_failed.emit((request.file.clone(), error.clone())),
+                    }
+                    dispatcher.dispatch(QueueAction::Finished(id, attempt, result));
+                });
+            }
+            || ()
+        });
+    }
+
+    let summary = state.summary();
+    let fraction = if summary.size > 0.0 { summary.loaded / summary.size } else { 0.0 };
+    let class = classes!("ybc-upload-queue", props.classes.clone());
+    props.attrs.apply(html! {
+        <div {class}>
+            if !state.entries.is_empty() {
+                <div class="ybc-upload-summary" aria-live="polite">
+                    <Progress classes="is-primary is-small" max={1.0} value={fraction as f32} />
+                    <p class="help">{props.summary_label.emit(summary)}</p>
+                </div>
+            }
+            <ul class="ybc-upload-list">
+                { for state.entries.iter().map(|entry| {
+                    let id = entry.id;
+                    let size = entry.file.size();
+                    let fraction = if size > 0.0 { entry.loaded / size } else if entry.status == UploadStatus::Done { 1.0 } else { 0.0 };
+                    let action = match entry.status {
+                        UploadStatus::Queued | UploadStatus::Uploading => {
+                            let dispatcher = state.dispatcher();
+                            let onclick = Callback::from(move |_: MouseEvent| dispatcher.dispatch(QueueAction::Cancel(id)));
+                            let aria_label = format!("{} {}", props.cancel_label, entry.file.name());
+                            html! {
+                                <Button {onclick} {aria_label} size={ButtonSize::Small} variant={ButtonVariant::Text}>{props.cancel_label.clone()}</Button>
+                            }
+                        }
+                        UploadStatus::Failed(_) | UploadStatus::Cancelled => {
+                            let dispatcher = state.dispatcher();
+                            let onclick = Callback::from(move |_: MouseEvent| dispatcher.dispatch(QueueAction::Retry(id)));
+                            let aria_label = format!("{} {}", props.retry_label, entry.file.name());
+                            html! {
+                                <Button {onclick} {aria_label} size={ButtonSize::Small} color={ButtonColor::Link} variant={ButtonVariant::Light}>
+                                    {props.retry_label.clone()}
+                                </Button>
+                            }
+                        }
+                        UploadStatus::Done => html! {},
+                    };
+                    html! {
+                        <li class="ybc-upload-item" key={id}>
+                            <div class="ybc-upload-item-header">
+                                <span class="ybc-upload-item-name">{entry.file.name()}</span>
+                                <span class="ybc-upload-item-size">{format_file_size(size)}</span>
+                                <Tag classes={classes!(entry.status.color())}>{Html::from(props.status_label.emit(entry.status.clone()))}</Tag>
+                                {action}
+                            </div>
+                            <Progress classes={classes!("is-small", entry.status.color())} max={1.0} value={fraction as f32} />
+                            if let UploadStatus::Failed(error) = &entry.status {
+                                <p class="help is-danger">{error.clone()}</p>
+                            }
+                        </li>
+                    }
+                }) }
+            </ul>
+        </div>
+    })
+}
+
+const UPLOAD_QUEUE_STYLE: &str = r#"
+.ybc-upload-list {
+    list-style: none;
+    margin: 0.75rem 0 0;
+}
+.ybc-upload-item {
+    margin-bottom: 0.75rem;
+}
+.ybc-upload-item-header {
+    display: flex;
+    align-items: center;
+    gap: 0.5rem;
+    margin-bottom: 0.25rem;
+}
+.ybc-upload-item-name {
+    flex: 1;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+.ybc-upload-item-size {
+    font-size: 0.75rem;
+    opacity: 0.7;
+}
+.ybc-upload-queue .progress {
+    margin-bottom: 0.25rem;
+}
+"#;
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FakeFile(&'static str, f64);
+
+    impl QueuedFile for FakeFile {
+        fn size(&self) -> f64 {
+            self.1
+        }
+    }
+
+    const A: FakeFile = FakeFile("a.txt", 100.0);
+    const B: FakeFile = FakeFile("b.txt", 300.0);
+
+    fn reduce(state: Rc<QueueState<FakeFile>>, actions: impl IntoIterator<Item = QueueAction<FakeFile>>) -> Rc<QueueState<FakeFile>> {
+        actions.into_iter().fold(state, Reducible::reduce)
+    }
+
+    fn synced(files: Vec<FakeFile>) -> Rc<QueueState<FakeFile>> {
+        reduce(Rc::default(), [QueueAction::Sync(files)])
+    }
+
+    fn entry(state: &QueueState<FakeFile>, id: u64) -> &UploadEntry<FakeFile> {
+        state.entries.iter().find(|entry| entry.id == id).unwrap()
+    }
+
+    #[test]
+    fn sync_adds_new_files_and_drops_removed_ones() {
+        let state = synced(vec![A, B]);
+        assert_eq!(
+            state.entries.iter().map(|entry| (entry.id, entry.file.0)).collect::<Vec<_>>(),
+            [(0, "a.txt"), (1, "b.txt")]
+        );
+        assert!(state.entries.iter().all(|entry| entry.status == UploadStatus::Queued));
+
+        let state = reduce(state, [QueueAction::Started(0, 0), QueueAction::Sync(vec![A, FakeFile("c.txt", 1.0)])]);
+        assert_eq!(
+            state.entries.iter().map(|entry| (entry.id, entry.file.0)).collect::<Vec<_>>(),
+            [(0, "a.txt"), (2, "c.txt")]
+        );
+        assert_eq!(entry(&state, 0).status, UploadStatus::Uploading);
+    }
+
+    #[test]
+    fn uploads_report_progress_and_finish() {
+        let state = reduce(synced(vec![A, B]), [QueueAction::Started(0, 0), QueueAction::Progress(0, 0, 40.0)]);
+        assert_eq!(entry(&state, 0).loaded, 40.0);
+        let state = reduce(state, [QueueAction::Progress(0, 0, 500.0)]);
+        assert_eq!(entry(&state, 0).loaded, 100.0);
+
+        let state = reduce(
+            state,
+            [
+                QueueAction::Started(1, 0),
+                QueueAction::Finished(0, 0, Ok(())),
+                QueueAction::Finished(1, 0, Err("Offline".into())),
+            ],
+        );
+        assert_eq!(entry(&state, 0).status, UploadStatus::Done);
+        assert_eq!(entry(&state, 1).status, UploadStatus::Failed("Offline".into()));
+    }
+
+    #[test]
+    fn cancel_and_retry_start_new_attempts() {
+        let state = reduce(
+            synced(vec![A]),
+            [QueueAction::Started(0, 0), QueueAction::Progress(0, 0, 40.0), QueueAction::Cancel(0)],
+        );
+        assert_eq!((entry(&state, 0).attempt, &entry(&state, 0).status), (1, &UploadStatus::Cancelled));
+
+        let state = reduce(state, [QueueAction::Retry(0)]);
+        assert_eq!((entry(&state, 0).attempt, &entry(&state, 0).status), (2, &UploadStatus::Queued));
+        assert_eq!(entry(&state, 0).loaded, 0.0);
+
+        // Done uploads can neither be cancelled nor retried.
+        let state = reduce(state, [QueueAction::Started(0, 2), QueueAction::Finished(0, 2, Ok(()))]);
+        assert!(Rc::ptr_eq(
+            &state,
+            &reduce(state.clone(), [QueueAction::Cancel(0), QueueAction::Retry(0)])
+        ));
+    }
+
+    #[test]
+    fn stale_attempts_are_ignored() {
+        let state = reduce(synced(vec![A]), [QueueAction::Started(0, 0), QueueAction::Cancel(0)]);
+        let cancelled = reduce(state.clone(), [QueueAction::Progress(0, 0, 50.0), QueueAction::Finished(0, 0, Ok(()))]);
+        assert!(Rc::ptr_eq(&state, &cancelled));
+
+        let retried = reduce(state, [QueueAction::Retry(0), QueueAction::Started(0, 2)]);
+        let stale = reduce(
+            retried.clone(),
+            [QueueAction::Progress(0, 1, 50.0), QueueAction::Finished(0, 0, Err("Late".into()))],
+        );
+        assert!(Rc::ptr_eq(&retried, &stale));
+        assert_eq!(entry(&stale, 0).status, UploadStatus::Uploading);
+    }
+
+    #[test]
+    fn summary_counts_files_which_are_not_cancelled() {
+        let state = reduce(
+            synced(vec![A, B, FakeFile("c.txt", 2048.0)]),
+            [
+                QueueAction::Started(0, 0),
+                QueueAction::Finished(0, 0, Ok(())),
+                QueueAction::Started(1, 0),
+                QueueAction::Progress(1, 0, 150.0),
+                QueueAction::Finished(1, 0, Err("Offline".into())),
+                QueueAction::Cancel(2),
+            ],
+        );
+        let summary = state.summary();
+        assert_eq!(
+            summary,
+            UploadSummary {
+                total: 2,
+                done: 1,
+                failed: 1,
+                loaded: 250.0,
+                size: 400.0,
+            }
+        );
+        assert_eq!(summary.message(), "1 of 2 files uploaded (250 B of 400 B), 1 failed");
+    }
+
+    #[test]
+    fn summary_messages_count_files_and_sizes() {
+        assert_eq!(UploadSummary::default().message(), "0 of 0 files uploaded (0 B of 0 B)");
+        let summary = UploadSummary {
+            total: 1,
+            done: 0,
+            failed: 0,
+            loaded: 1536.0,
+            size: 3.0 * 1024.0 * 1024.0,
+        };
+        assert_eq!(summary.message(), "0 of 1 file uploaded (1.5 KB of 3.0 MB)");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+    use web_sys::js_sys::Array;
+
+    use super::*;
+    use crate::testing::{Mounted, Queries, click, mount, settle};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn file(name: &str, content: &str) -> SysFile {
+        SysFile::new_with_str_sequence(&Array::of1(&JsValue::from_str(content)), name).unwrap()
+    }
+
+    fn statuses(mounted: &Mounted) -> Vec<String> {
+        mounted.find_all(|element| element.has_class("tag")).iter().map(Queries::text).collect()
+    }
+
+    async fn wait_for_summary(mounted: &Mounted, message: &str) {
+        for _ in 0..500 {
+            if mounted.query_by_text(message).is_some() {
+                return;
+            }
+            settle().await;
+        }
+        panic!("the summary never became `{}`: {:?}", message, statuses(mounted));
+    }
+
+    #[wasm_bindgen_test]
+    async fn uploads_at_most_concurrency_files_at_a_time() {
+        let uploader = MemoryUploader::new().step_ms(0);
+        let files = vec![file("a.txt", "aaa"), file("b.txt", "bbb"), file("c.txt", "ccc")];
+        let mounted = mount(html! { <UploadQueue uploader={UploaderSource::new(uploader.clone())} {files} concurrency={2} /> }).await;
+        settle().await;
+        assert_eq!(statuses(&mounted), ["Uploading", "Uploading", "Queued"]);
+
+        wait_for_summary(&mounted, "3 of 3 files uploaded (9 B of 9 B)").await;
+        assert_eq!(statuses(&mounted), ["Uploaded", "Uploaded", "Uploaded"]);
+        assert_eq!(
+            uploader.uploaded().iter().map(SysFile::name).collect::<Vec<_>>(),
+            ["a.txt", "b.txt", "c.txt"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn cancelled_uploads_can_be_retried() {
+        let uploader = MemoryUploader::new().step_ms(0);
+        let files = vec![file("a.txt", "aaa"), file("b.txt", "bbb")];
+        let mounted = mount(html! { <UploadQueue uploader={UploaderSource::new(uploader.clone())} {files} concurrency={1} /> }).await;
+        settle().await;
+        assert_eq!(statuses(&mounted), ["Uploading", "Queued"]);
+
+        click(&mounted.get_by_role_and_name("button", "Cancel a.txt")).await;
+        assert_eq!(statuses(&mounted)[0], "Cancelled");
+        assert_eq!(statuses(&mounted)[1], "Uploading");
+        wait_for_summary(&mounted, "1 of 1 file uploaded (3 B of 3 B)").await;
+
+        click(&mounted.get_by_role_and_name("button", "Retry a.txt")).await;
+        wait_for_summary(&mounted, "2 of 2 files uploaded (6 B of 6 B)").await;
+        assert_eq!(uploader.uploaded().iter().map(SysFile::name).collect::<Vec<_>>(), ["b.txt", "a.txt"]);
+    }
+}
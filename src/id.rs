@@ -0,0 +1,207 @@
+/*!
+Deterministic element ids for components which need them for ARIA relations, e.g. `aria-controls`.
+
+Summary
+- [`use_ybc_id`] returns an id such as `"tabs-1"` which stays the same for the lifetime of the component.
+- Ids are counted per prefix within the nearest [`YbcIdProvider`], in render order. Server and client render the same
+  tree in the same order, so server-rendered markup and its hydration agree on every id, and each render on the
+  server starts counting anew.
+- Outside of a provider, ids come from counters shared by the whole thread; [`reset_ybc_ids`] restarts them, e.g.
+  at the start of every test.
+- Explicit `id` props always take precedence over generated ids.
+
+Example
+```rust,ignore
+#[component(App)]
+fn app() -> Html {
+    html! {
+        <YbcIdProvider>
+            <Tabs>...</Tabs>
+        </YbcIdProvider>
+    }
+}
+
+#[component(Disclosure)]
+fn disclosure() -> Html {
+    let panel_id = use_ybc_id("disclosure");
+    html! { <button aria-controls={panel_id.clone()}>{"More"}</button> }
+}
+```
+*/
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::ExtraAttrs;
+
+thread_local! {
+    static GLOBAL_IDS: RefCell<BTreeMap<String, usize>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// The next id for `prefix` from `counters`, starting at 1.
+fn next_in(counters: &mut BTreeMap<String, usize>, prefix: &str) -> usize {
+    let counter = counters.entry(prefix.to_owned()).or_default();
+    *counter += 1;
+    *counter
+}
+
+/// The id counters of one [`YbcIdProvider`].
+#[derive(Debug, Default)]
+pub(crate) struct IdScope {
+    scope: AttrValue,
+    counters: RefCell<BTreeMap<String, usize>>,
+}
+
+/// The context shared by a [`YbcIdProvider`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IdContext(Rc<IdScope>);
+
+impl PartialEq for IdContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The next id for `prefix`, from `context` if there is one and from the thread-wide counters otherwise.
+pub(crate) fn next_id(context: Option<&IdContext>, prefix: &str) -> AttrValue {
+    match context {
+        Some(IdContext(scope)) => {
+            let number = next_in(&mut scope.counters.borrow_mut(), prefix);
+            if scope.scope.is_empty() {
+                format!("{}-{}", prefix, number).into()
+            } else {
+                format!("{}-{}-{}", scope.scope, prefix, number).into()
+            }
+        }
+        None => {
+            let number = GLOBAL_IDS.with(|counters| next_in(&mut counters.borrow_mut(), prefix));
+            format!("{}-{}", prefix, number).into()
+        }
+    }
+}
+
+/// The next id for `prefix`, for struct components which cannot use [`use_ybc_id`].
+pub(crate) fn next_component_id<C: BaseComponent>(ctx: &Context<C>, prefix: &str) -> AttrValue {
+    let context = ctx.link().context::<IdContext>(Callback::noop()).map(|(context, _)| context);
+    next_id(context.as_ref(), prefix)
+}
+
+/// Restart the thread-wide id counters used outside of any [`YbcIdProvider`], so that ids do not depend on what was
+/// rendered before, e.g. by earlier tests.
+pub fn reset_ybc_ids() {
+    GLOBAL_IDS.with(|counters| counters.borrow_mut().clear());
+}
+
+/// An id for this component, e.g. `"dropdown-1"` for the prefix `"dropdown"`, generated on the first render and kept
+/// afterwards.
+#[hook]
+pub fn use_ybc_id(prefix: &str) -> AttrValue {
+    let context = use_context::<IdContext>();
+    let prefix = prefix.to_owned();
+    (*use_state(move || next_id(context.as_ref(), &prefix))).clone()
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct YbcIdProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    /// Put in front of every id generated inside this provider, e.g. `"sidebar"` for `"sidebar-tabs-1"`; keeps the
+    /// ids of several independently rendered trees on one page apart.
+    #[prop_or_default]
+    pub scope: AttrValue,
+    /// Extra attributes, e.g. a test id, for a wrapper around the children; see [`ExtraAttrs`].
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
+}
+
+/// Generates the ids of the ybc components within it, counting from 1 for every provider.
+///
+/// Wrap the root of server-rendered and hydrated apps in a provider, so that both renders generate the same ids, and
+/// render each test inside a fresh provider, or call [`reset_ybc_ids`] first, for ids which do not depend on the
+/// order of tests.
+#[component(YbcIdProvider)]
+pub fn ybc_id_provider(props: &YbcIdProviderProps) -> Html {
+    let context = use_state(|| {
+        IdContext(Rc::new(IdScope {
+            scope: props.scope.clone(),
+            counters: RefCell::default(),
+        }))
+    });
+    props.attrs.apply(html! {
+        <ContextProvider<IdContext> context={(*context).clone()}>
+            {props.children.clone()}
+        </ContextProvider<IdContext>>
+    })
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::{Queries, Rendered, block_on, render_blocking};
+    use crate::{Dropdown, Tabs};
+
+    /// The non-empty ids in document order.
+    fn ids(rendered: &Rendered) -> Vec<String> {
+        rendered
+            .find_all(|element| element.attr("id").is_some_and(|id| !id.is_empty()))
+            .iter()
+            .filter_map(|element| element.attr("id"))
+            .collect()
+    }
+
+    #[component]
+    fn Widgets() -> Html {
+        html! {
+            <>
+                <Tabs />
+                <Dropdown />
+                <Tabs />
+                <Dropdown />
+            </>
+        }
+    }
+
+    #[test]
+    fn ids_count_per_prefix_in_render_order() {
+        let rendered = render_blocking(|| html! { <Widgets /> });
+        assert_eq!(
+            ids(&rendered),
+            ["tabs-1", "dropdown-1", "dropdown-1-menu", "tabs-2", "dropdown-2", "dropdown-2-menu"]
+        );
+        assert_eq!(ids(&render_blocking(|| html! { <Widgets /> })), ids(&rendered));
+    }
+
+    #[test]
+    fn every_provider_counts_anew_and_scopes_its_ids() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <YbcIdProvider scope="sidebar"><Tabs /><Tabs /></YbcIdProvider>
+                    <YbcIdProvider><Tabs /></YbcIdProvider>
+                </>
+            }
+        });
+        assert_eq!(ids(&rendered), ["sidebar-tabs-1", "sidebar-tabs-2", "tabs-1"]);
+    }
+
+    #[test]
+    fn explicit_ids_leave_the_generated_ones_unchanged() {
+        let rendered = render_blocking(|| html! { <><Tabs /><Tabs id="main" /><Tabs /></> });
+        assert_eq!(ids(&rendered), ["tabs-1", "main", "tabs-3"]);
+    }
+
+    #[test]
+    fn resetting_restarts_the_ids_outside_of_providers() {
+        // The local renderer renders on this thread, whose counters `reset_ybc_ids` restarts.
+        let render = || Rendered::parse(block_on(yew::LocalServerRenderer::<Widgets>::new().hydratable(false).render()));
+        reset_ybc_ids();
+        let first = render();
+        assert_eq!(ids(&first)[..2], ["tabs-1", "dropdown-1"]);
+        assert_eq!(ids(&render())[..2], ["tabs-3", "dropdown-3"]);
+        reset_ybc_ids();
+        assert_eq!(ids(&render()), ids(&first));
+    }
+}
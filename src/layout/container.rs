@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ContainerProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// Add a `32px` margin to the left and right sides of the container.
     #[prop_or_default]
     pub fluid: bool,
@@ -17,7 +22,7 @@ pub struct ContainerProps {
 #[component(Container)]
 pub fn container(props: &ContainerProps) -> Html {
     let class = classes!("container", props.classes.clone(), props.fluid.then_some("is-fluid"));
-    html! {
+    props.attrs.apply(html! {
         <div {class}>{props.children.clone()}</div>
-    }
+    })
 }
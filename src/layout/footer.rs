@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct FooterProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
 }
 
 /// A simple responsive footer which can include anything.
@@ -13,9 +18,9 @@ pub struct FooterProps {
 /// [https://bulma.io/documentation/layout/footer/](https://bulma.io/documentation/layout/footer/)
 #[component(Footer)]
 pub fn footer(props: &FooterProps) -> Html {
-    html! {
+    props.attrs.apply(html! {
         <footer class={classes!("footer", props.classes.clone())}>
             {props.children.clone()}
         </footer>
-    }
+    })
 }
@@ -1,11 +1,16 @@
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct HeroProps {
     /// Extra classes for the hero container.
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The contents of the hero-head section.
     #[prop_or_default]
     pub head: Option<Html>,
@@ -67,13 +72,13 @@ pub fn hero(props: &HeroProps) -> Html {
     };
 
     let body_classes = classes!("hero-body", props.body_classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <section {class}>
             {head}
             <div class={body_classes}>{props.body.clone()}</div>
             {foot}
         </section>
-    }
+    })
 }
 
 /// The 4 sizes available for heroes.
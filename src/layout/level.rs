@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct LevelProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "nav".into())]
     pub tag: String,
@@ -17,11 +22,11 @@ pub struct LevelProps {
 #[component(Level)]
 pub fn level(props: &LevelProps) -> Html {
     let class = classes!("level", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -33,6 +38,9 @@ pub struct LevelLeftProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -44,11 +52,11 @@ pub struct LevelLeftProps {
 #[component(LevelLeft)]
 pub fn level_left(props: &LevelLeftProps) -> Html {
     let class = classes!("level-left", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -60,6 +68,9 @@ pub struct LevelRightProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -71,11 +82,11 @@ pub struct LevelRightProps {
 #[component(LevelRight)]
 pub fn level_right(props: &LevelRightProps) -> Html {
     let class = classes!("level-right", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -87,6 +98,9 @@ pub struct LevelItemProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -98,9 +112,9 @@ pub struct LevelItemProps {
 #[component(LevelItem)]
 pub fn level_item(props: &LevelItemProps) -> Html {
     let class = classes!("level-item", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
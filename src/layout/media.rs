@@ -1,11 +1,16 @@
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct MediaProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -17,11 +22,11 @@ pub struct MediaProps {
 #[component(Media)]
 pub fn media(props: &MediaProps) -> Html {
     let class = classes!("media", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -33,6 +38,9 @@ pub struct MediaLeftProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -44,11 +52,11 @@ pub struct MediaLeftProps {
 #[component(MediaLeft)]
 pub fn media_left(props: &MediaLeftProps) -> Html {
     let class = classes!("media-left", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -60,6 +68,9 @@ pub struct MediaRightProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -71,11 +82,11 @@ pub struct MediaRightProps {
 #[component(MediaRight)]
 pub fn media_right(props: &MediaRightProps) -> Html {
     let class = classes!("media-right", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -87,6 +98,9 @@ pub struct MediaContentProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -98,9 +112,9 @@ pub struct MediaContentProps {
 #[component(MediaContent)]
 pub fn media_content(props: &MediaContentProps) -> Html {
     let class = classes!("media-content", props.classes.clone());
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
@@ -1,12 +1,17 @@
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct SectionProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// A size modifier to control spacing.
     #[prop_or_default]
     pub size: Option<SectionSize>,
@@ -18,11 +23,11 @@ pub struct SectionProps {
 #[component(Section)]
 pub fn section(props: &SectionProps) -> Html {
     let size = props.size.as_ref().map(|size| size.to_string());
-    html! {
+    props.attrs.apply(html! {
         <section class={classes!("section", props.classes.clone(), size)}>
             {props.children.clone()}
         </section>
-    }
+    })
 }
 
 /// The 2 sizes available for sections, which controls spacing.
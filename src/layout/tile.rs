@@ -2,12 +2,17 @@ use derive_more::Display;
 use std::string::ToString;
 use yew::prelude::*;
 
+use crate::ExtraAttrs;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TileProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Extra attributes, e.g. a test id, for the root element.
+    #[prop_or_default]
+    pub attrs: ExtraAttrs,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "div".into())]
     pub tag: String,
@@ -36,11 +41,11 @@ pub fn tile(props: &TileProps) -> Html {
     let ctx = props.ctx.as_ref().map(|ctx| ctx.to_string());
     let size = props.size.as_ref().map(|size| size.to_string());
     let class = classes!("tile", props.classes.clone(), ctx, props.vertical.then_some("is-vertical"), size);
-    html! {
+    props.attrs.apply(html! {
         <@{props.tag.clone()} {class}>
             {props.children.clone()}
         </@>
-    }
+    })
 }
 
 /// Tile context modifiers.
@@ -25,24 +25,28 @@ mod common;
 pub mod components;
 mod elements;
 mod form;
+mod id;
 mod layout;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 // columns
 pub use columns::{Column, ColumnProps, Columns, ColumnsProps};
 
 // common
-pub use common::{Alignment, Size};
+pub use common::{Alignment, ExtraAttrs, Size, TestAttr};
 
 // components
 pub use components::accordion::{AccordionItem, AccordionItemProps, Accordions, AccordionsProps};
-pub use components::autocomplete::{AutoComplete, AutoCompleteProps};
+pub use components::autocomplete::{AutoComplete, AutoCompleteItem, AutoCompleteProps, Suggest, SuggestFuture, SuggestSource};
 pub use components::breadcrumb::{Breadcrumb, BreadcrumbProps, BreadcrumbSeparator, BreadcrumbSize};
 pub use components::card::{
     Card, CardContent, CardContentProps, CardFooter, CardFooterProps, CardHeader, CardHeaderProps, CardImage, CardImageProps, CardProps,
 };
+pub use components::data_table::{ColumnDef, DataTable, DataTableProps, SortDirection, SortState, TableSelection};
 pub use components::dropdown::{Dropdown, DropdownMsg, DropdownProps};
 pub use components::menu::{Menu, MenuLabel, MenuLabelProps, MenuList, MenuListProps, MenuProps};
-pub use components::message::{Message, MessageBody, MessageBodyProps, MessageHeader, MessageHeaderProps, MessageProps};
+pub use components::message::{Message, MessageBody, MessageBodyProps, MessageColor, MessageHeader, MessageHeaderProps, MessageProps};
 pub use components::modal::{
     Modal, ModalCard, ModalCard2, ModalCardProps, ModalCloseReason, ModalController, ModalControllerContext, ModalControllerProvider,
     ModalControllerProviderProps, ModalMsg, ModalProps, ModalShouldClose,
@@ -52,12 +56,23 @@ pub use components::navbar::{
     NavbarItemTag, NavbarMsg, NavbarProps,
 };
 #[cfg(feature = "router")]
-pub use components::pagination::PaginationItemRouter;
-pub use components::pagination::{Pagination, PaginationEllipsis, PaginationItem, PaginationItemProps, PaginationItemType, PaginationProps};
+pub use components::pagination::{
+    PageQuery, PaginationItemRouter, PaginationQueryParams, PaginationRouter, PaginationRouterProps, use_pagination_query,
+};
+pub use components::pagination::{
+    Pagination, PaginationEllipsis, PaginationItem, PaginationItemProps, PaginationItemType, PaginationPage, PaginationProps, pagination_window,
+};
 pub use components::panel::{Panel, PanelBlock, PanelBlockProps, PanelProps, PanelTabs, PanelTabsProps};
+pub use components::table_query::{TableQuery, TableQueryHandle, TableQueryRequest, TableResultsCount, TableResultsCountProps, use_table_query};
 pub use components::tabs::{TabItem, TabItemProps, TabPanel, TabPanelProps, Tabs, TabsProps, TabsProvider, TabsProviderProps};
+pub use components::theme::{Theme, ThemeColor, ThemeContext, ThemeController, ThemeMode, ThemeProvider, ThemeProviderProps, use_theme};
+pub use components::toast::{
+    Toast, ToastContext, ToastController, ToastId, ToastPosition, ToastProvider, ToastProviderProps, ToastVariant, use_toast,
+};
 
-pub use components::calendar::{Calendar, CalendarProps, TestAttr};
+pub use components::calendar::{
+    Calendar, CalendarDate, CalendarLocale, CalendarProps, CalendarRange, CalendarTime, CalendarValue, RangePreset, Weekday,
+};
 
 // elements
 pub use elements::block::{Block, BlockProps};
@@ -72,7 +87,7 @@ pub use elements::content::{Content, ContentProps};
 pub use elements::delete::{Delete, DeleteProps};
 pub use elements::icon::{FaIcon, FaIconProps, Icon, IconProps};
 pub use elements::image::{Image, ImageProps, ImageSize};
-pub use elements::notification::{Notification, NotificationProps};
+pub use elements::notification::{Notification, NotificationColor, NotificationProps};
 pub use elements::progress::{Progress, ProgressProps};
 pub use elements::table::{Table, TableProps};
 pub use elements::tag::{Tag, TagProps, Tags, TagsProps};
@@ -82,11 +97,25 @@ pub use elements::title::{HeaderSize, Subtitle, SubtitleProps, Title, TitleProps
 pub use form::checkbox::{Checkbox, CheckboxProps};
 pub use form::control::{Control, ControlProps};
 pub use form::field::{AddonsAlign, Field, FieldProps, GroupedAlign, LabelSize};
-pub use form::file::{File, FileProps};
+pub use form::file::{File, FileProps, FileRejectReason, FileRejection, format_file_size};
+pub use form::form_state::{
+    AsyncValidator, Form, FormContext, FormFieldDef, FormFieldHandle, FormHandle, FormOptions, FormProps, FormValue, ValidationFuture, Validator,
+    use_form, use_form_with,
+};
 pub use form::input::{Input, InputProps, InputType};
+pub use form::masked_input::{Mask, MaskedInput, MaskedInputProps, MaskedValue};
+pub use form::number_input::{Num, NumberInput, NumberInputError, NumberInputProps};
 pub use form::radio::{Radio, RadioProps};
-pub use form::select::{MultiSelect, MultiSelectProps, Select, SelectProps};
+pub use form::select::{Combobox, ComboboxProps, MultiCombobox, MultiComboboxProps, MultiSelect, MultiSelectProps, Select, SelectProps};
 pub use form::textarea::{TextArea, TextAreaProps};
+pub use form::upload_queue::{
+    MemoryUploader, UploadFuture, UploadQueue, UploadQueueProps, UploadRequest, UploadStatus, UploadSummary, Uploader, UploaderSource,
+};
+#[cfg(feature = "derive")]
+pub use ybc_derive::YbcForm;
+
+// id
+pub use id::{YbcIdProvider, YbcIdProviderProps, reset_ybc_ids, use_ybc_id};
 
 // layout
 pub use layout::container::{Container, ContainerProps};
@@ -0,0 +1,352 @@
+use super::queries::Queries;
+
+/// Panic with `message` and the markup of `root` unless `condition` holds.
+fn check<N: Queries>(root: &N, condition: bool, message: impl FnOnce() -> String) {
+    if !condition {
+        panic!("{}\nin:\n{}", message(), root.markup());
+    }
+}
+
+/// The value of the boolean ARIA state `name` of `element`, which must be `"true"` or `"false"`.
+fn aria_bool<N: Queries>(root: &N, element: &N, name: &str) -> bool {
+    let value = element.attr(name);
+    check(root, matches!(value.as_deref(), Some("true" | "false")), || {
+        format!(
+            "expected `{}` to be \"true\" or \"false\", found {:?} on {}",
+            name,
+            value,
+            element.markup()
+        )
+    });
+    value.as_deref() == Some("true")
+}
+
+/// The element referenced by the `aria-controls` of `trigger`, which must exist within `root`.
+fn controlled<N: Queries>(root: &N, trigger: &N) -> N {
+    let id = trigger.attr("aria-controls").unwrap_or_default();
+    check(root, !id.is_empty(), || format!("expected `aria-controls` on {}", trigger.markup()));
+    match root.query_by_id(&id) {
+        Some(element) => element,
+        None => panic!(
+            "found no element with the id `{}` referenced by `aria-controls` of {}\nin:\n{}",
+            id,
+            trigger.markup(),
+            root.markup()
+        ),
+    }
+}
+
+/// Check the trigger of a dropdown menu within `container`: a button whose `aria-expanded` follows the `is-active`
+/// class of the container, unless it opens on hover, and whose `aria-controls` references a `menu` within it.
+fn check_menu_trigger<N: Queries>(root: &N, container: &N, trigger: &N) {
+    check(root, trigger.role().as_deref() == Some("button"), || {
+        format!("expected the role `button` on {}", trigger.markup())
+    });
+    let expanded = aria_bool(root, trigger, "aria-expanded");
+    let menu = controlled(root, trigger);
+    check(root, menu.role().as_deref() == Some("menu"), || {
+        format!("expected the role `menu` on {}", menu.markup())
+    });
+    check(root, container.descendants().contains(&menu), || {
+        format!("expected the menu {} within {}", menu.markup(), container.markup())
+    });
+    if !container.has_class("is-hoverable") {
+        check(root, expanded == container.has_class("is-active"), || {
+            format!(
+                "expected `aria-expanded=\"{}\"` to match the `is-active` class of {}",
+                expanded,
+                container.markup()
+            )
+        });
+    }
+}
+
+/// Check the ARIA contract of every [`Tabs`](crate::Tabs) list within `root`, and panic with the markup of `root` if
+/// it is broken or there are no tabs.
+///
+/// In every `tablist` exactly one `tab` has `aria-selected="true"` and all others `"false"`; every tab has an id and
+/// only the selected tab is in the tab order (`tabindex="0"`, `"-1"` for the others). A tab's `aria-controls`
+/// references a `tabpanel`, which is not `hidden` for the selected tab, and a panel's `aria-labelledby` references a
+/// `tab`.
+pub fn assert_tabs<N: Queries>(root: &N) {
+    check(root, !root.get_all_by_role("tab").is_empty(), || "found no tabs".to_owned());
+    for tablist in root.get_all_by_role("tablist") {
+        let tabs = tablist.get_all_by_role("tab");
+        if tabs.is_empty() {
+            continue;
+        }
+        let selected = tabs.iter().filter(|tab| aria_bool(root, tab, "aria-selected")).count();
+        check(root, selected == 1, || {
+            format!("expected one selected tab, found {} in {}", selected, tablist.markup())
+        });
+        for tab in &tabs {
+            let is_selected = aria_bool(root, tab, "aria-selected");
+            check(root, tab.attr("id").is_some_and(|id| !id.is_empty()), || {
+                format!("expected an id on {}", tab.markup())
+            });
+            let tabindex = if is_selected { "0" } else { "-1" };
+            check(root, tab.attr("tabindex").as_deref() == Some(tabindex), || {
+                format!("expected `tabindex=\"{}\"` on {}", tabindex, tab.markup())
+            });
+            if tab.attr("aria-controls").is_some() {
+                let panel = controlled(root, tab);
+                check(root, panel.role().as_deref() == Some("tabpanel"), || {
+                    format!("expected the role `tabpanel` on {}", panel.markup())
+                });
+                check(root, !is_selected || panel.attr("hidden").is_none(), || {
+                    format!("expected the panel of the selected tab to be visible: {}", panel.markup())
+                });
+            }
+        }
+    }
+    for panel in root.get_all_by_role("tabpanel") {
+        if let Some(id) = panel.attr("aria-labelledby") {
+            let tab = root.query_by_id(&id);
+            check(root, tab.as_ref().and_then(Queries::role).as_deref() == Some("tab"), || {
+                format!("expected `aria-labelledby` to reference a tab on {}", panel.markup())
+            });
+        }
+    }
+}
+
+/// Check the ARIA contract of every [`Dropdown`](crate::Dropdown) within `root`, and panic with the markup of
+/// `root` if it is broken or there are no dropdowns.
+///
+/// The trigger is a button with `aria-haspopup="menu"`; its `aria-expanded` is `"true"` exactly while the dropdown
+/// is active, except for hoverable dropdowns, and its `aria-controls` references the `menu` of the dropdown.
+pub fn assert_dropdowns<N: Queries>(root: &N) {
+    let mut found = 0;
+    for dropdown in root.find_all(|element| element.has_class("dropdown")) {
+        let Some(trigger) = dropdown
+            .find_all(|element| element.attr("aria-haspopup").as_deref() == Some("menu"))
+            .into_iter()
+            .next()
+        else {
+            continue;
+        };
+        check_menu_trigger(root, &dropdown, &trigger);
+        found += 1;
+    }
+    check(root, found > 0, || "found no dropdowns".to_owned());
+}
+
+/// Check the ARIA contract of every [`Navbar`](crate::Navbar) and [`NavbarDropdown`](crate::NavbarDropdown) within
+/// `root`, and panic with the markup of `root` if it is broken or there are no navbars.
+///
+/// The navbar is a labelled `navigation`. Its burger is a labelled button whose `aria-controls` references the
+/// `navbar-menu` and whose `aria-expanded` is `"true"` exactly while burger and menu are active. Navbar dropdowns
+/// follow the contract of [`assert_dropdowns`].
+pub fn assert_navbars<N: Queries>(root: &N) {
+    let navbars = root.find_all(|element| element.has_class("navbar"));
+    check(root, !navbars.is_empty(), || "found no navbars".to_owned());
+    for navbar in navbars {
+        check(root, navbar.role().as_deref() == Some("navigation"), || {
+            format!("expected the role `navigation` on {}", navbar.markup())
+        });
+        check(root, !root.name_of(&navbar).is_empty(), || {
+            format!("expected a label on {}", navbar.markup())
+        });
+
+        for burger in navbar.find_all(|element| element.has_class("navbar-burger")) {
+            check(root, burger.role().as_deref() == Some("button"), || {
+                format!("expected the role `button` on {}", burger.markup())
+            });
+            check(root, !root.name_of(&burger).is_empty(), || {
+                format!("expected a label on {}", burger.markup())
+            });
+            let expanded = aria_bool(root, &burger, "aria-expanded");
+            let menu = controlled(root, &burger);
+            check(root, menu.has_class("navbar-menu"), || {
+                format!("expected the burger to control the navbar menu, not {}", menu.markup())
+            });
+            check(
+                root,
+                expanded == burger.has_class("is-active") && expanded == menu.has_class("is-active"),
+                || {
+                    format!(
+                        "expected `aria-expanded=\"{}\"` to match the `is-active` class of burger and menu",
+                        expanded
+                    )
+                },
+            );
+        }
+
+        for dropdown in navbar.find_all(|element| element.has_class("has-dropdown")) {
+            let trigger = dropdown.find_all(|element| element.attr("aria-haspopup").is_some()).into_iter().next();
+            match trigger {
+                Some(trigger) => check_menu_trigger(root, &dropdown, &trigger),
+                None => panic!("expected a trigger with `aria-haspopup` in {}\nin:\n{}", dropdown.markup(), root.markup()),
+            }
+        }
+    }
+}
+
+/// Check the ARIA contract of every [`Modal`](crate::Modal) and [`ModalCard`](crate::ModalCard) within `root`, and
+/// panic with the markup of `root` if it is broken or there are no modals.
+///
+/// The modal is a `dialog` with an id, is active while it is open, and contains a labelled close button.
+pub fn assert_modals<N: Queries>(root: &N) {
+    let modals = root.find_all(|element| element.has_class("modal"));
+    check(root, !modals.is_empty(), || "found no modals".to_owned());
+    for modal in modals {
+        check(root, modal.role().as_deref() == Some("dialog"), || {
+            format!("expected the role `dialog` on {}", modal.markup())
+        });
+        check(root, modal.attr("id").is_some_and(|id| !id.is_empty()), || {
+            format!("expected an id on {}", modal.markup())
+        });
+        check(root, modal.attr("open").is_none() || modal.has_class("is-active"), || {
+            format!("expected the open modal to be active: {}", modal.markup())
+        });
+        let close = modal.find_all(|element| {
+            (element.has_class("modal-close") || element.has_class("delete"))
+                && element.role().as_deref() == Some("button")
+                && !root.name_of(element).is_empty()
+        });
+        check(root, !close.is_empty(), || {
+            format!("expected a labelled close button in {}", modal.markup())
+        });
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use yew::prelude::*;
+
+    use super::*;
+    use crate::testing::{Rendered, render_blocking};
+    use crate::{Dropdown, Modal, ModalCard, Navbar, NavbarDropdown, NavbarItem, TabItem, TabPanel, Tabs, TabsProvider};
+
+    fn settings_tabs() -> Html {
+        html! {
+            <TabsProvider default_active={1}>
+                <Tabs aria_label="Settings">
+                    <TabItem index={0} id="tab-profile" panel_id="panel-profile">{"Profile"}</TabItem>
+                    <TabItem index={1} id="tab-billing" panel_id="panel-billing">{"Billing"}</TabItem>
+                </Tabs>
+                <TabPanel index={0} id="panel-profile" tab_id="tab-profile">{"Profile form"}</TabPanel>
+                <TabPanel index={1} id="panel-billing" tab_id="tab-billing">{"Billing form"}</TabPanel>
+            </TabsProvider>
+        }
+    }
+
+    #[test]
+    fn tabs_keep_their_contract() {
+        let rendered = render_blocking(settings_tabs);
+        assert_tabs(&*rendered);
+        let billing = rendered.get_by_role_and_name("tab", "Billing");
+        assert_eq!(billing.attr("aria-selected").as_deref(), Some("true"));
+        assert!(rendered.get_by_text("Profile form").attr("hidden").is_some());
+        assert!(rendered.get_by_text("Billing form").attr("hidden").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one selected tab, found 2")]
+    fn tabs_with_two_selected_tabs_fail() {
+        let rendered = Rendered::parse(
+            r#"<ul role="tablist">
+                <li><a role="tab" id="a" aria-selected="true" tabindex="0">A</a></li>
+                <li><a role="tab" id="b" aria-selected="true" tabindex="0">B</a></li>
+            </ul>"#,
+        );
+        assert_tabs(&*rendered);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected the role `tabpanel`")]
+    fn tabs_controlling_no_panel_fail() {
+        let rendered = Rendered::parse(
+            r#"<ul role="tablist"><li><a role="tab" id="a" aria-selected="true" tabindex="0" aria-controls="p">A</a></li></ul>
+            <div id="p">Panel</div>"#,
+        );
+        assert_tabs(&*rendered);
+    }
+
+    #[test]
+    #[should_panic(expected = "found no tabs")]
+    fn tabs_fail_without_tabs() {
+        assert_tabs(&*render_blocking(|| html! { <p>{"Nothing"}</p> }));
+    }
+
+    #[test]
+    fn dropdowns_keep_their_contract() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Dropdown button_html={html! { {"Actions"} }}>
+                        <a class="dropdown-item">{"Edit"}</a>
+                    </Dropdown>
+                    <Dropdown hoverable=true button_html={html! { {"More"} }}>
+                        <a class="dropdown-item">{"Archive"}</a>
+                    </Dropdown>
+                </>
+            }
+        });
+        assert_dropdowns(&*rendered);
+        let trigger = rendered.get_by_role_and_name("button", "Actions");
+        assert_eq!(trigger.attr("aria-expanded").as_deref(), Some("false"));
+    }
+
+    #[test]
+    #[should_panic(expected = "to match the `is-active` class")]
+    fn dropdowns_expanded_while_closed_fail() {
+        let rendered = Rendered::parse(
+            r#"<div class="dropdown">
+                <button aria-haspopup="menu" aria-expanded="true" aria-controls="m">Actions</button>
+                <div id="m" role="menu"></div>
+            </div>"#,
+        );
+        assert_dropdowns(&*rendered);
+    }
+
+    #[test]
+    fn navbars_keep_their_contract() {
+        let rendered = render_blocking(|| {
+            html! {
+                <Navbar
+                    aria_label="Main"
+                    navstart={html! {
+                        <>
+                            <NavbarItem>{"Home"}</NavbarItem>
+                            <NavbarDropdown navlink={html! { {"More"} }}>
+                                <NavbarItem>{"About"}</NavbarItem>
+                            </NavbarDropdown>
+                        </>
+                    }}
+                />
+            }
+        });
+        assert_navbars(&*rendered);
+        assert_eq!(rendered.get_by_role("navigation").attr("aria-label").as_deref(), Some("Main"));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a label on <nav")]
+    fn navbars_without_label_fail() {
+        assert_navbars(&*Rendered::parse(r#"<nav class="navbar"></nav>"#));
+    }
+
+    #[test]
+    fn modals_keep_their_contract() {
+        let rendered = render_blocking(|| {
+            html! {
+                <>
+                    <Modal id="confirm" trigger={html! { <button>{"Open"}</button> }}>
+                        <p>{"Sure?"}</p>
+                    </Modal>
+                    <ModalCard title="Details" body={html! { {"Body"} }} trigger={html! { <button>{"Details"}</button> }} />
+                </>
+            }
+        });
+        assert_modals(&*rendered);
+        assert_eq!(rendered.get_all_by_role("dialog").len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a labelled close button")]
+    fn modals_without_close_button_fail() {
+        assert_modals(&*Rendered::parse(
+            r#"<dialog class="modal" id="m"><button class="modal-close"></button></dialog>"#,
+        ));
+    }
+}
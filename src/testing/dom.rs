@@ -0,0 +1,230 @@
+use std::ops::Deref;
+
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Element, Event, EventInit, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, InputEvent, InputEventInit, KeyboardEvent,
+    KeyboardEventInit, Node,
+};
+use yew::AppHandle;
+use yew::prelude::*;
+
+use super::queries::Queries;
+use super::{TestRoot, TestRootProps};
+
+impl Queries for Element {
+    fn tag(&self) -> String {
+        self.tag_name().to_ascii_lowercase()
+    }
+
+    fn attr(&self, name: &str) -> Option<String> {
+        self.get_attribute(name)
+    }
+
+    fn children(&self) -> Vec<Self> {
+        let children = Element::children(self);
+        (0..children.length()).filter_map(|index| children.item(index)).collect()
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        let mut child = self.first_child();
+        while let Some(node) = child {
+            match node.node_type() {
+                Node::TEXT_NODE => text.push_str(&node.text_content().unwrap_or_default()),
+                Node::ELEMENT_NODE => {
+                    let element: &Element = node.unchecked_ref();
+                    if !matches!(element.tag().as_str(), "style" | "script") {
+                        text.push_str(&Queries::text(element));
+                    }
+                }
+                _ => {}
+            }
+            child = node.next_sibling();
+        }
+        text
+    }
+
+    fn markup(&self) -> String {
+        self.outer_html()
+    }
+}
+
+/// Markup rendered into the document by [`mount`]; destroyed and removed from the document when dropped.
+///
+/// Dereferences to the element the markup is rendered into, so that all [`Queries`] search the whole output.
+pub struct Mounted {
+    root: Element,
+    handle: Option<AppHandle<TestRoot>>,
+}
+
+impl Deref for Mounted {
+    type Target = Element;
+
+    fn deref(&self) -> &Element {
+        &self.root
+    }
+}
+
+impl Drop for Mounted {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.destroy();
+        }
+        self.root.remove();
+    }
+}
+
+/// Render `html` into a new element at the end of the document body, within a fresh
+/// [`YbcIdProvider`](crate::YbcIdProvider), and wait until it is rendered.
+///
+/// Only works in a browser, e.g. in `wasm-bindgen-test` tests configured with `wasm_bindgen_test_configure!(run_in_browser)`.
+pub async fn mount(html: Html) -> Mounted {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .expect("mount needs a browser document");
+    let body = document.body().expect("mount needs a document body");
+    let root = document.create_element("div").expect("creating the root element failed");
+    body.append_child(&root).expect("appending the root element failed");
+    let handle = yew::Renderer::<TestRoot>::with_root_and_props(root.clone(), TestRootProps { html }).render();
+    settle().await;
+    Mounted { root, handle: Some(handle) }
+}
+
+/// Wait until pending renders and effects have run, e.g. after dispatching events yourself.
+pub async fn settle() {
+    TimeoutFuture::new(0).await;
+}
+
+/// Click `element` like a user would, and wait until the resulting renders have run.
+pub async fn click(element: &Element) {
+    match element.dyn_ref::<HtmlElement>() {
+        Some(element) => element.click(),
+        None => panic!("cannot click an element without HTML interface: {}", element.outer_html()),
+    }
+    settle().await;
+}
+
+/// Dispatch a bubbling `keydown` event with the [`key`](KeyboardEvent::key) `key`, e.g. `"Enter"` or `"ArrowDown"`,
+/// to `element`, and wait until the resulting renders have run.
+///
+/// Returns whether a handler prevented the default action, e.g. to tell handled keys from ignored ones.
+pub async fn key_down(element: &Element, key: &str) -> bool {
+    let init = KeyboardEventInit::new();
+    init.set_key(key);
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).expect("creating a keydown event failed");
+    let prevented = !element.dispatch_event(&event).unwrap_or(true);
+    settle().await;
+    prevented
+}
+
+/// Replace the value of the `<input>`, `<textarea>` or `<select>` `element` with `text`, dispatch the `input` and
+/// `change` events a user's edit would, and wait until the resulting renders have run.
+pub async fn input_text(element: &Element, text: &str) {
+    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        input.set_value(text);
+    } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+        textarea.set_value(text);
+    } else if let Some(select) = element.dyn_ref::<HtmlSelectElement>() {
+        select.set_value(text);
+    } else {
+        panic!("cannot input text into an element which is not a form control: {}", element.outer_html());
+    }
+
+    let init = InputEventInit::new();
+    init.set_bubbles(true);
+    init.set_data(Some(text));
+    let input = InputEvent::new_with_event_init_dict("input", &init).expect("creating an input event failed");
+    let _ = element.dispatch_event(&input);
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let change = Event::new_with_event_init_dict("change", &init).expect("creating a change event failed");
+    let _ = element.dispatch_event(&change);
+    settle().await;
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::testing::{assert_dropdowns, assert_tabs};
+    use crate::{Dropdown, Input, TabItem, Tabs};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[component(Echo)]
+    fn echo() -> Html {
+        let value = use_state(String::new);
+        let update = {
+            let value = value.clone();
+            Callback::from(move |text: String| value.set(text))
+        };
+        html! {
+            <>
+                <Input name="echo" value={(*value).clone()} {update} attrs={crate::TestAttr::test_id("input")} />
+                <p data-testid="echo">{(*value).clone()}</p>
+            </>
+        }
+    }
+
+    fn tabs() -> Html {
+        html! {
+            <Tabs aria_label="Sections">
+                <TabItem index={0}>{"One"}</TabItem>
+                <TabItem index={1}>{"Two"}</TabItem>
+            </Tabs>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn mount_renders_into_the_document_and_cleans_up() {
+        let mounted = mount(tabs()).await;
+        assert_tabs(&*mounted);
+        assert!(mounted.is_connected());
+        let root = mounted.root.clone();
+        drop(mounted);
+        assert!(!root.is_connected());
+    }
+
+    #[wasm_bindgen_test]
+    async fn click_selects_a_tab() {
+        let mounted = mount(tabs()).await;
+        click(&mounted.get_by_role_and_name("tab", "Two")).await;
+        assert_tabs(&*mounted);
+        assert_eq!(mounted.get_by_role_and_name("tab", "Two").attr("aria-selected").as_deref(), Some("true"));
+        assert_eq!(mounted.get_by_role_and_name("tab", "One").attr("tabindex").as_deref(), Some("-1"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn key_down_reports_handled_keys() {
+        let mounted = mount(html! {
+            <Dropdown button_html={html! { {"Actions"} }}>
+                <a class="dropdown-item">{"Edit"}</a>
+            </Dropdown>
+        })
+        .await;
+        let trigger = mounted.get_by_role_and_name("button", "Actions");
+        assert!(!key_down(&trigger, "x").await);
+        assert_eq!(trigger.attr("aria-expanded").as_deref(), Some("false"));
+
+        assert!(key_down(&trigger, "Enter").await);
+        assert_dropdowns(&*mounted);
+        assert_eq!(trigger.attr("aria-expanded").as_deref(), Some("true"));
+
+        assert!(key_down(&trigger, "Escape").await);
+        assert_dropdowns(&*mounted);
+        assert_eq!(trigger.attr("aria-expanded").as_deref(), Some("false"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_text_updates_controlled_inputs() {
+        let mounted = mount(html! { <Echo /> }).await;
+        input_text(&mounted.get_by_test_id("input"), "hello").await;
+        assert_eq!(mounted.get_by_test_id("echo").text(), "hello");
+        let input: HtmlInputElement = mounted.get_by_test_id("input").unchecked_into();
+        assert_eq!(input.value(), "hello");
+    }
+}
@@ -0,0 +1,475 @@
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::queries::Queries;
+use super::{TestRoot, TestRootProps};
+
+/// Elements which never have content or an end tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+enum RenderedNode {
+    Element(RenderedElement),
+    Text(String),
+}
+
+struct ElementData {
+    tag: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<RenderedNode>,
+}
+
+/// An element of a [`Rendered`] tree; cheap to clone.
+///
+/// Two elements are equal if they are the same element of the same tree.
+#[derive(Clone)]
+pub struct RenderedElement(Rc<ElementData>);
+
+impl PartialEq for RenderedElement {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for RenderedElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.markup())
+    }
+}
+
+impl RenderedElement {
+    /// All attributes in source order.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.attributes.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    fn write_markup(&self, out: &mut String) {
+        let data = &self.0;
+        if !data.tag.is_empty() {
+            out.push('<');
+            out.push_str(&data.tag);
+            for (name, value) in &data.attributes {
+                out.push_str(&format!(" {}=\"{}\"", name, escape(value, true)));
+            }
+            out.push('>');
+        }
+        for child in &data.children {
+            match child {
+                RenderedNode::Element(element) => element.write_markup(out),
+                RenderedNode::Text(text) if matches!(data.tag.as_str(), "style" | "script") => out.push_str(text),
+                RenderedNode::Text(text) => out.push_str(&escape(text, false)),
+            }
+        }
+        if !data.tag.is_empty() && !VOID_ELEMENTS.contains(&data.tag.as_str()) {
+            out.push_str(&format!("</{}>", data.tag));
+        }
+    }
+}
+
+impl Queries for RenderedElement {
+    fn tag(&self) -> String {
+        self.0.tag.clone()
+    }
+
+    fn attr(&self, name: &str) -> Option<String> {
+        self.0
+            .attributes
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.0
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                RenderedNode::Element(element) => Some(element.clone()),
+                RenderedNode::Text(_) => None,
+            })
+            .collect()
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for child in &self.0.children {
+            match child {
+                RenderedNode::Element(element) if matches!(element.0.tag.as_str(), "style" | "script") => {}
+                RenderedNode::Element(element) => text.push_str(&element.text()),
+                RenderedNode::Text(content) => text.push_str(content),
+            }
+        }
+        text
+    }
+
+    fn markup(&self) -> String {
+        let mut out = String::new();
+        self.write_markup(&mut out);
+        out
+    }
+}
+
+/// Markup rendered by [`render`]: the HTML source and the parsed tree of elements.
+///
+/// Dereferences to the root of the tree, a fragment without tag of its own, so that all [`Queries`] search the
+/// whole output.
+#[derive(Clone, Debug)]
+pub struct Rendered {
+    html: String,
+    root: RenderedElement,
+}
+
+impl Rendered {
+    /// Parse `html`, e.g. markup rendered elsewhere or a snapshot.
+    pub fn parse(html: impl Into<String>) -> Self {
+        let html = html.into();
+        let root = Parser::new(&html).parse();
+        Self { html, root }
+    }
+
+    /// The HTML source as rendered.
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+}
+
+impl Deref for Rendered {
+    type Target = RenderedElement;
+
+    fn deref(&self) -> &RenderedElement {
+        &self.root
+    }
+}
+
+/// Render the markup returned by `view` with the server-side renderer, within a fresh
+/// [`YbcIdProvider`](crate::YbcIdProvider).
+///
+/// `view` runs on the rendering thread, so the markup may hold values which are not `Send`, e.g. callbacks. Effects
+/// do not run during server-side rendering; use [`mount`](super::mount) to test behaviour.
+pub async fn render<F>(view: F) -> Rendered
+where
+    F: FnOnce() -> Html + Send + 'static,
+{
+    let html = yew::ServerRenderer::<TestRoot>::with_props(move || TestRootProps { html: view() })
+        .hydratable(false)
+        .render()
+        .await;
+    Rendered::parse(html)
+}
+
+/// [`render`] for synchronous tests on the host, e.g. `#[test]` functions.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn render_blocking<F>(view: F) -> Rendered
+where
+    F: FnOnce() -> Html + Send + 'static,
+{
+    block_on(render(view))
+}
+
+/// Poll `future` on the current thread until it completes; the server-side renderer runs on threads of its own.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+fn escape(text: &str, attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if attribute => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Replace character references such as `&amp;` or `&#39;`; unknown references are kept as they are.
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => match name.strip_prefix('#') {
+                    Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16).ok().and_then(char::from_u32),
+                    Some(decimal) => decimal.parse().ok().and_then(char::from_u32),
+                    None => None,
+                },
+            };
+            c.map(|c| (c, end + 1))
+        });
+        match decoded {
+            Some((c, length)) => {
+                out.push(c);
+                rest = &rest[length..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A lenient HTML parser for the markup of the server-side renderer: unknown end tags are ignored and open elements
+/// are closed at the end of the input.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+/// An element being parsed.
+struct OpenElement {
+    tag: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<RenderedNode>,
+}
+
+impl OpenElement {
+    fn close(self) -> RenderedElement {
+        RenderedElement(Rc::new(ElementData {
+            tag: self.tag,
+            attributes: self.attributes,
+            children: self.children,
+        }))
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn parse(mut self) -> RenderedElement {
+        let mut stack = vec![OpenElement {
+            tag: String::new(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }];
+        while !self.rest().is_empty() {
+            let rest = self.rest();
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                self.position += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                self.position += rest.find('>').map_or(rest.len(), |end| end + 1);
+            } else if let Some(end_tag) = rest.strip_prefix("</") {
+                let length = end_tag.find('>').unwrap_or(end_tag.len());
+                let tag = end_tag[..length].trim().to_ascii_lowercase();
+                self.position += 2 + (length + 1).min(end_tag.len());
+                if let Some(index) = stack.iter().rposition(|element| element.tag == tag)
+                    && index > 0
+                {
+                    while stack.len() > index {
+                        close_top(&mut stack);
+                    }
+                }
+            } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let (element, self_closing) = self.start_tag();
+                if element.tag == "style" || element.tag == "script" || element.tag == "textarea" || element.tag == "title" {
+                    let mut element = element;
+                    let end = format!("</{}", element.tag);
+                    let rest = self.rest();
+                    let length = rest.to_ascii_lowercase().find(&end).unwrap_or(rest.len());
+                    let content = &rest[..length];
+                    if !content.is_empty() {
+                        let content = if element.tag == "style" || element.tag == "script" {
+                            content.to_owned()
+                        } else {
+                            unescape(content)
+                        };
+                        element.children.push(RenderedNode::Text(content));
+                    }
+                    self.position += length;
+                    self.position += self.rest().find('>').map_or(self.rest().len(), |end| end + 1);
+                    push_child(&mut stack, RenderedNode::Element(element.close()));
+                } else if self_closing || VOID_ELEMENTS.contains(&element.tag.as_str()) {
+                    push_child(&mut stack, RenderedNode::Element(element.close()));
+                } else {
+                    stack.push(element);
+                }
+            } else {
+                let length = rest.char_indices().skip(1).find(|(_, c)| *c == '<').map_or(rest.len(), |(end, _)| end);
+                push_child(&mut stack, RenderedNode::Text(unescape(&rest[..length])));
+                self.position += length;
+            }
+        }
+        while stack.len() > 1 {
+            close_top(&mut stack);
+        }
+        stack.pop().unwrap().close()
+    }
+
+    /// Parse a start tag, returning the element and whether it ends with `/>`.
+    fn start_tag(&mut self) -> (OpenElement, bool) {
+        self.position += 1;
+        let name = self.take_while(|c| !c.is_whitespace() && c != '>' && c != '/');
+        let mut element = OpenElement {
+            tag: name.to_ascii_lowercase(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        };
+        loop {
+            self.take_while(char::is_whitespace);
+            let rest = self.rest();
+            if rest.is_empty() {
+                return (element, false);
+            } else if rest.starts_with("/>") {
+                self.position += 2;
+                return (element, true);
+            } else if rest.starts_with('>') {
+                self.position += 1;
+                return (element, false);
+            } else if rest.starts_with('/') {
+                self.position += 1;
+                continue;
+            }
+            let name = self
+                .take_while(|c| !c.is_whitespace() && c != '=' && c != '>' && c != '/')
+                .to_ascii_lowercase();
+            self.take_while(char::is_whitespace);
+            let value = if self.rest().starts_with('=') {
+                self.position += 1;
+                self.take_while(char::is_whitespace);
+                match self.rest().chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        self.position += 1;
+                        let value = self.take_while(|c| c != quote);
+                        self.position = (self.position + 1).min(self.input.len());
+                        unescape(value)
+                    }
+                    _ => unescape(self.take_while(|c| !c.is_whitespace() && c != '>')),
+                }
+            } else {
+                String::new()
+            };
+            element.attributes.push((name, value));
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+}
+
+fn push_child(stack: &mut [OpenElement], child: RenderedNode) {
+    let parent = stack.last_mut().unwrap();
+    // Merge adjacent texts, e.g. split around a comment.
+    if let (RenderedNode::Text(text), Some(RenderedNode::Text(previous))) = (&child, parent.children.last_mut()) {
+        previous.push_str(text);
+    } else {
+        parent.children.push(child);
+    }
+}
+
+fn close_top(stack: &mut Vec<OpenElement>) {
+    let element = stack.pop().unwrap().close();
+    push_child(stack, RenderedNode::Element(element));
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_elements_attributes_and_text() {
+        let rendered = Rendered::parse(r#"<div id="a" class='b c' hidden data-x=1><p>one<br>two</p><img src="x.png"/></div>"#);
+        let div = rendered.query_by_id("a").unwrap();
+        assert_eq!(div.tag(), "div");
+        assert_eq!(div.attr("class").as_deref(), Some("b c"));
+        assert_eq!(div.attr("hidden").as_deref(), Some(""));
+        assert_eq!(div.attr("data-x").as_deref(), Some("1"));
+        assert_eq!(div.attr("missing"), None);
+        let tags: Vec<String> = div.descendants().iter().map(Queries::tag).collect();
+        assert_eq!(tags, ["p", "br", "img"]);
+        assert_eq!(div.text(), "onetwo");
+    }
+
+    #[test]
+    fn unescapes_references_and_keeps_unknown_ones() {
+        let rendered = Rendered::parse(r#"<p title="a &quot;b&quot;">&lt;x&gt; &amp; &#39;y&#x27; &unknown; & z</p>"#);
+        let p = rendered.get_by_text("<x> & 'y' &unknown; & z");
+        assert_eq!(p.attr("title").as_deref(), Some("a \"b\""));
+    }
+
+    #[test]
+    fn skips_comments_and_keeps_raw_text_out_of_text() {
+        let rendered = Rendered::parse("<div>a<!-- <p>hidden</p> -->b<style>.x > p { color: red; }</style><textarea>&lt;c&gt;</textarea></div>");
+        let div = rendered.get_by_text("ab<c>");
+        assert_eq!(div.children().iter().map(Queries::tag).collect::<Vec<_>>(), ["style", "textarea"]);
+        assert_eq!(div.children()[0].markup(), "<style>.x > p { color: red; }</style>");
+        assert_eq!(div.children()[1].text(), "<c>");
+    }
+
+    #[test]
+    fn recovers_from_unbalanced_tags() {
+        let rendered = Rendered::parse("<ul><li>one</span><li>two</ul><p>three");
+        assert_eq!(rendered.find_all(|element| element.tag() == "li").len(), 2);
+        assert_eq!(rendered.get_by_text("three").tag(), "p");
+        assert!(!rendered.markup().contains("span"));
+        assert!(rendered.markup().ends_with("</ul><p>three</p>"));
+    }
+
+    #[test]
+    fn markup_escapes_text_and_attributes() {
+        let rendered = Rendered::parse(r#"<a href="?a=1&amp;b=&quot;2&quot;">1 &lt; 2</a>"#);
+        assert_eq!(rendered.markup(), r#"<a href="?a=1&amp;b=&quot;2&quot;">1 &lt; 2</a>"#);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_blocking_renders_components_with_fresh_ids() {
+        let view = || html! { <crate::Tabs><crate::TabItem index={0}>{"One"}</crate::TabItem></crate::Tabs> };
+        let first = render_blocking(view);
+        let second = render_blocking(view);
+        assert_eq!(first.get_by_role("tablist").attr("id").as_deref(), Some("tabs-1"));
+        assert_eq!(first.html(), second.html());
+    }
+}
@@ -0,0 +1,72 @@
+/*!
+Utilities for testing screens built from ybc components; enabled by the `testing` feature.
+
+Summary
+- [`render`] renders markup with the server-side renderer and parses the result into a [`Rendered`] tree, which works
+  in plain `#[test]` functions on the host through [`render_blocking`].
+- [`mount`] renders markup into the DOM of a browser, e.g. under `wasm-bindgen-test` in a headless browser, where
+  [`click`], [`key_down`] and [`input_text`] dispatch events like a user would.
+- The [`Queries`] trait finds elements by role, label, test id (`data-testid`) or text, in a rendered tree as well
+  as in the DOM. `get_*` queries panic with the searched markup unless there is exactly one match, `query_*` queries
+  return `None` instead when there is none, and `get_all_*` queries return every match.
+- [`assert_tabs`], [`assert_dropdowns`], [`assert_navbars`] and [`assert_modals`] check the ARIA attributes which
+  [`Tabs`](crate::Tabs), [`Dropdown`](crate::Dropdown), [`Navbar`](crate::Navbar) and [`Modal`](crate::Modal)
+  maintain.
+
+Markup is always rendered within a fresh [`YbcIdProvider`], so generated ids such as `"tabs-1"` do not depend on
+the order of tests.
+
+Example
+```rust,ignore
+use ybc::testing::{Queries, assert_tabs, render_blocking};
+
+#[test]
+fn settings_tabs() {
+    let rendered = render_blocking(|| html! { <SettingsPage /> });
+    assert_tabs(&*rendered);
+    let tab = rendered.get_by_role_and_name("tab", "Billing");
+    assert_eq!(tab.attr("aria-selected").as_deref(), Some("false"));
+}
+
+#[wasm_bindgen_test]
+async fn opens_the_dropdown() {
+    let mounted = mount(html! { <SettingsPage /> }).await;
+    click(&mounted.get_by_role_and_name("button", "Actions")).await;
+    assert_dropdowns(&*mounted);
+    assert!(mounted.query_by_role("menu").is_some());
+}
+```
+*/
+
+mod aria;
+mod dom;
+mod html;
+mod queries;
+
+pub use aria::{assert_dropdowns, assert_modals, assert_navbars, assert_tabs};
+pub use dom::{Mounted, click, input_text, key_down, mount, settle};
+#[cfg(all(test, not(target_arch = "wasm32")))]
+pub(crate) use html::block_on;
+#[cfg(not(target_arch = "wasm32"))]
+pub use html::render_blocking;
+pub use html::{Rendered, RenderedElement, render};
+pub use queries::Queries;
+
+use yew::prelude::*;
+
+use crate::YbcIdProvider;
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct TestRootProps {
+    html: Html,
+}
+
+/// The root of every rendered and mounted test tree.
+#[component(TestRoot)]
+fn test_root(props: &TestRootProps) -> Html {
+    html! {
+        <YbcIdProvider>
+            {props.html.clone()}
+        </YbcIdProvider>
+    }
+}
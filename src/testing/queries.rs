@@ -0,0 +1,304 @@
+/// Collapse runs of whitespace into single spaces and trim the ends, the way text is compared by all queries.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The role an element has without a `role` attribute, following the HTML-ARIA mapping for common elements.
+fn implicit_role<N: Queries>(element: &N) -> Option<&'static str> {
+    let role = match element.tag().as_str() {
+        "a" | "area" if element.attr("href").is_some() => "link",
+        "article" => "article",
+        "aside" => "complementary",
+        "button" => "button",
+        "dialog" => "dialog",
+        "footer" => "contentinfo",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" => "banner",
+        "hr" => "separator",
+        "img" if element.attr("alt").is_some_and(|alt| !alt.is_empty()) => "img",
+        "input" => match element.attr("type").unwrap_or_default().to_ascii_lowercase().as_str() {
+            "button" | "image" | "reset" | "submit" => "button",
+            "checkbox" => "checkbox",
+            "hidden" | "file" | "color" | "date" | "datetime-local" | "month" | "password" | "time" | "week" => return None,
+            "number" => "spinbutton",
+            "radio" => "radio",
+            "range" => "slider",
+            "search" => "searchbox",
+            _ => "textbox",
+        },
+        "li" => "listitem",
+        "main" => "main",
+        "nav" => "navigation",
+        "ol" | "ul" => "list",
+        "option" => "option",
+        "progress" => "progressbar",
+        "select" => {
+            let size = element.attr("size").and_then(|size| size.parse::<u32>().ok()).unwrap_or(0);
+            if element.attr("multiple").is_some() || size > 1 {
+                "listbox"
+            } else {
+                "combobox"
+            }
+        }
+        "table" => "table",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" => "columnheader",
+        "tr" => "row",
+        _ => return None,
+    };
+    Some(role)
+}
+
+/// Elements whose label comes from a `<label>` rather than their content.
+fn is_labelable(tag: &str) -> bool {
+    matches!(tag, "input" | "meter" | "output" | "progress" | "select" | "textarea")
+}
+
+/// Element lookups shared by [`Rendered`](super::Rendered) trees and DOM elements.
+///
+/// Implementors provide access to tags, attributes, child elements and text; every query searches the descendants
+/// of the element it is called on, in document order, and includes hidden elements. Texts, names and labels are
+/// compared after collapsing whitespace.
+pub trait Queries: Clone + PartialEq + Sized {
+    /// The lower case tag name, e.g. `"button"`.
+    fn tag(&self) -> String;
+    /// The value of the attribute `name`; `Some("")` for attributes without a value.
+    fn attr(&self, name: &str) -> Option<String>;
+    /// The child elements.
+    fn children(&self) -> Vec<Self>;
+    /// The text content, without the content of `<style>` and `<script>` elements.
+    fn text(&self) -> String;
+    /// The markup of this element, used in the messages of failed queries and assertions.
+    fn markup(&self) -> String;
+
+    /// Whether the `class` attribute contains `class`.
+    fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|name| name == class))
+    }
+
+    /// The explicit `role` or, without one, the implicit role of the element, e.g. `"button"` for a `<button>`.
+    fn role(&self) -> Option<String> {
+        match self.attr("role").as_deref().and_then(|role| role.split_whitespace().next()) {
+            Some(role) => Some(role.to_owned()),
+            None => implicit_role(self).map(str::to_owned),
+        }
+    }
+
+    /// All descendants in document order.
+    fn descendants(&self) -> Vec<Self> {
+        let mut found = Vec::new();
+        let mut stack: Vec<Self> = self.children().into_iter().rev().collect();
+        while let Some(element) = stack.pop() {
+            stack.extend(element.children().into_iter().rev());
+            found.push(element);
+        }
+        found
+    }
+
+    /// All descendants matching `predicate`.
+    fn find_all(&self, predicate: impl Fn(&Self) -> bool) -> Vec<Self> {
+        self.descendants().into_iter().filter(|element| predicate(element)).collect()
+    }
+
+    /// The descendant with the `id` attribute `id`.
+    fn query_by_id(&self, id: &str) -> Option<Self> {
+        self.descendants().into_iter().find(|element| element.attr("id").as_deref() == Some(id))
+    }
+
+    /// The accessible name of `element`, a descendant of this element: the text of the elements referenced by
+    /// `aria-labelledby`, the `aria-label`, the text of the `<label>` of a form control, the text content or the
+    /// `title`, whichever comes first.
+    fn name_of(&self, element: &Self) -> String {
+        if let Some(ids) = element.attr("aria-labelledby") {
+            let name = ids
+                .split_whitespace()
+                .filter_map(|id| self.query_by_id(id))
+                .map(|label| label.text())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !normalize(&name).is_empty() {
+                return normalize(&name);
+            }
+        }
+        if let Some(label) = element.attr("aria-label").filter(|label| !normalize(label).is_empty()) {
+            return normalize(&label);
+        }
+        if is_labelable(&element.tag()) {
+            let id = element.attr("id").filter(|id| !id.is_empty());
+            let label = self
+                .find_all(|candidate| candidate.tag() == "label")
+                .into_iter()
+                .find(|label| id.is_some() && label.attr("for") == id || label.descendants().contains(element));
+            if let Some(label) = label {
+                return normalize(&label.text());
+            }
+        } else if !normalize(&element.text()).is_empty() {
+            return normalize(&element.text());
+        }
+        normalize(&element.attr("title").unwrap_or_default())
+    }
+
+    /// All descendants with the role `role`.
+    fn get_all_by_role(&self, role: &str) -> Vec<Self> {
+        self.find_all(|element| element.role().as_deref() == Some(role))
+    }
+
+    /// The only descendant with the role `role`, if there is one.
+    fn query_by_role(&self, role: &str) -> Option<Self> {
+        single(self, self.get_all_by_role(role), || format!("role `{}`", role), false)
+    }
+
+    /// The only descendant with the role `role`.
+    fn get_by_role(&self, role: &str) -> Self {
+        single(self, self.get_all_by_role(role), || format!("role `{}`", role), true).unwrap()
+    }
+
+    /// The only descendant with the role `role` and the accessible name `name`, if there is one.
+    fn query_by_role_and_name(&self, role: &str, name: &str) -> Option<Self> {
+        let found = self.find_all(|element| element.role().as_deref() == Some(role) && self.name_of(element) == normalize(name));
+        single(self, found, || format!("role `{}` and name `{}`", role, name), false)
+    }
+
+    /// The only descendant with the role `role` and the accessible name `name`.
+    fn get_by_role_and_name(&self, role: &str, name: &str) -> Self {
+        let found = self.find_all(|element| element.role().as_deref() == Some(role) && self.name_of(element) == normalize(name));
+        single(self, found, || format!("role `{}` and name `{}`", role, name), true).unwrap()
+    }
+
+    /// All descendants labelled `label` by `aria-label`, `aria-labelledby` or a `<label>`.
+    fn get_all_by_label(&self, label: &str) -> Vec<Self> {
+        let label = normalize(label);
+        self.find_all(|element| {
+            (element.attr("aria-label").is_some() || element.attr("aria-labelledby").is_some() || is_labelable(&element.tag()))
+                && self.name_of(element) == label
+        })
+    }
+
+    /// The only descendant labelled `label`, if there is one.
+    fn query_by_label(&self, label: &str) -> Option<Self> {
+        single(self, self.get_all_by_label(label), || format!("label `{}`", label), false)
+    }
+
+    /// The only descendant labelled `label`.
+    fn get_by_label(&self, label: &str) -> Self {
+        single(self, self.get_all_by_label(label), || format!("label `{}`", label), true).unwrap()
+    }
+
+    /// All descendants with the `data-testid` attribute `test_id`, e.g. set through
+    /// [`TestAttr::test_id`](crate::TestAttr::test_id).
+    fn get_all_by_test_id(&self, test_id: &str) -> Vec<Self> {
+        self.find_all(|element| element.attr("data-testid").as_deref() == Some(test_id))
+    }
+
+    /// The only descendant with the `data-testid` attribute `test_id`, if there is one.
+    fn query_by_test_id(&self, test_id: &str) -> Option<Self> {
+        single(self, self.get_all_by_test_id(test_id), || format!("test id `{}`", test_id), false)
+    }
+
+    /// The only descendant with the `data-testid` attribute `test_id`.
+    fn get_by_test_id(&self, test_id: &str) -> Self {
+        single(self, self.get_all_by_test_id(test_id), || format!("test id `{}`", test_id), true).unwrap()
+    }
+
+    /// All innermost descendants with the text `text`; their ancestors, which contain the same text, are skipped.
+    fn get_all_by_text(&self, text: &str) -> Vec<Self> {
+        let text = normalize(text);
+        self.find_all(|element| normalize(&element.text()) == text && !element.children().iter().any(|child| normalize(&child.text()) == text))
+    }
+
+    /// The only innermost descendant with the text `text`, if there is one.
+    fn query_by_text(&self, text: &str) -> Option<Self> {
+        single(self, self.get_all_by_text(text), || format!("text `{}`", text), false)
+    }
+
+    /// The only innermost descendant with the text `text`.
+    fn get_by_text(&self, text: &str) -> Self {
+        single(self, self.get_all_by_text(text), || format!("text `{}`", text), true).unwrap()
+    }
+}
+
+/// The single element in `found`; panics with the markup of `root` when there are several, or none and `required`.
+fn single<N: Queries>(root: &N, mut found: Vec<N>, what: impl Fn() -> String, required: bool) -> Option<N> {
+    match found.len() {
+        0 if required => panic!("found no element with {} in:\n{}", what(), root.markup()),
+        0 => None,
+        1 => found.pop(),
+        count => panic!("found {} elements with {} instead of one in:\n{}", count, what(), root.markup()),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::Rendered;
+
+    const FORM: &str = r#"
+        <form aria-label="Profile">
+            <h2 id="title">Your   profile</h2>
+            <section aria-labelledby="title missing"></section>
+            <label for="name">Name</label>
+            <input id="name" type="text" data-testid="name-input">
+            <label><input type="checkbox" name="news"> Send news</label>
+            <input type="number" aria-label="Age">
+            <input type="hidden" name="token">
+            <select multiple><option>A</option></select>
+            <a href="/help" title="Help page"><img src="help.svg" alt=""></a>
+            <a>not a link</a>
+            <button type="submit">Save <span>now</span></button>
+            <div role="alert tooltip">Saved</div>
+        </form>
+    "#;
+
+    #[test]
+    fn roles_are_explicit_or_implicit() {
+        let rendered = Rendered::parse(FORM);
+        assert_eq!(rendered.get_by_role("alert").text(), "Saved");
+        assert_eq!(rendered.get_by_role("textbox").attr("id").as_deref(), Some("name"));
+        assert_eq!(rendered.get_by_role("spinbutton").attr("aria-label").as_deref(), Some("Age"));
+        assert_eq!(rendered.get_by_role("listbox").tag(), "select");
+        assert_eq!(rendered.get_all_by_role("link").len(), 1);
+        assert_eq!(rendered.get_all_by_role("heading").len(), 1);
+        assert!(rendered.query_by_role("img").is_none());
+    }
+
+    #[test]
+    fn names_follow_labelledby_label_content_and_title() {
+        let rendered = Rendered::parse(FORM);
+        let section = rendered.find_all(|element| element.tag() == "section").pop().unwrap();
+        assert_eq!(rendered.name_of(&section), "Your profile");
+        assert_eq!(rendered.name_of(&rendered.get_by_role("checkbox")), "Send news");
+        assert_eq!(rendered.name_of(&rendered.get_by_role("link")), "Help page");
+        assert_eq!(
+            rendered.get_by_role_and_name("button", "Save now").attr("type").as_deref(),
+            Some("submit")
+        );
+        assert!(rendered.query_by_role_and_name("button", "Cancel").is_none());
+    }
+
+    #[test]
+    fn finds_by_label_test_id_and_text() {
+        let rendered = Rendered::parse(FORM);
+        assert_eq!(rendered.get_by_label("Name").attr("data-testid").as_deref(), Some("name-input"));
+        assert_eq!(rendered.get_by_label("Age").attr("type").as_deref(), Some("number"));
+        assert_eq!(rendered.get_by_label("Profile").tag(), "form");
+        assert_eq!(rendered.get_by_test_id("name-input").attr("id").as_deref(), Some("name"));
+        assert_eq!(rendered.get_by_text("now").tag(), "span");
+        assert_eq!(rendered.get_by_text("Your profile").tag(), "h2");
+        assert!(rendered.query_by_text("Your").is_none());
+        assert!(rendered.query_by_test_id("missing").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "found no element with label `Email`")]
+    fn get_panics_without_match() {
+        Rendered::parse(FORM).get_by_label("Email");
+    }
+
+    #[test]
+    #[should_panic(expected = "found 2 elements with role `listitem` instead of one")]
+    fn query_panics_with_several_matches() {
+        Rendered::parse("<ul><li>a</li><li>b</li></ul>").query_by_role("listitem");
+    }
+}
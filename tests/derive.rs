@@ -0,0 +1,108 @@
+//! Tests of `#[derive(YbcForm)]`: the generated component renders one control per field, and unsupported inputs fail
+//! to compile with a pointed error (see `tests/ui`).
+#![cfg(all(feature = "derive", not(target_arch = "wasm32")))]
+
+use ybc::YbcForm;
+use ybc::testing::{Queries, render_blocking};
+use yew::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+enum Plan {
+    #[default]
+    Free,
+    #[ybc(label = "Team plan", value = "team")]
+    Team,
+}
+
+fn no_admin(name: &str) -> Result<(), String> {
+    if name == "admin" {
+        Err("Pick another name.".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+#[ybc(component = "SignupDialog", submit = "Sign up")]
+struct Signup {
+    #[ybc(required, min_length = 3, validate = "no_admin", placeholder = "jane")]
+    user_name: String,
+    #[ybc(email, help = "We never share it.")]
+    email: String,
+    #[ybc(password)]
+    password: String,
+    #[ybc(textarea, label = "About you")]
+    bio: String,
+    plan: Plan,
+    #[ybc(label = "Send me the newsletter")]
+    newsletter: bool,
+    #[ybc(skip)]
+    referrer: String,
+}
+
+#[test]
+fn enums_list_their_options() {
+    use ybc::FormOptions;
+
+    assert_eq!(Plan::options(), [Plan::Free, Plan::Team]);
+    assert_eq!(Plan::Team.option_value(), "team");
+    assert_eq!(Plan::Team.option_label(), "Team plan");
+    assert_eq!(Plan::Free.option_value(), "Free");
+    assert_eq!(Plan::Free.option_label(), "Free");
+    assert_eq!(Plan::from_option_value("team"), Some(Plan::Team));
+    assert_eq!(Plan::from_option_value("Team"), None);
+}
+
+fn by_name<Q: Queries>(rendered: &Q, name: &str) -> Q {
+    let mut found = rendered.find_all(|element| element.attr("name").as_deref() == Some(name));
+    assert_eq!(found.len(), 1, "expected one control named `{}`", name);
+    found.remove(0)
+}
+
+#[test]
+fn structs_render_a_control_per_field() {
+    let rendered = render_blocking(|| {
+        let initial = Signup {
+            plan: Plan::Team,
+            newsletter: true,
+            ..Signup::default()
+        };
+        html! { <SignupDialog {initial} /> }
+    });
+
+    let labels: Vec<_> = rendered
+        .find_all(|element| element.has_class("label"))
+        .iter()
+        .map(Queries::text)
+        .collect();
+    assert_eq!(labels, ["User name", "Email", "Password", "About you", "Plan"]);
+
+    let user_name = by_name(&*rendered, "user_name");
+    assert_eq!(user_name.tag(), "input");
+    assert_eq!(user_name.attr("placeholder").as_deref(), Some("jane"));
+    assert_eq!(by_name(&*rendered, "email").attr("type").as_deref(), Some("email"));
+    assert!(rendered.query_by_text("We never share it.").is_some());
+    assert_eq!(by_name(&*rendered, "password").attr("type").as_deref(), Some("password"));
+    assert_eq!(by_name(&*rendered, "bio").tag(), "textarea");
+    let plan = by_name(&*rendered, "plan");
+    assert_eq!(plan.tag(), "select");
+    let options: Vec<_> = plan
+        .children()
+        .iter()
+        .map(|option| (option.text(), option.attr("selected").is_some()))
+        .collect();
+    assert_eq!(options, [("Free".to_string(), false), ("Team plan".to_string(), true)]);
+    let newsletter = rendered.get_by_role_and_name("checkbox", "Send me the newsletter");
+    assert!(newsletter.attr("checked").is_some());
+    assert!(
+        rendered
+            .find_all(|element| element.attr("name").as_deref() == Some("referrer"))
+            .is_empty()
+    );
+    assert_eq!(rendered.get_by_role("button").attr("value").as_deref(), Some("Sign up"));
+}
+
+#[test]
+fn unsupported_inputs_fail_to_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,60 @@
+//! Hydration of server-rendered markup in the browser: the client render adopts the markup of the server render,
+//! with the same generated ids, instead of replacing it.
+#![cfg(all(target_arch = "wasm32", feature = "hydration"))]
+
+use std::rc::Rc;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use ybc::testing::{Queries, Rendered, settle};
+use ybc::{AccordionItem, Accordions, Button, Modal, TabItem, Tabs, YbcIdProvider};
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn Page() -> Html {
+    html! {
+        <YbcIdProvider>
+            <Tabs aria_label="Sections">
+                <TabItem index={0}>{"Overview"}</TabItem>
+                <TabItem index={1}>{"Details"}</TabItem>
+            </Tabs>
+            <Accordions id={Rc::from("faq")}>
+                <AccordionItem title={Rc::from("Why?")} open=true>{"Because."}</AccordionItem>
+                <AccordionItem title={Rc::from("How?")}>{"Like so."}</AccordionItem>
+            </Accordions>
+            <Modal trigger={html! { <Button>{"Open"}</Button> }}>{"Sure?"}</Modal>
+        </YbcIdProvider>
+    }
+}
+
+/// The markup of `root` without the comments marking component boundaries for hydration.
+fn markup(root: &web_sys::Element) -> String {
+    Rendered::parse(root.inner_html()).markup()
+}
+
+#[wasm_bindgen_test]
+async fn server_rendered_markup_hydrates_unchanged() {
+    let html = yew::LocalServerRenderer::<Page>::new().hydratable(true).render().await;
+    let document = web_sys::window().and_then(|window| window.document()).unwrap();
+    let root = document.create_element("div").unwrap();
+    root.set_inner_html(&html);
+    document.body().unwrap().append_child(&root).unwrap();
+    let server = markup(&root);
+    let tab = root.get_by_role_and_name("tab", "Overview");
+
+    let app = yew::Renderer::<Page>::with_root(root.clone()).hydrate();
+    settle().await;
+
+    assert_eq!(markup(&root), server);
+    // The server-rendered elements are kept, not replaced.
+    assert!(root.get_by_role_and_name("tab", "Overview") == tab);
+    let ids: Vec<_> = ["tabs-1", "accordion-item-1-header", "accordion-item-2-panel", "modal-1"]
+        .into_iter()
+        .map(|id| document.get_element_by_id(id).is_some())
+        .collect();
+    assert_eq!(ids, [true; 4]);
+
+    app.destroy();
+    root.remove();
+}
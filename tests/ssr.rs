@@ -0,0 +1,98 @@
+//! Server-side rendering of the components which use the DOM or a JS plugin on the client: they render their plain
+//! markup, in the state given by their props, without touching `window` or `document`.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::rc::Rc;
+
+use ybc::testing::{Queries, render_blocking};
+use ybc::{AccordionItem, Accordions, AutoComplete, Button, Calendar, Modal};
+use yew::prelude::*;
+
+#[test]
+fn calendars_render_the_month_of_their_date() {
+    let rendered = render_blocking(|| html! { <Calendar id="due" date={"2024-02-29".to_string()} on_date_changed={Callback::noop()} /> });
+
+    let input = rendered.find_all(|element| element.attr("id").as_deref() == Some("due")).remove(0);
+    assert_eq!(input.attr("value").as_deref(), Some("2024-02-29"));
+    assert_eq!(input.attr("aria-expanded").as_deref(), Some("false"));
+    assert!(rendered.query_by_text("February 2024").is_some());
+    let day = rendered.get_by_role_and_name("button", "2024-02-29");
+    assert_eq!(day.attr("tabindex").as_deref(), Some("0"));
+}
+
+#[test]
+fn autocompletes_render_their_tags_and_remaining_options() {
+    let rendered = render_blocking(|| {
+        html! {
+            <AutoComplete id={Rc::from("tags")} items={vec!["Rust".to_string(), "Go".to_string()]} current_selector={Rc::from("Rust")} />
+        }
+    });
+
+    let tags: Vec<_> = rendered.find_all(|element| element.has_class("tag")).iter().map(Queries::text).collect();
+    assert_eq!(tags, ["Rust"]);
+    assert!(rendered.query_by_role_and_name("button", "remove Rust").is_some());
+    assert_eq!(rendered.get_by_role("combobox").attr("id").as_deref(), Some("tags"));
+    let options: Vec<_> = rendered
+        .get_by_role("listbox")
+        .get_all_by_role("option")
+        .iter()
+        .map(Queries::text)
+        .collect();
+    assert_eq!(options, ["Go"]);
+}
+
+#[test]
+fn accordions_render_their_items_open_or_closed() {
+    let rendered = render_blocking(|| {
+        html! {
+            <Accordions id={Rc::from("faq")}>
+                <AccordionItem title={Rc::from("Why?")} open=true>{"Because."}</AccordionItem>
+                <AccordionItem title={Rc::from("How?")}>{"Like so."}</AccordionItem>
+            </Accordions>
+        }
+    });
+
+    let expanded: Vec<_> = rendered
+        .find_all(|element| element.has_class("accordion-header"))
+        .iter()
+        .map(|header| (header.text(), header.attr("aria-expanded").unwrap_or_default()))
+        .collect();
+    assert_eq!(
+        expanded,
+        [("Why?".to_string(), "true".to_string()), ("How?".to_string(), "false".to_string())]
+    );
+    let hidden: Vec<_> = rendered
+        .get_all_by_role("region")
+        .iter()
+        .map(|panel| panel.attr("aria-hidden").unwrap_or_default())
+        .collect();
+    assert_eq!(hidden, ["false", "true"]);
+}
+
+#[test]
+fn modals_render_their_dialog_and_trigger() {
+    let rendered = render_blocking(|| {
+        html! {
+            <>
+                <Modal id="confirm" trigger={html! { <Button>{"Open"}</Button> }}>{"Sure?"}</Modal>
+                <Modal id="shown" open={true} set_open={Callback::noop()}>{"Shown"}</Modal>
+            </>
+        }
+    });
+
+    assert!(rendered.query_by_role_and_name("button", "Open").is_some());
+    let dialogs: Vec<_> = rendered
+        .find_all(|element| element.tag() == "dialog")
+        .iter()
+        .map(|dialog| (dialog.attr("id").unwrap_or_default(), dialog.has_class("is-active"), dialog.text()))
+        .collect();
+    assert_eq!(
+        dialogs,
+        [
+            ("confirm".to_string(), false, "Sure?".to_string()),
+            ("shown".to_string(), true, "Shown".to_string())
+        ]
+    );
+    // A server-rendered dialog is never shown natively; the client opens it once hydrated.
+    assert!(rendered.find_all(|element| element.attr("open").is_some()).is_empty());
+}
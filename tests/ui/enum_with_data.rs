@@ -0,0 +1,10 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Default, PartialEq, YbcForm)]
+enum Contact {
+    #[default]
+    None,
+    Email(String),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Default, PartialEq, YbcForm)]
+struct Entry<T> {
+    value: T,
+}
+
+fn main() {}
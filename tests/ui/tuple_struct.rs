@@ -0,0 +1,6 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Default, PartialEq, YbcForm)]
+struct Name(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Copy, YbcForm)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
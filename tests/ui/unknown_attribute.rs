@@ -0,0 +1,9 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Default, PartialEq, YbcForm)]
+struct Profile {
+    #[ybc(colour = "red")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ybc::YbcForm;
+
+#[derive(Clone, Default, PartialEq, YbcForm)]
+struct Order {
+    quantity: u32,
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 use crate::router::{Route, SwitchRoutes};
 use crate::ui::CatalogList;
 use std::rc::Rc;
-use ybc::{FaIcon, NavBurgerCloserState, Navbar, NavbarItem};
+use ybc::{FaIcon, NavBurgerCloserState, Navbar, NavbarItem, ThemeProvider, YbcIdProvider, use_theme};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -10,38 +10,63 @@ pub fn app() -> Html {
     let state = Rc::new(NavBurgerCloserState { total_clicks: 0 });
     html! {
         <ContextProvider<Rc<NavBurgerCloserState>> context={state}>
-            <BrowserRouter>
-                <Navbar
-                    navbrand={html!{
-                        <NavbarItem>
-                            <ybc::Title classes={classes!("has-text-white", "is-flex", "is-align-items-center")} size={ybc::HeaderSize::Is4}>
-                                <FaIcon classes={classes!("mr-2")} icon_classes={classes!("fa-solid", "fa-cubes")} />
-                                {"Trunk | Yew | YBC"}
-                            </ybc::Title>
-                        </NavbarItem>
-                    }}
-                    navstart={html!{
-                        <>
-                            <NavbarItem>
-                                <Link<Route> to={Route::Home}>{"Home"}</Link<Route>>
-                            </NavbarItem>
-                            <NavbarItem>
-                                <a href="https://crates.io/crates/ybc" target="_blank">{"YBC crate"}</a>
-                            </NavbarItem>
-                            <NavbarItem>
-                                <a href="https://bulma.io/documentation/" target="_blank">{"Bulma docs"}</a>
-                            </NavbarItem>
-                        </>
-                    }}
-                >
-                </Navbar>
+            <ThemeProvider>
+                <YbcIdProvider>
+                    <BrowserRouter>
+                        <Navbar
+                            navbrand={html!{
+                                <NavbarItem>
+                                    <ybc::Title classes={classes!("has-text-white", "is-flex", "is-align-items-center")} size={ybc::HeaderSize::Is4}>
+                                        <FaIcon classes={classes!("mr-2")} icon_classes={classes!("fa-solid", "fa-cubes")} />
+                                        {"Trunk | Yew | YBC"}
+                                    </ybc::Title>
+                                </NavbarItem>
+                            }}
+                            navstart={html!{
+                                <>
+                                    <NavbarItem>
+                                        <Link<Route> to={Route::Home}>{"Home"}</Link<Route>>
+                                    </NavbarItem>
+                                    <NavbarItem>
+                                        <a href="https://crates.io/crates/ybc" target="_blank">{"YBC crate"}</a>
+                                    </NavbarItem>
+                                    <NavbarItem>
+                                        <a href="https://bulma.io/documentation/" target="_blank">{"Bulma docs"}</a>
+                                    </NavbarItem>
+                                </>
+                            }}
+                            navend={html!{
+                                <NavbarItem>
+                                    <ThemeToggle />
+                                </NavbarItem>
+                            }}
+                        >
+                        </Navbar>
 
-                <SwitchRoutes/>
-            </BrowserRouter>
+                        <SwitchRoutes/>
+                    </BrowserRouter>
+                </YbcIdProvider>
+            </ThemeProvider>
         </ContextProvider<Rc<NavBurgerCloserState>>>
     }
 }
 
+#[component(ThemeToggle)]
+fn theme_toggle() -> Html {
+    let theme = use_theme();
+    let (icon, label) = if theme.is_dark() {
+        ("fa-sun", "Switch to light mode")
+    } else {
+        ("fa-moon", "Switch to dark mode")
+    };
+    let onclick = Callback::from(move |_: MouseEvent| theme.toggle());
+    html! {
+        <button class="button is-small is-dark" type="button" aria-label={label} title={label} {onclick}>
+            <FaIcon icon_classes={classes!("fa-solid", icon)} />
+        </button>
+    }
+}
+
 #[component(HomePage)]
 pub fn home_page() -> Html {
     html! {
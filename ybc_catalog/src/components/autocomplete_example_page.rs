@@ -1,8 +1,32 @@
 use std::rc::Rc;
-use ybc::components::autocomplete::AutoComplete;
+use ybc::components::autocomplete::{AutoComplete, AutoCompleteItem, SuggestSource};
 use yew::prelude::*;
 use crate::ui::DemoTitle;
 
+#[derive(Clone, PartialEq)]
+struct Language {
+    code: &'static str,
+    name: &'static str,
+}
+
+const LANGUAGES: &[Language] = &[
+    Language { code: "rs", name: "Rust" },
+    Language { code: "ts", name: "TypeScript" },
+    Language { code: "go", name: "Go" },
+    Language { code: "py", name: "Python" },
+    Language { code: "kt", name: "Kotlin" },
+];
+
+impl AutoCompleteItem for Language {
+    fn label(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn key(&self) -> String {
+        self.code.to_string()
+    }
+}
+
 #[component(AutocompleteExamplePage)]
 pub fn autocomplete_example_page() -> Html {
     let selected = use_state(|| Vec::<String>::new());
@@ -32,6 +56,35 @@ pub fn autocomplete_example_page() -> Html {
         .map(|s| s.to_string())
         .collect::<Vec<_>>();
 
+    // An in-memory index queried through the async `Suggest` source.
+    let suggest = use_memo((), |_| {
+        SuggestSource::new(|query: String| async move {
+            let query = query.to_lowercase();
+            LANGUAGES
+                .iter()
+                .filter(|language| language.name.to_lowercase().contains(&query))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+    let languages = use_state(Vec::<&'static str>::new);
+    let on_language_select = {
+        let languages = languages.clone();
+        Callback::from(move |language: Language| {
+            let mut v = (*languages).clone();
+            v.push(language.code);
+            languages.set(v);
+        })
+    };
+    let on_language_deselect = {
+        let languages = languages.clone();
+        Callback::from(move |language: Language| {
+            let mut v = (*languages).clone();
+            v.retain(|code| *code != language.code);
+            languages.set(v);
+        })
+    };
+
     html! {
             <ybc::Section>
                 <ybc::Container classes={classes!("content")}>
@@ -64,6 +117,19 @@ pub fn autocomplete_example_page() -> Html {
                             }
                         }
                     </div>
+
+                    <p class="is-size-6 mt-5">{ "Typed items from an async source" }</p>
+                    <AutoComplete<Language>
+                        id={Rc::<str>::from("ac-typed")}
+                        suggest={(*suggest).clone()}
+                        on_select={on_language_select}
+                        on_deselect={on_language_deselect}
+                        placeholder={Rc::<str>::from("Search languages")}
+                    />
+                    <div class="is-size-7 mt-3">
+                        <span class="has-text-weight-semibold">{ "Codes: " }</span>
+                        { languages.join(", ") }
+                    </div>
                 </ybc::Container>
             </ybc::Section>
         }
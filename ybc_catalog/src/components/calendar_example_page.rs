@@ -12,11 +12,32 @@ pub fn calendar_example_page() -> Html {
         })
     };
 
+    let range = use_state(|| Option::<CalendarValue>::None);
+    let on_range_change = {
+        let range = range.clone();
+        Callback::from(move |value: Option<CalendarValue>| range.set(value))
+    };
+    let today = CalendarDate::today();
+
+    let report_range = use_state(|| CalendarRange::new(today.add_days(-6), today));
+    let on_report_range_changed = {
+        let report_range = report_range.clone();
+        Callback::from(move |value: Option<CalendarRange>| report_range.set(value))
+    };
+    let presets = use_memo((), |_| {
+        vec![
+            RangePreset::last_days("Last 7 days", 7),
+            RangePreset::last_days("Last 30 days", 30),
+            RangePreset::this_month("This month"),
+            RangePreset::last_month("Last month"),
+        ]
+    });
+
     html! {
         <ybc::Section>
           <ybc::Container classes={classes!("content")}> 
             <DemoTitle title={"Calendar"} icon_classes={classes!("fa-solid", "fa-calendar-days")} />
-            <p class="is-size-6">{"bulma-calendar date/time picker wrapped in Yew."}</p>
+            <p class="is-size-6">{"Native date/time picker rendered with Bulma elements."}</p>
 
             <ybc::Field>
               <ybc::Tag tag="Label">{"Pick date & time"}</ybc::Tag>
@@ -34,6 +55,61 @@ pub fn calendar_example_page() -> Html {
                 }
               }
             </div>
+
+            <ybc::Field>
+              <ybc::Tag tag="Label">{"Pick a range within the next 90 days (weeks start on Monday, German names)"}</ybc::Tag>
+              <ybc::Control>
+                <Calendar
+                    id="demo-calendar-range"
+                    calendar_type="range"
+                    date_format="d. MMMM yyyy"
+                    locale="de-DE"
+                    week_start={Weekday::Monday}
+                    min_date={Some(today)}
+                    max_date={Some(today.add_days(90))}
+                    on_date_changed={Callback::noop()}
+                    on_change={on_range_change}
+                />
+              </ybc::Control>
+            </ybc::Field>
+
+            <div class="is-size-7 mt-3">
+              <span class="has-text-weight-semibold">{"Typed range:"}</span>
+              { match &*range {
+                  Some(CalendarValue { date: Some(start), end_date: Some(end), .. }) => html!{
+                      <span class="tag is-link is-light ml-2">{ format!("{:?} .. {:?}", start, end) }</span>
+                  },
+                  _ => html!{ <span class="has-text-grey">{" none"}</span> },
+                }
+              }
+            </div>
+
+            <ybc::Field>
+              <ybc::Tag tag="Label">{"Report period (controlled start/end with presets)"}</ybc::Tag>
+              <ybc::Control>
+                <Calendar
+                    id="demo-calendar-report"
+                    calendar_type="range"
+                    start={report_range.map(|range| range.start())}
+                    end={report_range.map(|range| range.end())}
+                    max_date={Some(today)}
+                    range_presets={(*presets).clone()}
+                    on_date_changed={Callback::noop()}
+                    on_range_changed={on_report_range_changed}
+                />
+              </ybc::Control>
+            </ybc::Field>
+
+            <div class="is-size-7 mt-3">
+              <span class="has-text-weight-semibold">{"Report period:"}</span>
+              { match *report_range {
+                  Some(range) => html!{
+                      <span class="tag is-link is-light ml-2">{ format!("{:?} .. {:?} ({} days)", range.start(), range.end(), range.days()) }</span>
+                  },
+                  None => html!{ <span class="has-text-grey">{" none"}</span> },
+                }
+              }
+            </div>
           </ybc::Container>
         </ybc::Section>
     }
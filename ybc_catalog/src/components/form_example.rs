@@ -2,6 +2,401 @@ use ybc::*;
 use yew::prelude::*;
 use crate::ui::DemoTitle;
 
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Signup {
+    username: String,
+    email: String,
+    password: String,
+    confirm: String,
+    terms: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+enum Theme {
+    #[default]
+    Light,
+    Dark,
+    #[ybc(label = "Follow the system")]
+    System,
+}
+
+fn no_spaces(value: &str) -> Result<(), String> {
+    if value.contains(' ') { Err("No spaces, please.".into()) } else { Ok(()) }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+#[ybc(submit = "Save settings")]
+struct Settings {
+    #[ybc(required, max_length = 40, placeholder = "Jane Doe")]
+    display_name: String,
+    #[ybc(required = "We need an address to reach you.", email)]
+    email: String,
+    #[ybc(validate = "no_spaces", help = "Letters and digits only.")]
+    handle: String,
+    #[ybc(textarea, max_length = 200)]
+    bio: String,
+    #[ybc(help = "Applies to all devices.")]
+    theme: Theme,
+    #[ybc(label = "Send me the newsletter")]
+    newsletter: bool,
+    #[ybc(label = "Avatar")]
+    avatar: Vec<web_sys::File>,
+}
+
+/// Stands in for a server lookup: answers after 600ms that a few names are taken.
+fn username_available() -> AsyncValidator<String> {
+    AsyncValidator::new(|username: String| async move {
+        gloo_timers::future::TimeoutFuture::new(600).await;
+        match username.to_lowercase().as_str() {
+            "admin" | "root" | "alice" => Err(format!("\"{}\" is already taken.", username)),
+            _ => Ok(()),
+        }
+    })
+    .debounce(400)
+}
+
+#[component(InputTypes)]
+fn input_types() -> Html {
+    let values = use_state(|| vec![String::new(); 6]);
+    let setter = |index: usize| {
+        let values = values.clone();
+        Callback::from(move |value: String| {
+            let mut next = (*values).clone();
+            next[index] = value;
+            values.set(next);
+        })
+    };
+    let last_key = use_state(String::new);
+    let onkeydown = {
+        let last_key = last_key.clone();
+        Callback::from(move |event: KeyboardEvent| last_key.set(event.key()))
+    };
+
+    html! {
+      <>
+        <Field label="Website" help="Type a few letters for suggestions.">
+          <Control>
+            <Input name="website" value={values[0].clone()} update={setter(0)} r#type={InputType::Url} list="website-suggestions"
+                   autocomplete="url" placeholder="https://" pattern="https://.*" {onkeydown} />
+            <datalist id="website-suggestions">
+              <option value="https://bulma.io" />
+              <option value="https://yew.rs" />
+            </datalist>
+          </Control>
+        </Field>
+        <Field label="Search">
+          <Control><Input name="search" value={values[1].clone()} update={setter(1)} r#type={InputType::Search} aria_label="Search the catalog" /></Control>
+        </Field>
+        <Field grouped=true>
+          <Control><Input name="date" value={values[2].clone()} update={setter(2)} r#type={InputType::Date} min="2024-01-01" max="2030-12-31" required=true /></Control>
+          <Control><Input name="time" value={values[3].clone()} update={setter(3)} r#type={InputType::Time} /></Control>
+          <Control><Input name="color" value={values[4].clone()} update={setter(4)} r#type={InputType::Color} /></Control>
+        </Field>
+        <Field label="Volume">
+          <Control><Input name="volume" value={values[5].clone()} update={setter(5)} r#type={InputType::Range} min="0" max="100" step={5.0} /></Control>
+        </Field>
+        <p class="is-size-7">{format!("{:?}, last key in website: {}", *values, *last_key)}</p>
+      </>
+    }
+}
+
+#[component(NumberInputs)]
+fn number_inputs() -> Html {
+    let price = use_state(|| Some(19.9f64));
+    let quantity = use_state(|| Some(1u32));
+    let offset = use_state(|| Option::<i64>::None);
+    let set = |state: &UseStateHandle<Option<f64>>| {
+        let state = state.clone();
+        Callback::from(move |value| state.set(value))
+    };
+    let set_quantity = {
+        let quantity = quantity.clone();
+        Callback::from(move |value| quantity.set(value))
+    };
+    let set_offset = {
+        let offset = offset.clone();
+        Callback::from(move |value| offset.set(value))
+    };
+    let german = Callback::from(|error: NumberInputError| match error {
+        NumberInputError::TooManyDecimals(places) => format!("Höchstens {} Nachkommastellen.", places),
+        _ => "Bitte eine Zahl eingeben.".to_string(),
+    });
+
+    html! {
+      <>
+        <Field grouped=true>
+          <Control>
+            <label class="label is-small">{"Preis (EUR, 1.234,56)"}</label>
+            <NumberInput<f64> name="price" value={*price} update={set(&price)} precision={Some(2)} negative=false
+                              decimal_separator=',' grouping_separator={Some('.')} validity_message={german} />
+          </Control>
+          <Control>
+            <label class="label is-small">{"Quantity (1–99, arrow keys)"}</label>
+            <NumberInput<u32> name="quantity" value={*quantity} update={set_quantity} min={Some(1)} max={Some(99)} step={Some(1)} />
+          </Control>
+          <Control>
+            <label class="label is-small">{"Offset (whole, may be negative)"}</label>
+            <NumberInput<i64> name="offset" value={*offset} update={set_offset} step={Some(10)} placeholder="0" />
+          </Control>
+        </Field>
+        <p class="is-size-7">{format!("price: {:?}, quantity: {:?}, offset: {:?}", *price, *quantity, *offset)}</p>
+      </>
+    }
+}
+
+#[component(MaskedInputs)]
+fn masked_inputs() -> Html {
+    let phone = use_state(MaskedValue::default);
+    let iban = use_state(MaskedValue::default);
+    let timecode = use_state(MaskedValue::default);
+    let set = |state: &UseStateHandle<MaskedValue>| {
+        let state = state.clone();
+        Callback::from(move |value| state.set(value))
+    };
+
+    html! {
+      <>
+        <Field label="Phone" help="Try pasting +1 555 123 4567.">
+          <Control>
+            <MaskedInput name="phone" mask="(999) 999-9999" value={phone.raw.clone()} update={set(&phone)}
+                         r#type={InputType::Tel} placeholder="(___) ___-____" autocomplete="tel-national" />
+          </Control>
+        </Field>
+        <Field label="IBAN">
+          <Control>
+            <MaskedInput name="iban" mask="aa99 **** **** **** **** **" value={iban.raw.clone()} update={set(&iban)} uppercase=true />
+          </Control>
+        </Field>
+        <Field label="Time code">
+          <Control>
+            <MaskedInput name="timecode" mask="99:99:99:99" value={timecode.raw.clone()} update={set(&timecode)} placeholder="hh:mm:ss:ff" />
+          </Control>
+        </Field>
+        <p class="is-size-7">
+          {format!("phone: {:?} ({}), iban: {:?}, time code: {:?}", phone.raw, if phone.complete { "complete" } else { "incomplete" }, iban.formatted, timecode.formatted)}
+        </p>
+      </>
+    }
+}
+
+#[component(SignupForm)]
+fn signup_form() -> Html {
+    let form = use_form::<Signup>();
+    let username = form.register(
+        FormFieldDef::new("username", |signup: &Signup| signup.username.clone(), |signup, value| signup.username = value)
+            .validate(Validator::required("Please choose a username."))
+            .validate(Validator::min_length(3, "At least 3 characters."))
+            .validate(Validator::max_length(20, "At most 20 characters."))
+            .validate_async(username_available()),
+    );
+    let email = form.register(
+        FormFieldDef::new("email", |signup: &Signup| signup.email.clone(), |signup, value| signup.email = value)
+            .validate(Validator::required("Please enter your email address."))
+            .validate(Validator::email("This is not an email address.")),
+    );
+    let password = form.register(
+        FormFieldDef::new("password", |signup: &Signup| signup.password.clone(), |signup, value| signup.password = value)
+            .validate(Validator::required("Please choose a password."))
+            .validate(Validator::min_length(8, "At least 8 characters.")),
+    );
+    let confirm = form.register(
+        FormFieldDef::new("confirm", |signup: &Signup| signup.confirm.clone(), |signup, value| signup.confirm = value)
+            .validate_with(|confirm, signup| if *confirm == signup.password { Ok(()) } else { Err("The passwords differ.".into()) }),
+    );
+    let terms = form.register(
+        FormFieldDef::new("terms", |signup: &Signup| signup.terms, |signup, value| signup.terms = value)
+            .validate(Validator::required("Please accept the terms.")),
+    );
+
+    let submitted = use_state(|| Option::<Signup>::None);
+    let onsubmit = {
+        let submitted = submitted.clone();
+        Callback::from(move |signup: Signup| submitted.set(Some(signup)))
+    };
+    let onreset = {
+        let form = form.clone();
+        Callback::from(move |_: MouseEvent| form.reset())
+    };
+
+    html! {
+      <Form<Signup> form={form.clone()} {onsubmit} aria_label="Sign up">
+        <Field label="Username" name="username" help="Shown to other users; try \"admin\".">
+          <Control loading={username.is_pending()}><Input name={username.name()} value={username.value()} update={username.update()} /></Control>
+        </Field>
+        <Field label="Email" name="email">
+          <Control><Input name={email.name()} value={email.value()} update={email.update()} r#type={InputType::Email} /></Control>
+        </Field>
+        <Field label="Password" name="password">
+          <Control><Input name={password.name()} value={password.value()} update={password.update()} r#type={InputType::Password} /></Control>
+        </Field>
+        <Field label="Confirm password" name="confirm">
+          <Control><Input name={confirm.name()} value={confirm.value()} update={confirm.update()} r#type={InputType::Password} /></Control>
+        </Field>
+        <Field name="terms">
+          <Control><Checkbox name={terms.name()} checked={terms.value()} update={terms.update()}>{" I accept the terms"}</Checkbox></Control>
+        </Field>
+        <Field grouped=true>
+          <Control><ButtonInputSubmit value="Sign up" classes={classes!("is-primary")} disabled={form.is_pending()} /></Control>
+          <Control><Button onclick={onreset} disabled={!form.is_dirty()}>{"Reset"}</Button></Control>
+        </Field>
+        <p class="is-size-7">
+          {format!("Dirty: {}, submit attempts: {}", form.is_dirty(), form.submit_count())}
+          {submitted.as_ref().map(|signup| format!(", submitted: {} <{}>", signup.username, signup.email)).unwrap_or_default()}
+        </p>
+      </Form<Signup>>
+    }
+}
+
+#[component(FileDropZones)]
+fn file_drop_zones() -> Html {
+    let images = use_state(Vec::<web_sys::File>::new);
+    let rejected = use_state(|| 0usize);
+    let update = {
+        let images = images.clone();
+        Callback::from(move |files| images.set(files))
+    };
+    let on_reject = {
+        let rejected = rejected.clone();
+        Callback::from(move |files: Vec<FileRejection>| rejected.set(*rejected + files.len()))
+    };
+
+    html! {
+      <>
+        <Field label="Photos" help="Up to 3 images of at most 2 MB each.">
+          <Control>
+            <ybc::File name="photos" files={(*images).clone()} {update} {on_reject} drop_zone=true multiple=true
+                       accept="image/*" max_size={2 * 1024 * 1024} max_files={3}
+                       selector_icon={html! {<i class="fas fa-upload"></i>}} />
+          </Control>
+        </Field>
+        <p class="is-size-7">
+          {format!("{} selected ({}), {} rejected so far", images.len(), format_file_size(images.iter().map(|file| file.size()).sum()), *rejected)}
+        </p>
+      </>
+    }
+}
+
+#[component(Uploads)]
+fn uploads() -> Html {
+    let files = use_state(Vec::<web_sys::File>::new);
+    let uploader = use_memo((), |_| {
+        UploaderSource::new(MemoryUploader::new().steps(20).step_ms(150).fail_when(|file| {
+            file.name().to_lowercase().contains("fail").then(|| "The server rejected this file.".to_string())
+        }))
+    });
+    let update = {
+        let files = files.clone();
+        Callback::from(move |chosen| files.set(chosen))
+    };
+
+    html! {
+      <>
+        <Field label="Attachments" help="Uploads are simulated in memory; files with \"fail\" in their name fail.">
+          <Control>
+            <ybc::File name="attachments" files={(*files).clone()} {update} drop_zone=true multiple=true previews=false
+                       selector_icon={html! {<i class="fas fa-upload"></i>}} />
+          </Control>
+        </Field>
+        <UploadQueue uploader={(*uploader).clone()} files={(*files).clone()} concurrency={2} />
+      </>
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Language {
+    Rust,
+    Go,
+    Zig,
+    TypeScript,
+    Python,
+    Ruby,
+}
+
+impl Language {
+    const ALL: [Language; 6] = [Language::Rust, Language::Go, Language::Zig, Language::TypeScript, Language::Python, Language::Ruby];
+
+    fn options() -> Vec<(Language, AttrValue)> {
+        Self::ALL.iter().map(|language| (*language, AttrValue::from(format!("{:?}", language)))).collect()
+    }
+
+    fn typing(self) -> AttrValue {
+        match self {
+            Language::Rust | Language::Go | Language::Zig | Language::TypeScript => "Statically typed".into(),
+            Language::Python | Language::Ruby => "Dynamically typed".into(),
+        }
+    }
+}
+
+#[component(Comboboxes)]
+fn comboboxes() -> Html {
+    let favorite = use_state(|| Some(Language::Rust));
+    let known = use_state(|| vec![Language::Rust, Language::Python]);
+    let on_favorite = {
+        let favorite = favorite.clone();
+        Callback::from(move |language| favorite.set(language))
+    };
+    let on_known = {
+        let known = known.clone();
+        Callback::from(move |languages| known.set(languages))
+    };
+
+    html! {
+      <>
+        <Field label="Favorite language">
+          <Control>
+            <Combobox<Language> name="favorite" options={Language::options()} selected={*favorite} on_select={on_favorite}
+                                clearable=true placeholder="Search languages" />
+          </Control>
+        </Field>
+        <Field label="Known languages">
+          <Control>
+            <MultiCombobox<Language> name="known" options={Language::options()} selected={(*known).clone()} on_select={on_known}
+                                     option_group={Callback::from(Language::typing)} clearable=true placeholder="Add languages" />
+          </Control>
+        </Field>
+        <p class="is-size-7">{format!("favorite: {:?}, known: {:?}", *favorite, *known)}</p>
+      </>
+    }
+}
+
+#[component(NativeMultiSelects)]
+fn native_multi_selects() -> Html {
+    let toppings = use_state(|| vec!["cheese".to_string(), "olives".to_string()]);
+    let update = {
+        let toppings = toppings.clone();
+        Callback::from(move |value| toppings.set(value))
+    };
+    let options = html! {
+      <>
+        <optgroup label="Classic">
+          <option value="cheese">{"Cheese"}</option>
+          <option value="tomato">{"Tomato"}</option>
+        </optgroup>
+        <optgroup label="Extra">
+          <option value="olives">{"Olives"}</option>
+          <option value="mushrooms">{"Mushrooms"}</option>
+          <option value="pineapple">{"Pineapple"}</option>
+        </optgroup>
+      </>
+    };
+
+    html! {
+      <>
+        <Field label="Toppings" help="A native multiple select; the controlled value preselects options.">
+          <Control>
+            <MultiSelect name="toppings" value={(*toppings).clone()} update={update.clone()}>{options.clone()}</MultiSelect>
+          </Control>
+        </Field>
+        <Field label="Toppings as chips">
+          <Control>
+            <MultiSelect name="topping_chips" value={(*toppings).clone()} {update} chips=true add_label="Add a topping">{options}</MultiSelect>
+          </Control>
+        </Field>
+        <p class="is-size-7">{format!("toppings: {:?}", *toppings)}</p>
+      </>
+    }
+}
+
 #[component(FormExamplePage)]
 pub fn form_example_page() -> Html {
     let text = use_state(|| String::new());
@@ -49,6 +444,12 @@ pub fn form_example_page() -> Html {
        <Calendar id="my-calendar" date={"2030-01-01 01:02"} on_date_changed={on_date_changed} class={vec!["input".to_string()]} />
     };
 
+    let settings = use_state(|| Option::<Settings>::None);
+    let on_settings = {
+        let settings = settings.clone();
+        Callback::from(move |value: Settings| settings.set(Some(value)))
+    };
+
     let select_value = "B";
     html! {
       <ybc::Section>
@@ -94,6 +495,34 @@ pub fn form_example_page() -> Html {
               {calendar_departure_date}
             </ybc::Control>
           </ybc::Field>
+
+          <h3 class="title is-5 mt-5">{"Input types and attributes"}</h3>
+          <InputTypes />
+
+          <h3 class="title is-5 mt-5">{"Combobox and MultiCombobox"}</h3>
+          <Comboboxes />
+
+          <h3 class="title is-5 mt-5">{"Native MultiSelect"}</h3>
+          <NativeMultiSelects />
+
+          <h3 class="title is-5 mt-5">{"NumberInput"}</h3>
+          <NumberInputs />
+
+          <h3 class="title is-5 mt-5">{"MaskedInput"}</h3>
+          <MaskedInputs />
+
+          <h3 class="title is-5 mt-5">{"File drop zone"}</h3>
+          <FileDropZones />
+
+          <h3 class="title is-5 mt-5">{"UploadQueue"}</h3>
+          <Uploads />
+
+          <h3 class="title is-5 mt-5">{"use_form: registered fields with validation"}</h3>
+          <SignupForm />
+
+          <h3 class="title is-5 mt-5">{"#[derive(YbcForm)]"}</h3>
+          <SettingsForm onsubmit={on_settings} />
+          <p class="is-size-7">{settings.as_ref().map(|settings| format!("{:?}", settings)).unwrap_or_default()}</p>
         </ybc::Container>
       </ybc::Section>
     }
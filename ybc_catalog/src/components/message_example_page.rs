@@ -1,73 +1,86 @@
 use yew::prelude::*;
-use ybc::components::message::{Message, MessageHeader, MessageBody};
+use ybc::components::message::{Message, MessageColor, MessageHeader, MessageBody};
+use ybc::Size;
 use crate::ui::DemoTitle;
 
 #[component(MessageExamplePage)]
 pub fn message_example_page() -> Html {
+    let open = use_state(|| true);
+    let set_open = {
+        let open = open.clone();
+        Callback::from(move |value: bool| open.set(value))
+    };
+    let reopen = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(true))
+    };
+
     html! {
         <ybc::Section>
             <ybc::Container classes={classes!("content")}>
                 <DemoTitle title={"Message"} icon_classes={classes!("fa-solid", "fa-envelope-open-text")} />
 
-                <Message>
+                <Message dismissible=true>
                     <MessageHeader>
                         { "Default" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "A simple message with a header and a body." }
                     </MessageBody>
                 </Message>
 
-                <Message classes={classes!("is-primary")}>
+                <Message color={MessageColor::Primary} dismissible=true>
                     <MessageHeader>
                         { "Primary" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "Primary color message body." }
                     </MessageBody>
                 </Message>
 
-                <Message classes={classes!("is-link")}>
+                <Message color={MessageColor::Link} dismissible=true>
                     <MessageHeader>
                         { "Link" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "Link color message body." }
                     </MessageBody>
                 </Message>
 
-                <Message classes={classes!("is-success", "is-small")}>
+                <Message color={MessageColor::Success} size={Size::Small} dismissible=true>
                     <MessageHeader>
                         { "Success (small)" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "Small size message." }
                     </MessageBody>
                 </Message>
 
-                <Message classes={classes!("is-warning", "is-medium")}>
+                <Message color={MessageColor::Warning} size={Size::Medium} dismissible=true>
                     <MessageHeader>
                         { "Warning (medium)" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "Medium size message." }
                     </MessageBody>
                 </Message>
 
-                <Message classes={classes!("is-danger", "is-large")}>
+                <Message color={MessageColor::Danger} size={Size::Large} dismissible=true>
                     <MessageHeader>
                         { "Danger (large)" }
-                        <ybc::Button classes={classes!("delete")} no_button_class={true} aria_label={"delete"} />
                     </MessageHeader>
                     <MessageBody>
                         { "Large size message." }
                     </MessageBody>
                 </Message>
+
+                <Message color={MessageColor::Info} dismissible=true open={*open} set_open={set_open}>
+                    <MessageHeader>{ "Info (controlled)" }</MessageHeader>
+                    <MessageBody>{ "The parent owns the open state of this message." }</MessageBody>
+                </Message>
+                if !*open {
+                    <ybc::Button onclick={reopen}>{ "Show the info message again" }</ybc::Button>
+                }
             </ybc::Container>
         </ybc::Section>
     }
@@ -1,6 +1,6 @@
 use yew::prelude::*;
 use yew_router::prelude::Link;
-use ybc::{Alignment, Size};
+use ybc::{Alignment, PaginationQueryParams, PaginationRouter, Size, use_pagination_query};
 use ybc::components::pagination::{Pagination, PaginationItem, PaginationItemType, PaginationEllipsis};
 use crate::router::Route;
 use crate::ui::DemoTitle;
@@ -10,6 +10,16 @@ pub fn pagination_example_page() -> Html {
     let prev_link = |to: Route| html! { <Link<Route> to={to} classes={classes!("pagination-previous")}>{ "Previous" }</Link<Route>> };
     let next_link = |to: Route| html! { <Link<Route> to={to} classes={classes!("pagination-next")}>{ "Next page" }</Link<Route>> };
 
+    let page = use_state(|| 5usize);
+    let on_page_change = {
+        let page = page.clone();
+        Callback::from(move |next: usize| page.set(next))
+    };
+
+    let params = PaginationQueryParams::default();
+    let page_query = use_pagination_query(&params, 20);
+    let page_query = page_query.clamp_to(487usize.div_ceil(page_query.page_size));
+
     html! {
         <ybc::Section>
             <ybc::Container classes={classes!("content")}>
@@ -42,6 +52,17 @@ pub fn pagination_example_page() -> Html {
                     <PaginationItem item_type={PaginationItemType::Link} label={"Go to page 10"} current=true>{ "10" }</PaginationItem>
                     <PaginationItem item_type={PaginationItemType::Link} label={"Go to page 11"}>{ "11" }</PaginationItem>
                 </Pagination>
+
+                <h5>{ "Automatic: 487 items, 20 per page" }</h5>
+                <Pagination total_items={Some(487)} page_size={20} current={*page} on_page_change={on_page_change.clone()} />
+
+                <h5>{ "Automatic: 50 pages, two siblings and two boundary pages" }</h5>
+                <Pagination total_pages={Some(50)} current={*page} siblings={2} boundaries={2} {on_page_change}
+                            alignment={Some(Alignment::Centered)} rounded=true />
+
+                <h5>{ "Router: page and page size in the query string" }</h5>
+                <p class="is-size-7">{ format!("?page={}&page_size={} (try browser back/forward)", page_query.page, page_query.page_size) }</p>
+                <PaginationRouter<Route> total_items={Some(487)} default_page_size={20} page_sizes={vec![10, 20, 50]} />
             </ybc::Container>
         </ybc::Section>
     }
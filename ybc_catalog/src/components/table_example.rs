@@ -2,8 +2,94 @@ use ybc::*;
 use yew::prelude::*;
 use crate::ui::DemoTitle;
 
+#[derive(Clone, Debug, PartialEq)]
+struct Person {
+    id: u32,
+    name: &'static str,
+    role: &'static str,
+    age: u32,
+}
+
+const PEOPLE: &[Person] = &[
+    Person { id: 1, name: "Alice", role: "Admin", age: 34 },
+    Person { id: 2, name: "Bob", role: "User", age: 27 },
+    Person { id: 3, name: "Caro", role: "Guest", age: 41 },
+    Person { id: 4, name: "Dmitri", role: "User", age: 19 },
+];
+
+#[derive(Clone, Debug, PartialEq)]
+struct Order {
+    number: u32,
+    customer: String,
+    total_cents: u32,
+}
+
+/// Stands in for a REST endpoint: filters, sorts and pages 1000 generated orders.
+fn query_orders(query: &TableQuery) -> (Vec<Order>, usize) {
+    const CUSTOMERS: [&str; 5] = ["Alice", "Bob", "Caro", "Dmitri", "Eve"];
+    let needle = query.filter("customer").to_lowercase();
+    let mut orders: Vec<Order> = (1..=1000)
+        .map(|number| Order {
+            number,
+            customer: CUSTOMERS[number as usize % CUSTOMERS.len()].to_string(),
+            total_cents: (number * 7919) % 100_000,
+        })
+        .filter(|order| order.customer.to_lowercase().contains(&needle))
+        .collect();
+    if let Some(sort) = query.sort.as_ref() {
+        match sort.column.as_str() {
+            "customer" => orders.sort_by(|a, b| a.customer.cmp(&b.customer)),
+            "total" => orders.sort_by_key(|order| order.total_cents),
+            _ => {}
+        }
+        if sort.direction == SortDirection::Descending {
+            orders.reverse();
+        }
+    }
+    let total = orders.len();
+    let page = orders.into_iter().skip(query.offset()).take(query.page_size).collect();
+    (page, total)
+}
+
 #[component(TableExamplePage)]
 pub fn table_example_page() -> Html {
+    let columns = use_memo((), |_| {
+        vec![
+            ColumnDef::new("id", "ID", |person: &Person| html! { {person.id} }).sort_by_key(|person: &Person| person.id),
+            ColumnDef::new("name", "Name", |person: &Person| html! { {person.name} }).sort_by_key(|person: &Person| person.name),
+            ColumnDef::new("role", "Role", |person: &Person| html! { <span class="tag is-light">{person.role}</span> }),
+            ColumnDef::new("age", "Age", |person: &Person| html! { {person.age} })
+                .sort_by_key(|person: &Person| person.age)
+                .align(Alignment::Right),
+        ]
+    });
+    let row_key = use_memo((), |_| Callback::from(|person: Person| AttrValue::from(person.id.to_string())));
+    let selected = use_state(Vec::<Person>::new);
+    let on_selection_change = {
+        let selected = selected.clone();
+        Callback::from(move |rows: Vec<Person>| selected.set(rows))
+    };
+
+    let order_columns = use_memo((), |_| {
+        vec![
+            ColumnDef::new("number", "Order", |order: &Order| html! { {format!("#{}", order.number)} }),
+            ColumnDef::new("customer", "Customer", |order: &Order| html! { {order.customer.clone()} }).sort_by_key(|order: &Order| order.customer.clone()),
+            ColumnDef::new("total", "Total", |order: &Order| html! { {format!("{:.2}", order.total_cents as f64 / 100.0)} })
+                .sort_by_key(|order: &Order| order.total_cents)
+                .align(Alignment::Right),
+        ]
+    });
+    let orders = use_state(Vec::<Order>::new);
+    let on_query = {
+        let orders = orders.clone();
+        Callback::from(move |request: TableQueryRequest| {
+            let (page, total) = query_orders(&request.query);
+            orders.set(page);
+            request.resolve(Some(total));
+        })
+    };
+    let table = use_table_query(TableQuery::new(10), on_query);
+
     html! {
       <ybc::Section>
         <ybc::Container>
@@ -18,6 +104,58 @@ pub fn table_example_page() -> Html {
               <tr><td>{"3"}</td><td>{"Caro"}</td><td>{"Guest"}</td></tr>
             </tbody>
           </Table>
+
+          <h3 class="title is-5 mt-5">{"DataTable: sortable columns, multi selection, sticky header"}</h3>
+          <DataTable<Person>
+              columns={(*columns).clone()}
+              rows={PEOPLE.to_vec()}
+              row_key={(*row_key).clone()}
+              selection={TableSelection::Multi}
+              default_sort={Some(SortState::ascending("name"))}
+              {on_selection_change}
+              sticky_header=true
+              max_height="12rem"
+              striped=true
+              hoverable=true
+              fullwidth=true
+              aria_label="People"
+          />
+          <p class="is-size-7">
+            {format!("Selected: {}", selected.iter().map(|person| person.name).collect::<Vec<_>>().join(", "))}
+          </p>
+
+          <h3 class="title is-5 mt-5">{"DataTable: empty state"}</h3>
+          <DataTable<Person>
+              columns={(*columns).clone()}
+              rows={Vec::new()}
+              selection={TableSelection::Single}
+              empty={html! { <em>{"Nobody here yet."}</em> }}
+              fullwidth=true
+          />
+
+          <h3 class="title is-5 mt-5">{"DataTable: server-side paging, sorting and filtering"}</h3>
+          <ybc::Field>
+            <ybc::Control>
+              <Input name="customer" value={table.filter("customer").to_owned()} update={table.filter_setter("customer")} placeholder="Filter by customer" />
+            </ybc::Control>
+          </ybc::Field>
+          <DataTable<Order>
+              columns={(*order_columns).clone()}
+              rows={(*orders).clone()}
+              manual_sort=true
+              sort={Some(table.query().sort.clone())}
+              set_sort={table.sort_setter()}
+              loading={table.loading()}
+              striped=true
+              fullwidth=true
+          />
+          <TableResultsCount query={table.query().clone()} total_items={table.total_items()} />
+          <Pagination
+              total_items={table.total_items()}
+              page_size={table.query().page_size}
+              current={table.query().page}
+              on_page_change={table.page_setter()}
+          />
         </ybc::Container>
       </ybc::Section>
     }
@@ -1,6 +1,31 @@
+use ybc::{NotificationColor, Toast, ToastPosition, ToastProvider, use_toast};
 use yew::prelude::*;
 use crate::ui::DemoTitle;
 
+#[component(ToastButtons)]
+fn toast_buttons() -> Html {
+    let toast = use_toast();
+    let show = |make: fn() -> Toast| {
+        let toast = toast.clone();
+        Callback::from(move |_: MouseEvent| {
+            toast.show(make());
+        })
+    };
+    let clear = {
+        let toast = toast.clone();
+        Callback::from(move |_: MouseEvent| toast.clear())
+    };
+    html! {
+      <ybc::Buttons>
+        <ybc::Button classes={classes!("is-info")} onclick={show(|| Toast::info("Something happened."))}>{"Info"}</ybc::Button>
+        <ybc::Button classes={classes!("is-success")} onclick={show(|| Toast::success("Profile saved.").title("Done"))}>{"Success"}</ybc::Button>
+        <ybc::Button classes={classes!("is-warning")} onclick={show(|| Toast::warning("Disk almost full.").duration_ms(10_000))}>{"Warning (10s)"}</ybc::Button>
+        <ybc::Button classes={classes!("is-danger")} onclick={show(|| Toast::danger("Upload failed.").sticky())}>{"Danger (sticky)"}</ybc::Button>
+        <ybc::Button onclick={clear}>{"Clear all"}</ybc::Button>
+      </ybc::Buttons>
+    }
+}
+
 #[component(TagNotificationExamplePage)]
 pub fn tags_notifications_page() -> Html {
     html! {
@@ -16,8 +41,13 @@ pub fn tags_notifications_page() -> Html {
             <ybc::Tag classes={classes!("is-danger")}>{"Danger"}</ybc::Tag>
           </ybc::Tags>
           <h2>{"Notifications"}</h2>
-          <ybc::Notification classes={classes!("is-info")}>{"An info notification"}</ybc::Notification>
-          <ybc::Notification classes={classes!("is-warning")}>{"A warning notification"}</ybc::Notification>
+          <ybc::Notification color={NotificationColor::Info}>{"An info notification"}</ybc::Notification>
+          <ybc::Notification color={NotificationColor::Warning} light=true dismissible=true>{"A light, dismissible warning notification"}</ybc::Notification>
+          <h2>{"Toasts"}</h2>
+          <p>{"Toasts close after five seconds; hovering a toast pauses its timer. At most three are shown at once."}</p>
+          <ToastProvider position={ToastPosition::BottomRight} max_visible={3}>
+            <ToastButtons />
+          </ToastProvider>
         </ybc::Container>
       </ybc::Section>
     }
@@ -0,0 +1,440 @@
+//! Derive macros for [ybc](https://docs.rs/ybc); enable the `derive` feature of `ybc` instead of depending on this
+//! crate directly.
+//!
+//! `#[derive(YbcForm)]` on a struct generates a form component for it, built on `ybc::use_form_with` and `ybc::Form`.
+//! Each field becomes a `Field` with a control chosen by its type:
+//!
+//! - `String` → `Input` (or `TextArea` with `#[ybc(textarea)]`)
+//! - `bool` → `Checkbox`
+//! - `Vec<File>` → `File`
+//! - any other type → `Select`; the type must implement `ybc::FormOptions`, which `#[derive(YbcForm)]` on a
+//!   fieldless enum provides.
+//!
+//! The struct must implement `Clone`, `Default` and `PartialEq`. The component is named after the struct with a
+//! `Form` suffix and takes the props `initial`, `onsubmit` (emitting the typed struct once all fields are valid) and
+//! `classes`.
+//!
+//! Struct attributes: `#[ybc(component = "SettingsDialog", submit = "Save")]`.
+//!
+//! Field attributes:
+//! - `label = "..."`, `help = "..."`, `placeholder = "..."`; the label defaults to the humanized field name.
+//! - `required` or `required = "message"`, `min_length = 3`, `max_length = 20`, `email` (also sets the input type),
+//!   and `validate = "path::to::check"` for a `fn(&V) -> Result<(), String>` (`&str` works for text fields).
+//! - `password` for a password input, `textarea` for a `TextArea`, and `skip` to leave a field out of the form.
+//!
+//! Variant attributes of enums: `label = "..."` and `value = "..."`.
+//!
+//! ```rust,ignore
+//! #[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+//! enum Theme {
+//!     #[default]
+//!     Light,
+//!     Dark,
+//! }
+//!
+//! #[derive(Clone, Debug, Default, PartialEq, YbcForm)]
+//! #[ybc(submit = "Save")]
+//! struct Settings {
+//!     #[ybc(required, max_length = 40, placeholder = "Jane Doe")]
+//!     display_name: String,
+//!     #[ybc(required = "We need an address to reach you.", email)]
+//!     email: String,
+//!     #[ybc(help = "Applies to all devices.")]
+//!     theme: Theme,
+//!     #[ybc(label = "Send me the newsletter")]
+//!     newsletter: bool,
+//! }
+//!
+//! html! { <SettingsForm initial={settings} onsubmit={save} /> }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, GenericArgument, Ident, LitInt, LitStr, PathArguments, Result, Type};
+
+/// Generates a form component for a struct, or the `ybc::FormOptions` of a fieldless enum.
+#[proc_macro_derive(YbcForm, attributes(ybc))]
+pub fn derive_ybc_form(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(data) => form_component(&input, data),
+        Data::Enum(data) => form_options(&input, data),
+        Data::Union(_) => Err(Error::new_spanned(&input.ident, "YbcForm cannot be derived for unions")),
+    };
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct StructAttrs {
+    component: Option<Ident>,
+    submit: Option<LitStr>,
+}
+
+impl StructAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("ybc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("component") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    parsed.component = Some(name.parse()?);
+                } else if meta.path.is_ident("submit") {
+                    parsed.submit = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `component` or `submit`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    label: Option<LitStr>,
+    help: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    required: Option<Option<LitStr>>,
+    min_length: Option<LitInt>,
+    max_length: Option<LitInt>,
+    email: bool,
+    password: bool,
+    textarea: bool,
+    validate: Vec<syn::Path>,
+    skip: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("ybc")) {
+            attr.parse_nested_meta(|meta| {
+                let path = &meta.path;
+                if path.is_ident("label") {
+                    parsed.label = Some(meta.value()?.parse()?);
+                } else if path.is_ident("help") {
+                    parsed.help = Some(meta.value()?.parse()?);
+                } else if path.is_ident("placeholder") {
+                    parsed.placeholder = Some(meta.value()?.parse()?);
+                } else if path.is_ident("required") {
+                    let message = if meta.input.peek(syn::Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    };
+                    parsed.required = Some(message);
+                } else if path.is_ident("min_length") {
+                    parsed.min_length = Some(meta.value()?.parse()?);
+                } else if path.is_ident("max_length") {
+                    parsed.max_length = Some(meta.value()?.parse()?);
+                } else if path.is_ident("email") {
+                    parsed.email = true;
+                } else if path.is_ident("password") {
+                    parsed.password = true;
+                } else if path.is_ident("textarea") {
+                    parsed.textarea = true;
+                } else if path.is_ident("validate") {
+                    let check: LitStr = meta.value()?.parse()?;
+                    parsed.validate.push(check.parse()?);
+                } else if path.is_ident("skip") {
+                    parsed.skip = true;
+                } else {
+                    return Err(meta.error(
+                        "expected one of `label`, `help`, `placeholder`, `required`, `min_length`, `max_length`, `email`, \
+                         `password`, `textarea`, `validate` or `skip`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// The control a field is rendered with, chosen by its type.
+enum FieldKind {
+    Input,
+    TextArea,
+    Checkbox,
+    Files,
+    Select,
+}
+
+impl FieldKind {
+    fn of(ty: &Type, attrs: &FieldAttrs) -> Self {
+        match last_segment(ty) {
+            Some((name, _)) if name == "String" && attrs.textarea => Self::TextArea,
+            Some((name, _)) if name == "String" => Self::Input,
+            Some((name, _)) if name == "bool" => Self::Checkbox,
+            Some((name, PathArguments::AngleBracketed(args))) if name == "Vec" => {
+                let is_file = args.args.iter().any(|arg| match arg {
+                    GenericArgument::Type(inner) => last_segment(inner).is_some_and(|(inner, _)| inner == "File"),
+                    _ => false,
+                });
+                if is_file { Self::Files } else { Self::Select }
+            }
+            _ => Self::Select,
+        }
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<(&Ident, &PathArguments)> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last().map(|segment| (&segment.ident, &segment.arguments)),
+        _ => None,
+    }
+}
+
+/// `display_name` → "Display name", `DarkMode` → "Dark mode".
+fn humanize(name: &str) -> String {
+    let mut words = String::new();
+    for (index, c) in name.trim_start_matches("r#").chars().enumerate() {
+        if c == '_' {
+            words.push(' ');
+        } else if c.is_uppercase() && index > 0 {
+            words.push(' ');
+            words.extend(c.to_lowercase());
+        } else {
+            words.push(c);
+        }
+    }
+    let mut chars = words.trim().chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// `SettingsForm` → `settings_form`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+fn form_component(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "YbcForm cannot be derived for generic structs"));
+    }
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "YbcForm can only be derived for structs with named fields",
+        ));
+    };
+
+    let model = &input.ident;
+    let vis = &input.vis;
+    let attrs = StructAttrs::parse(&input.attrs)?;
+    let component = attrs.component.unwrap_or_else(|| format_ident!("{}Form", model));
+    let props = format_ident!("{}Props", component);
+    let function = format_ident!("{}", snake_case(&component.to_string()));
+    let submit = attrs.submit.unwrap_or_else(|| LitStr::new("Submit", model.span()));
+
+    let mut registrations = Vec::new();
+    let mut controls = Vec::new();
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let kind = FieldKind::of(ty, &attrs);
+        let name = ident.to_string().trim_start_matches("r#").to_string();
+        let handle = format_ident!("field_{}", name);
+        let label = attrs.label.as_ref().map(LitStr::value).unwrap_or_else(|| humanize(&name));
+
+        let mut validators = Vec::new();
+        if let Some(message) = &attrs.required {
+            let message = message.as_ref().map(LitStr::value).unwrap_or_else(|| format!("{} is required.", label));
+            validators.push(quote! { .validate(::ybc::Validator::required(#message)) });
+        }
+        if let Some(min) = &attrs.min_length {
+            let message = format!("{} must have at least {} characters.", label, min.base10_digits());
+            validators.push(quote! { .validate(::ybc::Validator::min_length(#min, #message)) });
+        }
+        if let Some(max) = &attrs.max_length {
+            let message = format!("{} must have at most {} characters.", label, max.base10_digits());
+            validators.push(quote! { .validate(::ybc::Validator::max_length(#max, #message)) });
+        }
+        if attrs.email {
+            let message = format!("{} must be an email address.", label);
+            validators.push(quote! { .validate(::ybc::Validator::email(#message)) });
+        }
+        for check in &attrs.validate {
+            validators.push(quote! { .validate(::ybc::Validator::new(|value: &#ty| #check(value))) });
+        }
+
+        let def = match kind {
+            FieldKind::Select => quote! {
+                ::ybc::FormFieldDef::new(
+                    #name,
+                    |model: &#model| ::ybc::FormOptions::option_value(&model.#ident).to_string(),
+                    |model: &mut #model, value: ::std::string::String| {
+                        if let ::std::option::Option::Some(option) = <#ty as ::ybc::FormOptions>::from_option_value(&value) {
+                            model.#ident = option;
+                        }
+                    },
+                )
+            },
+            _ => quote! {
+                ::ybc::FormFieldDef::new(
+                    #name,
+                    |model: &#model| ::std::clone::Clone::clone(&model.#ident),
+                    |model: &mut #model, value: #ty| model.#ident = value,
+                )
+            },
+        };
+        registrations.push(quote! {
+            let #handle = form.register(#def #(#validators)*);
+        });
+
+        let help = match &attrs.help {
+            Some(help) => quote! { ::std::option::Option::Some(::std::string::String::from(#help)) },
+            None => quote! { ::std::option::Option::<::std::string::String>::None },
+        };
+        let placeholder = attrs.placeholder.as_ref().map(LitStr::value).unwrap_or_default();
+        let control = match kind {
+            FieldKind::Input => {
+                let input_type = if attrs.password {
+                    quote! { ::ybc::InputType::Password }
+                } else if attrs.email {
+                    quote! { ::ybc::InputType::Email }
+                } else {
+                    quote! { ::ybc::InputType::Text }
+                };
+                quote! {
+                    <::ybc::Input name={#handle.name()} value={#handle.value()} update={#handle.update()}
+                        placeholder={#placeholder} r#type={#input_type} aria_invalid={#handle.has_error()} />
+                }
+            }
+            FieldKind::TextArea => quote! {
+                <::ybc::TextArea name={#handle.name()} value={#handle.value()} update={#handle.update()} placeholder={#placeholder} />
+            },
+            FieldKind::Checkbox => quote! {
+                <::ybc::Checkbox name={#handle.name()} checked={#handle.value()} update={#handle.update()}>{" "}{#label}</::ybc::Checkbox>
+            },
+            FieldKind::Files => quote! {
+                <::ybc::File name={#handle.name()} files={#handle.value()} update={#handle.update()} multiple=true />
+            },
+            FieldKind::Select => quote! {
+                <::ybc::Select name={#handle.name()} value={#handle.value()} update={#handle.update()}>
+                    { for <#ty as ::ybc::FormOptions>::options().iter().map(|option| {
+                        let value = ::ybc::FormOptions::option_value(option);
+                        let selected = value == #handle.value();
+                        ::yew::html! { <option {value} {selected}>{ ::ybc::FormOptions::option_label(option) }</option> }
+                    }) }
+                </::ybc::Select>
+            },
+        };
+        let label = match kind {
+            FieldKind::Checkbox => quote! { ::std::option::Option::<::std::string::String>::None },
+            _ => quote! { ::std::option::Option::Some(::std::string::String::from(#label)) },
+        };
+        controls.push(quote! {
+            <::ybc::Field label={#label} name={#name} help={#help}>
+                <::ybc::Control>#control</::ybc::Control>
+            </::ybc::Field>
+        });
+    }
+
+    let props_doc = format!("Props of [`{}`].", component);
+    let component_doc = format!("A form editing a [`{}`], generated by `#[derive(YbcForm)]`.", model);
+    Ok(quote! {
+        #[doc = #props_doc]
+        #[derive(::std::clone::Clone, ::std::cmp::PartialEq, ::yew::Properties)]
+        #vis struct #props {
+            /// The value the form starts with.
+            #[prop_or_default]
+            pub initial: #model,
+            /// Receives the value when the form is submitted with all fields valid.
+            #[prop_or_default]
+            pub onsubmit: ::yew::Callback<#model>,
+            #[prop_or_default]
+            pub classes: ::yew::Classes,
+        }
+
+        #[doc = #component_doc]
+        #[::yew::component(#component)]
+        #vis fn #function(props: &#props) -> ::yew::Html {
+            let initial = ::std::clone::Clone::clone(&props.initial);
+            let form = ::ybc::use_form_with(move || initial);
+            #(#registrations)*
+            ::yew::html! {
+                <::ybc::Form<#model> form={::std::clone::Clone::clone(&form)} onsubmit={props.onsubmit.clone()} classes={props.classes.clone()}>
+                    #(#controls)*
+                    <::ybc::Field>
+                        <::ybc::Control>
+                            <::ybc::ButtonInputSubmit value={#submit} classes={::yew::classes!("is-primary")} disabled={form.is_pending()} />
+                        </::ybc::Control>
+                    </::ybc::Field>
+                </::ybc::Form<#model>>
+            }
+        }
+    })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+fn form_options(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "YbcForm cannot be derived for generic enums"));
+    }
+    let model = &input.ident;
+    let mut variants = Vec::new();
+    let mut values = Vec::new();
+    let mut labels = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.span(), "YbcForm options must be variants without fields"));
+        }
+        let mut value = None;
+        let mut label = None;
+        for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("ybc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    label = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("value") {
+                    value = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    return Err(meta.error("expected `label` or `value`"));
+                }
+                Ok(())
+            })?;
+        }
+        let ident = &variant.ident;
+        values.push(value.unwrap_or_else(|| ident.to_string()));
+        labels.push(label.unwrap_or_else(|| humanize(&ident.to_string())));
+        variants.push(ident);
+    }
+
+    Ok(quote! {
+        impl ::ybc::FormOptions for #model {
+            fn options() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(Self::#variants),*]
+            }
+
+            fn option_value(&self) -> ::yew::AttrValue {
+                match self {
+                    #(Self::#variants => ::yew::AttrValue::Static(#values),)*
+                }
+            }
+
+            fn option_label(&self) -> ::yew::AttrValue {
+                match self {
+                    #(Self::#variants => ::yew::AttrValue::Static(#labels),)*
+                }
+            }
+        }
+    })
+}